@@ -0,0 +1,140 @@
+use core::ops::{Deref, DerefMut};
+
+/// Concatenates multiple arrays into an array wrapped in an over-aligned type.
+///
+/// This macro is callable in const contexts.
+///
+/// This evaluates to an `AlignedN<[T; LEN]>` (eg: [`Aligned64`]),
+/// where `N` is the `$align` alignment,
+/// so that the concatenation has at least that alignment wherever it's stored
+/// (eg: for DMA or SIMD buffers).
+///
+/// # Syntax
+///
+/// ```text
+/// aligned_concat!(align = $align:literal; $($array_args:tt)*)
+/// ```
+///
+/// Where `$align` is one of
+/// `2`, `4`, `8`, `16`, `32`, `64`, `128`, `256`, `512`, `1024`, `2048`, or `4096`.
+///
+/// Where `$array_args` are the same arguments as [`concat_arrays`](crate::concat_arrays#syntax).
+///
+/// # Example
+///
+/// ```rust
+/// use arrcat::{aligned_concat, Aligned64};
+///
+/// static TABLE: Aligned64<[u8; 5]> = aligned_concat!(align = 64; [1, 2], [3, 5, 8]);
+///
+/// assert_eq!(TABLE.0, [1, 2, 3, 5, 8]);
+/// assert_eq!(TABLE.as_ptr() as usize % 64, 0);
+///
+/// let data = [13u8, 21];
+/// let aligned = aligned_concat!(align = 16; (TABLE.0): [_; 5], data: [_; 2]);
+/// assert_eq!(core::mem::align_of_val(&aligned), 16);
+/// assert_eq!(aligned.into_inner(), [1, 2, 3, 5, 8, 13, 21]);
+///
+/// ```
+#[macro_export]
+macro_rules! aligned_concat {
+    (align = 2; $($array_args:tt)*) => {
+        $crate::Aligned2($crate::concat_arrays!($($array_args)*))
+    };
+    (align = 4; $($array_args:tt)*) => {
+        $crate::Aligned4($crate::concat_arrays!($($array_args)*))
+    };
+    (align = 8; $($array_args:tt)*) => {
+        $crate::Aligned8($crate::concat_arrays!($($array_args)*))
+    };
+    (align = 16; $($array_args:tt)*) => {
+        $crate::Aligned16($crate::concat_arrays!($($array_args)*))
+    };
+    (align = 32; $($array_args:tt)*) => {
+        $crate::Aligned32($crate::concat_arrays!($($array_args)*))
+    };
+    (align = 64; $($array_args:tt)*) => {
+        $crate::Aligned64($crate::concat_arrays!($($array_args)*))
+    };
+    (align = 128; $($array_args:tt)*) => {
+        $crate::Aligned128($crate::concat_arrays!($($array_args)*))
+    };
+    (align = 256; $($array_args:tt)*) => {
+        $crate::Aligned256($crate::concat_arrays!($($array_args)*))
+    };
+    (align = 512; $($array_args:tt)*) => {
+        $crate::Aligned512($crate::concat_arrays!($($array_args)*))
+    };
+    (align = 1024; $($array_args:tt)*) => {
+        $crate::Aligned1024($crate::concat_arrays!($($array_args)*))
+    };
+    (align = 2048; $($array_args:tt)*) => {
+        $crate::Aligned2048($crate::concat_arrays!($($array_args)*))
+    };
+    (align = 4096; $($array_args:tt)*) => {
+        $crate::Aligned4096($crate::concat_arrays!($($array_args)*))
+    };
+    (align = $align:tt; $($array_args:tt)*) => {
+        $crate::__::compile_error!{$crate::__::concat!(
+            "unsupported alignment `",
+            $crate::__::stringify!($align),
+            "` passed to `aligned_concat`, \
+             expected a power of two from `2` to `4096`",
+        )}
+    };
+}
+
+macro_rules! declare_aligned_types {
+    ($(($name:ident, $align:literal))*) => {$(
+        #[doc = concat!("A wrapper that aligns `T` to at least ", stringify!($align), " bytes,")]
+        /// returned by [`aligned_concat`](crate::aligned_concat).
+        #[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+        #[repr(C, align($align))]
+        pub struct $name<T>(pub T);
+
+        impl<T> $name<T> {
+            #[doc = concat!("Constructs an `", stringify!($name), "`.")]
+            #[inline(always)]
+            pub const fn new(value: T) -> Self {
+                Self(value)
+            }
+
+            /// Unwraps the value.
+            #[inline(always)]
+            pub fn into_inner(self) -> T {
+                self.0
+            }
+        }
+
+        impl<T> Deref for $name<T> {
+            type Target = T;
+
+            #[inline(always)]
+            fn deref(&self) -> &T {
+                &self.0
+            }
+        }
+
+        impl<T> DerefMut for $name<T> {
+            #[inline(always)]
+            fn deref_mut(&mut self) -> &mut T {
+                &mut self.0
+            }
+        }
+    )*};
+}
+
+declare_aligned_types! {
+    (Aligned2, 2)
+    (Aligned4, 4)
+    (Aligned8, 8)
+    (Aligned16, 16)
+    (Aligned32, 32)
+    (Aligned64, 64)
+    (Aligned128, 128)
+    (Aligned256, 256)
+    (Aligned512, 512)
+    (Aligned1024, 1024)
+    (Aligned2048, 2048)
+    (Aligned4096, 4096)
+}
@@ -0,0 +1,662 @@
+//! Const functions for manipulating arrays of generic length.
+//!
+//! These functions require the `"rust_1_83"` crate feature.
+
+use core::{
+    mem::{offset_of, ManuallyDrop, MaybeUninit},
+    ptr,
+};
+
+use crate::internals::{array_assume_init, const_transmute, uninit_array};
+
+/// Moves the second half of an array to the front.
+///
+/// This is equivalent to rotating the array left by `N / 2`.
+///
+/// # Compile-time errors
+///
+/// This function causes a compile-time error if `N` is odd.
+///
+/// # Example
+///
+/// ```rust
+/// use arrcat::swap_halves;
+///
+/// const SWAPPED: [u8; 6] = swap_halves([1, 2, 3, 4, 5, 6]);
+///
+/// assert_eq!(SWAPPED, [4, 5, 6, 1, 2, 3]);
+///
+/// assert_eq!(swap_halves([0u8; 0]), []);
+///
+/// assert_eq!(
+///     swap_halves(["foo".to_string(), "bar".to_string()]),
+///     ["bar", "foo"],
+/// );
+/// ```
+pub const fn swap_halves<T, const N: usize>(arr: [T; N]) -> [T; N] {
+    const { assert!(N % 2 == 0, "`swap_halves` requires an even-length array") };
+
+    let arr = ManuallyDrop::new(arr);
+    let src = as_elem_ptr(&arr);
+
+    let mut out = uninit_array::<T, N>();
+    let mut i = 0;
+    while i < N {
+        // SAFETY: every element of `arr` is read exactly once,
+        // because `(i + N / 2) % N` is a permutation of `0..N` for even `N`.
+        out[i] = MaybeUninit::new(unsafe { src.add((i + N / 2) % N).read() });
+        i += 1;
+    }
+
+    // SAFETY: all elements of `out` were initialized in the loop above
+    unsafe { array_assume_init(out) }
+}
+
+/// Rotates an array left by `by` elements,
+/// moving the element at index `by % N` to the front.
+///
+/// This is the inverse of [`rotate_right`] for the same `by`.
+///
+/// # Example
+///
+/// ```rust
+/// use arrcat::rotate_left;
+///
+/// const ROTATED: [u8; 5] = rotate_left([1, 2, 3, 4, 5], 2);
+///
+/// assert_eq!(ROTATED, [3, 4, 5, 1, 2]);
+///
+/// assert_eq!(rotate_left([1, 2, 3], 4), [2, 3, 1]);
+///
+/// assert_eq!(
+///     rotate_left(["foo".to_string(), "bar".to_string()], 1),
+///     ["bar", "foo"],
+/// );
+/// ```
+pub const fn rotate_left<T, const N: usize>(arr: [T; N], by: usize) -> [T; N] {
+    if N == 0 {
+        return arr;
+    }
+
+    rotate_left_inner(arr, by % N)
+}
+
+/// Rotates an array right by `by` elements,
+/// moving the element at index `N - by % N` to the front.
+///
+/// This is the inverse of [`rotate_left`] for the same `by`.
+///
+/// # Example
+///
+/// ```rust
+/// use arrcat::{rotate_left, rotate_right};
+///
+/// const ROTATED: [u8; 5] = rotate_right([1, 2, 3, 4, 5], 2);
+///
+/// assert_eq!(ROTATED, [4, 5, 1, 2, 3]);
+///
+/// assert_eq!(rotate_right([1, 2, 3], 4), [3, 1, 2]);
+///
+/// assert_eq!(rotate_right(rotate_left([1, 2, 3], 2), 2), [1, 2, 3]);
+/// ```
+pub const fn rotate_right<T, const N: usize>(arr: [T; N], by: usize) -> [T; N] {
+    if N == 0 {
+        return arr;
+    }
+
+    rotate_left_inner(arr, (N - by % N) % N)
+}
+
+// requires `by < N`
+const fn rotate_left_inner<T, const N: usize>(arr: [T; N], by: usize) -> [T; N] {
+    let arr = ManuallyDrop::new(arr);
+    let src = as_elem_ptr(&arr);
+
+    let mut out = uninit_array::<T, N>();
+    let mut i = 0;
+    while i < N {
+        // SAFETY: every element of `arr` is read exactly once,
+        // because `(i + by) % N` is a permutation of `0..N`.
+        out[i] = MaybeUninit::new(unsafe { src.add((i + by) % N).read() });
+        i += 1;
+    }
+
+    // SAFETY: all elements of `out` were initialized in the loop above
+    unsafe { array_assume_init(out) }
+}
+
+/// Splits an array into its first `M` elements and the remaining `R` elements.
+///
+/// `R` must equal `N - M`,
+/// it's a separate parameter because the return type can't be `([T; M], [T; N - M])`
+/// on stable Rust.
+///
+/// # Compile-time errors
+///
+/// This function causes a compile-time error if `M > N` or `R != N - M`.
+///
+/// # Example
+///
+/// ```rust
+/// use arrcat::split_at;
+///
+/// const SPLIT: ([u8; 2], [u8; 3]) = split_at([3, 5, 8, 13, 21]);
+/// assert_eq!(SPLIT, ([3, 5], [8, 13, 21]));
+///
+/// assert_eq!(
+///     split_at(["foo".to_string(), "bar".to_string()]),
+///     (["foo".to_string()], ["bar".to_string()]),
+/// );
+/// ```
+pub const fn split_at<T, const N: usize, const M: usize, const R: usize>(
+    arr: [T; N],
+) -> ([T; M], [T; R]) {
+    const {
+        assert!(
+            M <= N,
+            "the `M` argument of `split_at` must not be larger than `N`"
+        );
+        assert!(
+            R == N - M,
+            "the `R` argument of `split_at` must be equal to `N - M`"
+        );
+    };
+
+    let arr = ManuallyDrop::new(arr);
+    let src = as_elem_ptr(&arr);
+
+    let mut left = uninit_array::<T, M>();
+    let mut right = uninit_array::<T, R>();
+
+    let mut i = 0;
+    while i < M {
+        // SAFETY: every element of `arr` is read exactly once, across both loops
+        left[i] = MaybeUninit::new(unsafe { src.add(i).read() });
+        i += 1;
+    }
+
+    let mut i = 0;
+    while i < R {
+        // SAFETY: every element of `arr` is read exactly once, across both loops
+        right[i] = MaybeUninit::new(unsafe { src.add(M + i).read() });
+        i += 1;
+    }
+
+    // SAFETY: all elements of `left` and `right` were initialized in the loops above
+    unsafe { (array_assume_init(left), array_assume_init(right)) }
+}
+
+/// Gets a copy of the first element of an array.
+///
+/// # Compile-time errors
+///
+/// This function causes a compile-time error if `N` is zero.
+///
+/// # Example
+///
+/// ```rust
+/// use arrcat::{concat_arrays, first};
+///
+/// const ARR: [u8; 5] = concat_arrays!([3, 5], [8, 13, 21]);
+/// const FIRST: u8 = first(&ARR);
+///
+/// assert_eq!(FIRST, 3);
+/// ```
+pub const fn first<T: Copy, const N: usize>(arr: &[T; N]) -> T {
+    const { assert!(N != 0, "`first` requires a non-empty array") };
+
+    arr[0]
+}
+
+/// Gets a copy of the last element of an array.
+///
+/// # Compile-time errors
+///
+/// This function causes a compile-time error if `N` is zero.
+///
+/// # Example
+///
+/// ```rust
+/// use arrcat::{concat_arrays, last};
+///
+/// const ARR: [u8; 5] = concat_arrays!([3, 5], [8, 13, 21]);
+/// const LAST: u8 = last(&ARR);
+///
+/// assert_eq!(LAST, 21);
+/// ```
+pub const fn last<T: Copy, const N: usize>(arr: &[T; N]) -> T {
+    const { assert!(N != 0, "`last` requires a non-empty array") };
+
+    arr[N - 1]
+}
+
+/// Repeats an array `K` times, returning an array of length `M`.
+///
+/// `M` must equal `N * K`,
+/// it's a separate parameter because the return type can't be `[T; N * K]` on stable Rust.
+///
+/// # Compile-time errors
+///
+/// This function causes a compile-time error if `M != N * K`.
+///
+/// # Example
+///
+/// ```rust
+/// use arrcat::repeat;
+///
+/// const TILED: [u8; 6] = repeat::<_, 2, 3, 6>([3, 5]);
+/// assert_eq!(TILED, [3, 5, 3, 5, 3, 5]);
+///
+/// // the length of the argument can be inferred
+/// assert_eq!(
+///     repeat::<_, _, 2, 6>(["foo", "bar", "baz"]),
+///     ["foo", "bar", "baz", "foo", "bar", "baz"],
+/// );
+///
+/// ```
+pub const fn repeat<T: Copy, const N: usize, const K: usize, const M: usize>(
+    arr: [T; N],
+) -> [T; M] {
+    const {
+        assert!(
+            N * K == M,
+            "the `M` argument of `repeat` must be equal to `N * K`"
+        )
+    };
+
+    let mut out = uninit_array::<T, M>();
+    let mut i = 0;
+    while i < M {
+        out[i] = MaybeUninit::new(arr[i % N]);
+        i += 1;
+    }
+
+    // SAFETY: all elements of `out` were initialized in the loop above
+    unsafe { array_assume_init(out) }
+}
+
+/// Concatenates `K` arrays of the same length `N`, returning an array of length `M`.
+///
+/// `M` must equal `N * K`,
+/// it's a separate parameter because the return type can't be `[T; N * K]` on stable Rust.
+///
+/// For concatenating rows passed as separate arguments, there's also the
+/// [`concat_rows`](crate::concat_rows) macro.
+///
+/// # Compile-time errors
+///
+/// This function causes a compile-time error if `M != N * K`.
+///
+/// # Example
+///
+/// ```rust
+/// use arrcat::concat_exact;
+///
+/// const MATRIX: [u8; 6] = concat_exact([[1, 2, 3], [4, 5, 6]]);
+/// assert_eq!(MATRIX, [1, 2, 3, 4, 5, 6]);
+///
+/// assert_eq!(
+///     concat_exact([["foo".to_string()], ["bar".to_string()]]),
+///     ["foo", "bar"],
+/// );
+///
+/// ```
+pub const fn concat_exact<T, const N: usize, const K: usize, const M: usize>(
+    rows: [[T; N]; K],
+) -> [T; M] {
+    const {
+        assert!(
+            N * K == M,
+            "the `M` argument of `concat_exact` must be equal to `N * K`"
+        )
+    };
+
+    // SAFETY: `[[T; N]; K]` has the same layout as `[T; M]`, since `N * K == M`
+    unsafe { const_transmute!([[T; N]; K], [T; M], rows) }
+}
+
+/// Splits an array into `K` non-overlapping chunks of length `C`.
+///
+/// `K` must equal `N / C`,
+/// it's a separate parameter because the return type can't be `[[T; C]; N / C]`
+/// on stable Rust.
+///
+/// # Compile-time errors
+///
+/// This function causes a compile-time error if any of these is true:
+/// - `C` is zero.
+/// - `N` isn't a multiple of `C`.
+/// - `K != N / C`.
+///
+/// # Example
+///
+/// ```rust
+/// use arrcat::chunks;
+///
+/// const CHUNKS: [[u8; 2]; 3] = chunks([3, 5, 8, 13, 21, 34]);
+/// assert_eq!(CHUNKS, [[3, 5], [8, 13], [21, 34]]);
+///
+/// assert_eq!(
+///     chunks(["foo".to_string(), "bar".to_string()]),
+///     [["foo".to_string()], ["bar".to_string()]],
+/// );
+/// ```
+pub const fn chunks<T, const N: usize, const C: usize, const K: usize>(arr: [T; N]) -> [[T; C]; K] {
+    const {
+        assert!(C != 0, "the chunk length of `chunks` must not be zero");
+        assert!(
+            N % C == 0,
+            "the array length must be a multiple of the chunk length of `chunks`"
+        );
+        assert!(
+            K == N / C,
+            "the `K` argument of `chunks` must be equal to `N / C`"
+        );
+    };
+
+    // SAFETY: `[[T; C]; K]` has the same layout as `[T; N]`, since `C * K == N`
+    unsafe { const_transmute!([T; N], [[T; C]; K], arr) }
+}
+
+/// Splits an array into `K` non-overlapping chunks of length `C`, starting from the end.
+///
+/// Like [`slice::rchunks_exact`], the first chunk is made of the last `C` elements,
+/// so this returns the chunks of [`chunks`] in reverse order.
+///
+/// `K` must equal `N / C`,
+/// it's a separate parameter because the return type can't be `[[T; C]; N / C]`
+/// on stable Rust.
+///
+/// # Compile-time errors
+///
+/// This function causes a compile-time error if any of these is true:
+/// - `C` is zero.
+/// - `N` isn't a multiple of `C`.
+/// - `K != N / C`.
+///
+/// # Example
+///
+/// ```rust
+/// use arrcat::rchunks;
+///
+/// const CHUNKS: [[u8; 2]; 3] = rchunks([3, 5, 8, 13, 21, 34]);
+/// assert_eq!(CHUNKS, [[21, 34], [8, 13], [3, 5]]);
+///
+/// ```
+pub const fn rchunks<T, const N: usize, const C: usize, const K: usize>(
+    arr: [T; N],
+) -> [[T; C]; K] {
+    const {
+        assert!(C != 0, "the chunk length of `rchunks` must not be zero");
+        assert!(
+            N % C == 0,
+            "the array length must be a multiple of the chunk length of `rchunks`"
+        );
+        assert!(
+            K == N / C,
+            "the `K` argument of `rchunks` must be equal to `N / C`"
+        );
+    };
+
+    let arr = ManuallyDrop::new(arr);
+    let src = as_elem_ptr(&arr);
+
+    let mut out = uninit_array::<[T; C], K>();
+    let mut i = 0;
+    while i < K {
+        let offset = N - (i + 1) * C;
+
+        let mut chunk = uninit_array::<T, C>();
+        let mut j = 0;
+        while j < C {
+            // SAFETY: every element of `arr` is read exactly once,
+            // because the chunks don't overlap and cover the entire array.
+            chunk[j] = MaybeUninit::new(unsafe { src.add(offset + j).read() });
+            j += 1;
+        }
+
+        // SAFETY: all elements of `chunk` were initialized in the loop above
+        out[i] = MaybeUninit::new(unsafe { array_assume_init(chunk) });
+        i += 1;
+    }
+
+    // SAFETY: all elements of `out` were initialized in the loop above
+    unsafe { array_assume_init(out) }
+}
+
+/// Borrows an array as `K` non-overlapping chunks of length `C`, without copying it.
+///
+/// This is the borrowing equivalent of [`chunks`], which moves the array instead.
+///
+/// `K` must equal `N / C`,
+/// it's a separate parameter because the return type can't be `&[[T; C]; N / C]`
+/// on stable Rust.
+///
+/// # Compile-time errors
+///
+/// This function causes a compile-time error if any of these is true:
+/// - `C` is zero.
+/// - `N` isn't a multiple of `C`.
+/// - `K != N / C`.
+///
+/// # Example
+///
+/// ```rust
+/// use arrcat::as_chunks;
+///
+/// const ARR: [u8; 6] = [3, 5, 8, 13, 21, 34];
+///
+/// const CHUNKS: &[[u8; 2]; 3] = as_chunks(&ARR);
+/// assert_eq!(CHUNKS, &[[3, 5], [8, 13], [21, 34]]);
+///
+/// let strings = ["foo".to_string(), "bar".to_string()];
+/// let chunks: &[[String; 1]; 2] = as_chunks(&strings);
+/// assert_eq!(chunks[1], ["bar"]);
+///
+/// ```
+pub const fn as_chunks<T, const N: usize, const C: usize, const K: usize>(
+    arr: &[T; N],
+) -> &[[T; C]; K] {
+    const {
+        assert!(C != 0, "the chunk length of `as_chunks` must not be zero");
+        assert!(
+            N % C == 0,
+            "the array length must be a multiple of the chunk length of `as_chunks`"
+        );
+        assert!(
+            K == N / C,
+            "the `K` argument of `as_chunks` must be equal to `N / C`"
+        );
+    };
+
+    // SAFETY: `[[T; C]; K]` has the same layout as `[T; N]`, since `C * K == N`
+    unsafe { &*(arr as *const [T; N] as *const [[T; C]; K]) }
+}
+
+/// Concatenates copies of the arrays that `parts` references,
+/// returning an array of length `M`.
+///
+/// `M` must equal `N * K`,
+/// it's a separate parameter because the return type can't be `[T; N * K]` on stable Rust.
+///
+/// # Compile-time errors
+///
+/// This function causes a compile-time error if `M != N * K`.
+///
+/// # Example
+///
+/// ```rust
+/// use arrcat::concat_refs;
+///
+/// const FOO: [u8; 2] = [3, 5];
+/// const BAR: [u8; 2] = [8, 13];
+///
+/// const TABLE: [u8; 6] = concat_refs([&FOO, &BAR, &FOO]);
+/// assert_eq!(TABLE, [3, 5, 8, 13, 3, 5]);
+///
+/// ```
+pub const fn concat_refs<T: Copy, const N: usize, const K: usize, const M: usize>(
+    parts: [&[T; N]; K],
+) -> [T; M] {
+    const {
+        assert!(
+            N * K == M,
+            "the `M` argument of `concat_refs` must be equal to `N * K`"
+        )
+    };
+
+    let mut out = uninit_array::<T, M>();
+    let mut i = 0;
+    while i < K {
+        let mut j = 0;
+        while j < N {
+            out[i * N + j] = MaybeUninit::new(parts[i][j]);
+            j += 1;
+        }
+        i += 1;
+    }
+
+    // SAFETY: all elements of `out` were initialized in the loops above
+    unsafe { array_assume_init(out) }
+}
+
+/// Copies every overlapping window of length `W` of an array,
+/// returning an array with `C` windows.
+///
+/// `C` must equal `N - W + 1`,
+/// it's a separate parameter because the return type can't be `[[T; W]; N - W + 1]`
+/// on stable Rust.
+///
+/// # Compile-time errors
+///
+/// This function causes a compile-time error if any of these is true:
+/// - `W` is zero.
+/// - `W` is larger than `N`.
+/// - `C != N - W + 1`.
+///
+/// # Example
+///
+/// ```rust
+/// use arrcat::windows;
+///
+/// const WINDOWS: [[u8; 2]; 3] = windows(&[3, 5, 8, 13]);
+/// assert_eq!(WINDOWS, [[3, 5], [5, 8], [8, 13]]);
+///
+/// assert_eq!(windows::<_, 3, 3, 1>(&["foo", "bar", "baz"]), [["foo", "bar", "baz"]]);
+///
+/// ```
+pub const fn windows<T: Copy, const N: usize, const W: usize, const C: usize>(
+    arr: &[T; N],
+) -> [[T; W]; C] {
+    const {
+        assert!(W != 0, "the window length of `windows` must not be zero");
+        assert!(
+            W <= N,
+            "the window length of `windows` must not be larger than the array"
+        );
+        assert!(
+            C == N - W + 1,
+            "the `C` argument of `windows` must be equal to `N - W + 1`"
+        );
+    };
+
+    let mut out = uninit_array::<[T; W], C>();
+    let mut i = 0;
+    while i < C {
+        let mut window = uninit_array::<T, W>();
+        let mut j = 0;
+        while j < W {
+            window[j] = MaybeUninit::new(arr[i + j]);
+            j += 1;
+        }
+
+        // SAFETY: all elements of `window` were initialized in the loop above
+        out[i] = MaybeUninit::new(unsafe { array_assume_init(window) });
+        i += 1;
+    }
+
+    // SAFETY: all elements of `out` were initialized in the loop above
+    unsafe { array_assume_init(out) }
+}
+
+/// A tuple of arrays with the same element type,
+/// which [`concat_tuple`](crate::concat_tuple) concatenates into a single array.
+///
+/// # Safety
+///
+/// `FIELDS` must contain the offset and length of every array field of the tuple,
+/// in field order, and `LEN` must be the sum of those lengths.
+#[doc(hidden)]
+pub unsafe trait ArrayTuple: Sized {
+    type Elem;
+
+    const LEN: usize;
+
+    const FIELDS: &'static [(usize, usize)];
+}
+
+macro_rules! impl_array_tuple {
+    ($( ($($field:tt $len:ident),*) )*) => {$(
+        unsafe impl<T, $(const $len: usize,)*> ArrayTuple for ($([T; $len],)*) {
+            type Elem = T;
+
+            const LEN: usize = 0 $(+ $len)*;
+
+            const FIELDS: &'static [(usize, usize)] = &[$((offset_of!(Self, $field), $len),)*];
+        }
+    )*};
+}
+
+impl_array_tuple! {
+    (0 L0)
+    (0 L0, 1 L1)
+    (0 L0, 1 L1, 2 L2)
+    (0 L0, 1 L1, 2 L2, 3 L3)
+    (0 L0, 1 L1, 2 L2, 3 L3, 4 L4)
+    (0 L0, 1 L1, 2 L2, 3 L3, 4 L4, 5 L5)
+    (0 L0, 1 L1, 2 L2, 3 L3, 4 L4, 5 L5, 6 L6)
+    (0 L0, 1 L1, 2 L2, 3 L3, 4 L4, 5 L5, 6 L6, 7 L7)
+    (0 L0, 1 L1, 2 L2, 3 L3, 4 L4, 5 L5, 6 L6, 7 L7, 8 L8)
+    (0 L0, 1 L1, 2 L2, 3 L3, 4 L4, 5 L5, 6 L6, 7 L7, 8 L8, 9 L9)
+    (0 L0, 1 L1, 2 L2, 3 L3, 4 L4, 5 L5, 6 L6, 7 L7, 8 L8, 9 L9, 10 L10)
+    (0 L0, 1 L1, 2 L2, 3 L3, 4 L4, 5 L5, 6 L6, 7 L7, 8 L8, 9 L9, 10 L10, 11 L11)
+}
+
+#[doc(hidden)]
+pub const fn concat_tuple<Tup: ArrayTuple, const N: usize>(tuple: Tup) -> [Tup::Elem; N] {
+    const {
+        assert!(
+            Tup::LEN == N,
+            "the length of the array returned by `concat_tuple` \
+             must be the sum of the lengths of the tuple's arrays"
+        )
+    };
+
+    let tuple = ManuallyDrop::new(tuple);
+    let src = &tuple as *const ManuallyDrop<Tup> as *const u8;
+
+    let mut out = uninit_array::<Tup::Elem, N>();
+    let dst = &mut out as *mut [MaybeUninit<Tup::Elem>; N] as *mut Tup::Elem;
+
+    let mut written = 0;
+    let mut i = 0;
+    while i < Tup::FIELDS.len() {
+        let (offset, len) = Tup::FIELDS[i];
+
+        // SAFETY: `offset` is the offset of an array field of `tuple` with `len` elements,
+        // and the lengths of all fields add up to `N`.
+        unsafe {
+            ptr::copy_nonoverlapping(src.add(offset) as *const Tup::Elem, dst.add(written), len);
+        }
+
+        written += len;
+        i += 1;
+    }
+
+    // SAFETY: all elements of `out` were initialized in the loop above
+    unsafe { array_assume_init(out) }
+}
+
+const fn as_elem_ptr<T, const N: usize>(arr: &ManuallyDrop<[T; N]>) -> *const T {
+    arr as *const ManuallyDrop<[T; N]> as *const T
+}
@@ -0,0 +1,120 @@
+//! Const functions for concatenating arrays.
+
+use crate::internals::const_transmute;
+
+/// Concatenates two arrays of references.
+///
+/// The returned array borrows for the same lifetime as the arguments.
+///
+/// # Panics
+///
+/// Panics if `C` isn't equal to `A + B`,
+/// which is a compile-time error in const contexts.
+///
+/// # Example
+///
+/// ```rust
+/// use arrcat::concat_ref;
+///
+/// const GREETINGS: [&str; 2] = ["hello", "hi"];
+/// const FAREWELLS: [&str; 1] = ["bye"];
+///
+/// const WORDS: [&str; 3] = concat_ref(GREETINGS, FAREWELLS);
+/// assert_eq!(WORDS, ["hello", "hi", "bye"]);
+///
+/// let number = 8;
+/// let numbers: [&u32; 4] = concat_ref([&3, &5], [&number, &13]);
+/// assert_eq!(numbers, [&3, &5, &8, &13]);
+///
+/// ```
+pub const fn concat_ref<'a, T: ?Sized, const A: usize, const B: usize, const C: usize>(
+    first: [&'a T; A],
+    second: [&'a T; B],
+) -> [&'a T; C] {
+    assert!(
+        A + B == C,
+        "the length of the array returned by `concat_ref` must be the sum of the argument lengths",
+    );
+
+    // SAFETY: `Pair` is a `#[repr(C)]` struct of two arrays of `&'a T`,
+    // so it has the same layout as an `[&'a T; A + B]` array.
+    unsafe { const_transmute!(Pair<&'a T, A, B>, [&'a T; C], Pair { first, second }) }
+}
+
+/// Concatenates two arrays, including arrays whose length is a generic parameter.
+///
+/// Since the return type can't be `[T; A + B]` on stable Rust,
+/// functions generic over the length of the arguments take the length of
+/// the returned array as an extra const parameter, which this function checks.
+///
+/// # Panics
+///
+/// Panics if `C` isn't equal to `A + B`,
+/// which is a compile-time error in const contexts.
+///
+/// # Example
+///
+/// ```rust
+/// use arrcat::concat_pair;
+///
+/// // `M` must be `N + 3`, it's inferred from the context.
+/// const fn push3<const N: usize, const M: usize>(array: [u8; N]) -> [u8; M] {
+///     concat_pair(array, [3, 5, 8])
+/// }
+///
+/// const ARR: [u8; 5] = push3([1, 2]);
+/// assert_eq!(ARR, [1, 2, 3, 5, 8]);
+///
+/// assert_eq!(push3::<0, 3>([]), [3, 5, 8]);
+///
+/// ```
+pub const fn concat_pair<T, const A: usize, const B: usize, const C: usize>(
+    first: [T; A],
+    second: [T; B],
+) -> [T; C] {
+    assert!(
+        A + B == C,
+        "the length of the array returned by `concat_pair` must be the sum of the argument lengths",
+    );
+
+    // SAFETY: `Pair` is a `#[repr(C)]` struct of two arrays of `T`,
+    // so it has the same layout as an `[T; A + B]` array.
+    unsafe { const_transmute!(Pair<T, A, B>, [T; C], Pair { first, second }) }
+}
+
+#[repr(C)]
+struct Pair<T, const A: usize, const B: usize> {
+    first: [T; A],
+    second: [T; B],
+}
+
+/// Selects elements from two arrays, by whether the same index in `mask` is `true`.
+///
+/// For every index `i`, the returned array contains `a[i]` if `mask[i]` is `true`,
+/// otherwise it contains `b[i]`.
+///
+/// This function requires the `"rust_1_83"` crate feature,
+/// because const fns can't have trait bounds in Rust 1.57.0.
+///
+/// # Example
+///
+/// ```rust
+/// use arrcat::select;
+///
+/// const SELECTED: [u8; 4] = select([3, 5, 8, 13], [0, 1, 2, 3], [true, false, false, true]);
+/// assert_eq!(SELECTED, [3, 1, 2, 13]);
+///
+/// ```
+#[cfg(feature = "rust_1_83")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rust_1_83")))]
+pub const fn select<T: Copy, const N: usize>(a: [T; N], b: [T; N], mask: [bool; N]) -> [T; N] {
+    let mut out = a;
+    let mut i = 0;
+    while i < N {
+        if !mask[i] {
+            out[i] = b[i];
+        }
+        i += 1;
+    }
+    out
+}
@@ -0,0 +1,1097 @@
+//! Compile-fail tests, written as doc tests.
+
+/// Overflowing the length of the returned array with the `checked` header
+///
+/// Overflowing the length is an error with or without `checked`,
+/// the header replaces the generic arithmetic overflow error with one that mentions
+/// `concat_arrays` (the exact messages are checked in the `concat_arrays_*_overflow` ui tests).
+///
+/// ```rust
+/// let _: [(); usize::MAX] = arrcat::concat_arrays!(checked; [(); usize::MAX - 1], [()]);
+/// ```
+///
+/// ```compile_fail
+/// let _ = arrcat::concat_arrays!(checked; [(); usize::MAX], [()]);
+/// ```
+///
+/// ```compile_fail
+/// let _ = arrcat::concat_arrays!(checked; [(); usize::MAX / 2 + 1], [(); usize::MAX / 2 + 1]);
+/// ```
+///
+pub struct CheckedLengthOverflow;
+
+/// Calling `swap_halves` with an odd-length array
+///
+/// ```rust
+/// let _ = arrcat::swap_halves([3, 5]);
+/// ```
+///
+/// ```compile_fail
+/// let _ = arrcat::swap_halves([3, 5, 8]);
+/// ```
+///
+/// ```compile_fail
+/// let _ = arrcat::swap_halves([3]);
+/// ```
+///
+#[cfg(feature = "rust_1_83")]
+pub struct SwapHalvesOddLength;
+
+/// Calling `first` or `last` with an empty array
+///
+/// ```rust
+/// let _: u8 = arrcat::first(&[3]);
+/// let _: u8 = arrcat::last(&[3]);
+/// ```
+///
+/// ```compile_fail
+/// let _: u8 = arrcat::first(&[]);
+/// ```
+///
+/// ```compile_fail
+/// let _: u8 = arrcat::last(&[]);
+/// ```
+///
+#[cfg(feature = "rust_1_83")]
+pub struct FirstLastEmpty;
+
+/// Calling `repeat` with an `M` that isn't `N * K`
+///
+/// ```rust
+/// let _: [u8; 6] = arrcat::repeat::<_, 2, 3, 6>([3, 5]);
+/// ```
+///
+/// ```compile_fail
+/// let _: [u8; 5] = arrcat::repeat::<_, 2, 3, 5>([3, 5]);
+/// ```
+///
+/// ```compile_fail
+/// let _: [u8; 7] = arrcat::repeat::<_, 2, 3, 7>([3, 5]);
+/// ```
+///
+#[cfg(feature = "rust_1_83")]
+pub struct RepeatWrongLength;
+
+/// Calling `windows` with invalid window lengths or window counts
+///
+/// ```rust
+/// let _: [[u8; 2]; 2] = arrcat::windows(&[3, 5, 8]);
+/// ```
+///
+/// ```compile_fail
+/// let _: [[u8; 0]; 4] = arrcat::windows(&[3, 5, 8]);
+/// ```
+///
+/// ```compile_fail
+/// let _: [[u8; 4]; 0] = arrcat::windows(&[3, 5, 8]);
+/// ```
+///
+/// ```compile_fail
+/// let _: [[u8; 2]; 3] = arrcat::windows(&[3, 5, 8]);
+/// ```
+///
+#[cfg(feature = "rust_1_83")]
+pub struct WindowsWrongLength;
+
+/// Calling `concat_refs` with an `M` that isn't `N * K`
+///
+/// ```rust
+/// let _: [u8; 4] = arrcat::concat_refs([&[3, 5], &[8, 13]]);
+/// ```
+///
+/// ```compile_fail
+/// let _: [u8; 3] = arrcat::concat_refs([&[3, 5], &[8, 13]]);
+/// ```
+///
+/// ```compile_fail
+/// let _: [u8; 5] = arrcat::concat_refs([&[3, 5], &[8, 13]]);
+/// ```
+///
+#[cfg(feature = "rust_1_83")]
+pub struct ConcatRefsWrongLength;
+
+/// Calling `split_at` with invalid lengths
+///
+/// ```rust
+/// let _: ([u8; 1], [u8; 2]) = arrcat::split_at([3, 5, 8]);
+/// ```
+///
+/// ```compile_fail
+/// let _: ([u8; 4], [u8; 0]) = arrcat::split_at([3, 5, 8]);
+/// ```
+///
+/// ```compile_fail
+/// let _: ([u8; 1], [u8; 1]) = arrcat::split_at([3, 5, 8]);
+/// ```
+///
+#[cfg(feature = "rust_1_83")]
+pub struct SplitAtWrongLength;
+
+/// Calling `chunks` with invalid chunk lengths or chunk counts
+///
+/// ```rust
+/// let _: [[u8; 2]; 2] = arrcat::chunks([3, 5, 8, 13]);
+/// ```
+///
+/// ```compile_fail
+/// let _: [[u8; 0]; 4] = arrcat::chunks([3, 5, 8, 13]);
+/// ```
+///
+/// ```compile_fail
+/// let _: [[u8; 3]; 1] = arrcat::chunks([3, 5, 8, 13]);
+/// ```
+///
+/// ```compile_fail
+/// let _: [[u8; 2]; 3] = arrcat::chunks([3, 5, 8, 13]);
+/// ```
+///
+#[cfg(feature = "rust_1_83")]
+pub struct ChunksWrongLength;
+
+/// Calling `rchunks` with invalid chunk lengths or chunk counts
+///
+/// ```rust
+/// let _: [[u8; 2]; 2] = arrcat::rchunks([3, 5, 8, 13]);
+/// ```
+///
+/// ```compile_fail
+/// let _: [[u8; 0]; 4] = arrcat::rchunks([3, 5, 8, 13]);
+/// ```
+///
+/// ```compile_fail
+/// let _: [[u8; 3]; 1] = arrcat::rchunks([3, 5, 8, 13]);
+/// ```
+///
+/// ```compile_fail
+/// let _: [[u8; 2]; 3] = arrcat::rchunks([3, 5, 8, 13]);
+/// ```
+///
+#[cfg(feature = "rust_1_83")]
+pub struct RChunksWrongLength;
+
+/// Calling `as_chunks` with invalid chunk lengths or chunk counts
+///
+/// ```rust
+/// let _: &[[u8; 2]; 2] = arrcat::as_chunks(&[3, 5, 8, 13]);
+/// ```
+///
+/// ```compile_fail
+/// let _: &[[u8; 0]; 4] = arrcat::as_chunks(&[3, 5, 8, 13]);
+/// ```
+///
+/// ```compile_fail
+/// let _: &[[u8; 3]; 1] = arrcat::as_chunks(&[3, 5, 8, 13]);
+/// ```
+///
+/// ```compile_fail
+/// let _: &[[u8; 2]; 3] = arrcat::as_chunks(&[3, 5, 8, 13]);
+/// ```
+///
+#[cfg(feature = "rust_1_83")]
+pub struct AsChunksWrongLength;
+
+/// Repeating a non-`Copy` argument of `concat_arrays`
+///
+/// ```rust
+/// const C: [&str; 1] = ["foo"];
+/// let var = ["bar"];
+/// let _ = arrcat::concat_arrays!(var * 2: [_; 1], C * 2);
+/// ```
+///
+/// ```compile_fail
+/// let var = [String::new()];
+/// let _ = arrcat::concat_arrays!(var * 2: [_; 1]);
+/// ```
+///
+/// ```compile_fail
+/// const C: [Vec<u8>; 1] = [Vec::new()];
+/// let _ = arrcat::concat_arrays!(C * 2);
+/// ```
+///
+pub struct ConcatArraysRepeatNonCopy;
+
+/// Passing a `lens` header to `concat_arrays` that doesn't match the arguments
+///
+/// ```rust
+/// let (a, b) = ([3u8], [5, 8]);
+/// let _: [u8; 3] = arrcat::concat_arrays!(lens = [1, 2]; a, b);
+/// ```
+///
+/// ```compile_fail
+/// let (a, b) = ([3u8], [5, 8]);
+/// let _: [u8; 3] = arrcat::concat_arrays!(lens = [1, 3]; a, b);
+/// ```
+///
+/// ```compile_fail
+/// let (a, b) = ([3u8], [5, 8]);
+/// let _: [u8; 3] = arrcat::concat_arrays!(lens = [1]; a, b);
+/// ```
+///
+/// ```compile_fail
+/// let (a, b) = ([3u8], [5, 8]);
+/// let _: [u8; 3] = arrcat::concat_arrays!(lens = [1, 2, 0]; a, b);
+/// ```
+///
+pub struct ConcatArraysLensMismatch;
+
+/// Calling `concat_tuple` with the wrong output length or mismatched element types
+///
+/// ```rust
+/// let _: [u8; 5] = arrcat::concat_tuple!(([3u8, 5], [8, 13, 21]));
+/// ```
+///
+/// ```compile_fail
+/// let _: [u8; 4] = arrcat::concat_tuple!(([3u8, 5], [8, 13, 21]));
+/// ```
+///
+/// ```compile_fail
+/// let _: [u8; 6] = arrcat::concat_tuple!(([3u8, 5], [8, 13, 21]));
+/// ```
+///
+/// ```compile_fail
+/// let _: [u8; 5] = arrcat::concat_tuple!(([3u8, 5], [8u16, 13, 21]));
+/// ```
+///
+#[cfg(feature = "rust_1_83")]
+pub struct ConcatTupleWrongLength;
+
+/// Inferring the length of a variable argument with a `[_; _]` annotation
+///
+/// ```rust
+/// const ARR: [u8; 2] = [3, 5];
+/// let var = [8u8, 13];
+/// let _: [u8; 4] = arrcat::concat_arrays!(ARR: [_; _], var: [_; 2]);
+/// ```
+///
+/// ```compile_fail
+/// let var = [8u8, 13];
+/// let _: [u8; 2] = arrcat::concat_arrays!(var: [_; _]);
+/// ```
+///
+pub struct ConcatArraysInferVariableLength;
+
+/// Packing more bits than the integer type has with `pack_bits`
+///
+/// ```rust
+/// let _: u8 = arrcat::pack_bits!(u8; [true; 8]);
+/// let _: u8 = arrcat::pack_bits_msb!(u8; [true; 8]);
+/// ```
+///
+/// ```compile_fail
+/// let _: u8 = arrcat::pack_bits!(u8; [true; 9]);
+/// ```
+///
+/// ```compile_fail
+/// let _: u8 = arrcat::pack_bits_msb!(u8; [true; 9]);
+/// ```
+///
+pub struct PackBitsTooManyBits;
+
+/// Passing non-`cfg` attributes to `concat_arrays` arguments
+///
+/// ```rust
+/// let _: [u8; 1] = arrcat::concat_arrays!(#[cfg(all())] [3]);
+/// ```
+///
+/// ```compile_fail
+/// let _: [u8; 1] = arrcat::concat_arrays!(#[allow(unused)] [3]);
+/// ```
+///
+pub struct ConcatArraysNonCfgAttribute;
+
+/// Deduplicating more elements than the `max_out` of `dedup_consecutive`
+///
+/// ```rust
+/// const _: ([u8; 2], usize) = arrcat::dedup_consecutive!([3, 3, 5, 5], 2);
+/// ```
+///
+/// ```compile_fail
+/// const _: ([u8; 2], usize) = arrcat::dedup_consecutive!([3, 3, 5, 8], 2);
+/// ```
+///
+/// ```compile_fail
+/// const _: ([u8; 1], usize) = arrcat::dedup_consecutive!([0u8; 0], 1);
+/// ```
+///
+pub struct DedupConsecutiveMaxOut;
+
+/// Converting to a narrower or differently signed type with `extend_array`
+///
+/// ```rust
+/// let _: [i16; 2] = arrcat::extend_array!(widen = i16; [3u8, 5]);
+/// ```
+///
+/// ```compile_fail
+/// let _: [u8; 2] = arrcat::extend_array!(widen = u8; [3u16, 5]);
+/// ```
+///
+/// ```compile_fail
+/// let _: [u32; 2] = arrcat::extend_array!(widen = u32; [3i8, -5]);
+/// ```
+///
+/// ```compile_fail
+/// let _: [i16; 2] = arrcat::extend_array!(widen = i16; [3u16, 5]);
+/// ```
+///
+pub struct ExtendArrayNarrowing;
+
+/// Filling an array with a concatenation of a different length, in a const context
+///
+/// ```rust
+/// const _: [u8; 3] = {
+///     let mut buffer = [0u8; 3];
+///     arrcat::fill_concat!(&mut buffer, [3, 5], [8]);
+///     buffer
+/// };
+/// ```
+///
+/// ```compile_fail
+/// const _: [u8; 3] = {
+///     let mut buffer = [0u8; 3];
+///     arrcat::fill_concat!(&mut buffer, [3, 5], [8, 13]);
+///     buffer
+/// };
+/// ```
+///
+/// ```compile_fail
+/// const _: [u8; 3] = {
+///     let mut buffer = [0u8; 3];
+///     arrcat::fill_concat!(&mut buffer, [3, 5]);
+///     buffer
+/// };
+/// ```
+///
+#[cfg(feature = "rust_1_83")]
+pub struct FillConcatWrongLength;
+
+/// Concatenating arrays of zero-sized types with mismatched lengths
+///
+/// ```rust
+/// use core::marker::PhantomData;
+///
+/// const MARKERS: [PhantomData<u8>; 2] = [PhantomData; 2];
+///
+/// let _: [PhantomData<u8>; 3] = arrcat::concat_arrays!(MARKERS, [PhantomData]);
+/// ```
+///
+/// ```compile_fail
+/// use core::marker::PhantomData;
+///
+/// const MARKERS: [PhantomData<u8>; 2] = [PhantomData; 2];
+///
+/// let _: [PhantomData<u8>; 4] = arrcat::concat_arrays!(MARKERS, [PhantomData]);
+/// ```
+///
+/// ```compile_fail
+/// use core::marker::PhantomData;
+///
+/// let markers = [PhantomData::<u8>; 2];
+///
+/// let _: [PhantomData<u8>; 3] = arrcat::concat_arrays!(markers: [_; 1], [PhantomData]);
+/// ```
+///
+pub struct ConcatArraysZeroSizedLengthMismatch;
+
+/// Calling `concat_exact` with an `M` that isn't `N * K`
+///
+/// ```rust
+/// let _: [u8; 4] = arrcat::concat_exact([[3, 5], [8, 13]]);
+/// ```
+///
+/// ```compile_fail
+/// let _: [u8; 3] = arrcat::concat_exact([[3, 5], [8, 13]]);
+/// ```
+///
+/// ```compile_fail
+/// let _: [u8; 5] = arrcat::concat_exact([[3, 5], [8, 13]]);
+/// ```
+///
+#[cfg(feature = "rust_1_83")]
+pub struct ConcatExactWrongLength;
+
+/// Passing rows of different lengths to `concat_rows`
+///
+/// ```rust
+/// const ROW: [u8; 2] = [3, 5];
+/// let _: [u8; 6] = arrcat::concat_rows!(ROW, [8, 13], [21, 34]);
+/// ```
+///
+/// ```compile_fail
+/// const ROW: [u8; 2] = [3, 5];
+/// let _: [u8; 5] = arrcat::concat_rows!(ROW, [8], [21, 34]);
+/// ```
+///
+/// ```compile_fail
+/// const ROW: [u8; 2] = [3, 5];
+/// let _: [u8; 7] = arrcat::concat_rows!([8, 13, 21], ROW, [21, 34]);
+/// ```
+///
+/// ```compile_fail
+/// let row = [3u8, 5];
+/// let _: [u8; 5] = arrcat::concat_rows!([8, 13, 21], row: [_; 2]);
+/// ```
+///
+pub struct ConcatRowsLengthMismatch;
+
+/// Annotating an argument with a `len = $type` length that doesn't match its length
+///
+/// ```rust
+/// struct Len;
+/// impl arrcat::ArrayLength for Len {
+///     const LENGTH: usize = 2;
+/// }
+///
+/// let pair = [3u8, 5];
+/// let _: [u8; 3] = arrcat::concat_arrays!(pair: len = Len, [8]);
+/// ```
+///
+/// ```compile_fail
+/// struct Len;
+/// impl arrcat::ArrayLength for Len {
+///     const LENGTH: usize = 3;
+/// }
+///
+/// let pair = [3u8, 5];
+/// let _: [u8; 4] = arrcat::concat_arrays!(pair: len = Len, [8]);
+/// ```
+///
+/// ```compile_fail
+/// struct Len;
+/// impl arrcat::ArrayLength for Len {
+///     const LENGTH: usize = 1;
+/// }
+///
+/// let _: [u8; 2] = arrcat::concat_arrays!([3, 5]: len = Len, [8]);
+/// ```
+///
+pub struct ConcatArraysLenTypeMismatch;
+
+/// Passing duplicate elements to `concat_unique`
+///
+/// ```rust
+/// const KEYS: [u8; 4] = arrcat::concat_unique!([1, 2], [3, 4]);
+/// ```
+///
+/// ```compile_fail
+/// const KEYS: [u8; 4] = arrcat::concat_unique!([1, 2], [3, 2]);
+/// ```
+///
+/// ```compile_fail
+/// const TAIL: [u8; 2] = [5, 3];
+/// const KEYS: [u8; 4] = arrcat::concat_unique!([3, 4], TAIL);
+/// ```
+///
+/// ```compile_fail
+/// const KEYS: [u8; 3] = arrcat::concat_unique!([7, 7], [8]);
+/// ```
+///
+pub struct ConcatUniqueDuplicate;
+
+/// Passing rows of different lengths to `matrix`
+///
+/// ```rust
+/// const ROW: [u8; 2] = [3, 5];
+/// let _: [[u8; 2]; 3] = arrcat::matrix!(ROW, [8, 13], [21, 34]);
+/// ```
+///
+/// ```compile_fail
+/// const ROW: [u8; 2] = [3, 5];
+/// let _ = arrcat::matrix!(ROW, [8], [21, 34]);
+/// ```
+///
+/// ```compile_fail
+/// const ROW: [u8; 2] = [3, 5];
+/// let _ = arrcat::matrix!([8, 13, 21], ROW);
+/// ```
+///
+pub struct MatrixRaggedRow;
+
+/// Passing arguments longer than the width of `concat_pad_each`
+///
+/// ```rust
+/// let _: [u8; 6] = arrcat::concat_pad_each!(width = 3, fill = 0; [1, 2], [3, 4, 5]);
+/// ```
+///
+/// ```compile_fail
+/// let _ = arrcat::concat_pad_each!(width = 2, fill = 0; [1, 2], [3, 4, 5]);
+/// ```
+///
+/// ```compile_fail
+/// let var = [1u8, 2, 3];
+/// let _ = arrcat::concat_pad_each!(width = 2, fill = 0; var: [_; 3]);
+/// ```
+///
+pub struct ConcatPadEachTooLong;
+
+/// Passing the `elem_type` header without an argument that specifies the element type
+///
+/// ```rust
+/// struct Elem;
+/// let _: [<Elem as arrcat::ElemType>::Elem; 3] =
+///     arrcat::concat_arrays!(elem_type = Elem; [3, 5], [8]: [u8; _]);
+/// ```
+///
+/// ```compile_fail
+/// struct Elem;
+/// let _ = arrcat::concat_arrays!(elem_type = Elem; [3u8, 5], [8]);
+/// ```
+///
+/// ```compile_fail
+/// struct Elem;
+/// let _ = arrcat::concat_arrays!(elem_type = Elem; [3u8, 5]: [_; 2], [8]: [u8; _]);
+/// ```
+///
+/// ```compile_fail
+/// struct Elem;
+/// let _ = arrcat::concat_arrays!([3u8, 5], elem_type = Elem; [8]: [u8; _]);
+/// ```
+///
+/// Passing the `elem_type` header with the same type twice
+///
+/// ```compile_fail
+/// struct Elem;
+/// let _ = arrcat::concat_arrays!(elem_type = Elem; [3u8, 5]: [u8; _]);
+/// let _ = arrcat::concat_arrays!(elem_type = Elem; [8u8]: [u8; _]);
+/// ```
+///
+pub struct ConcatArraysElemTypeUnannotated;
+
+/// Rotating a matrix that isn't square
+///
+/// ```rust
+/// let _ = arrcat::rotate90!([[3u8, 5], [8, 13]]);
+/// ```
+///
+/// ```compile_fail
+/// let _ = arrcat::rotate90!([[3u8, 5, 8], [13, 21, 34]]);
+/// ```
+///
+/// ```compile_fail
+/// let _ = arrcat::rotate180!([[3u8], [5]]);
+/// ```
+///
+/// ```compile_fail
+/// let _ = arrcat::rotate270!([[3u8, 5]]);
+/// ```
+///
+pub struct RotateNonSquare;
+
+/// Exceeding the `max_len` header of `concat_arrays`
+///
+/// ```rust
+/// let _: [u8; 3] = arrcat::concat_arrays!(max_len = 3; [3, 5], [8]);
+/// ```
+///
+/// ```compile_fail
+/// let _: [u8; 4] = arrcat::concat_arrays!(max_len = 3; [3, 5], [8, 13]);
+/// ```
+///
+/// ```compile_fail
+/// const MAX: usize = 1;
+/// let var = [3u8, 5];
+/// let _ = arrcat::concat_arrays!(max_len = MAX; var: [_; 2]);
+/// ```
+///
+/// ```compile_fail
+/// let _ = arrcat::concat_arrays!([3u8], max_len = 3; [8]);
+/// ```
+///
+pub struct ConcatArraysExceedsMaxLen;
+
+/// Passing `slice` arguments to `concat_arrays` whose length doesn't match their annotation
+///
+/// ```rust
+/// const SLICE: &[u8] = &[3, 5];
+/// let _: [u8; 3] = arrcat::concat_arrays!(slice SLICE: [u8; 2], [8]);
+/// ```
+///
+/// ```compile_fail
+/// const SLICE: &[u8] = &[3, 5];
+/// let _: [u8; 4] = arrcat::concat_arrays!(slice SLICE: [u8; 3], [8]);
+/// ```
+///
+/// ```compile_fail
+/// const SLICE: &[u8] = &[3, 5];
+/// let _: [u8; 2] = arrcat::concat_arrays!(slice SLICE: [u8; 1], [8]);
+/// ```
+///
+/// Passing a `slice` argument without a type annotation
+///
+/// ```compile_fail
+/// const SLICE: &[u8] = &[3, 5];
+/// let _: [u8; 3] = arrcat::concat_arrays!(slice SLICE, [8]);
+/// ```
+///
+/// Passing a non-constant `slice` argument
+///
+/// ```compile_fail
+/// let slice: &[u8] = &[3, 5];
+/// let _: [u8; 3] = arrcat::concat_arrays!(slice slice: [u8; 2], [8]);
+/// ```
+///
+pub struct ConcatArraysSliceArgWrongLength;
+
+/// Passing invalid range arguments to `concat_arrays`
+///
+/// ```rust
+/// const A: [u8; 3] = [3, 5, 8];
+/// const _: [u8; 3] = arrcat::concat_arrays!(A[1..]: [_; 2], [13]);
+/// ```
+///
+/// A range that's out of bounds
+///
+/// ```compile_fail
+/// const A: [u8; 3] = [3, 5, 8];
+/// const _: [u8; 4] = arrcat::concat_arrays!(A[1..4], [13]);
+/// ```
+///
+/// A range whose length doesn't match its type annotation
+///
+/// ```compile_fail
+/// const A: [u8; 3] = [3, 5, 8];
+/// const _: [u8; 4] = arrcat::concat_arrays!(A[1..]: [_; 3], [13]);
+/// ```
+///
+/// ```compile_fail
+/// const A: [u8; 3] = [3, 5, 8];
+/// const _: [u8; 2] = arrcat::concat_arrays!(A[0..]: [_; 1], [13]);
+/// ```
+///
+/// ```compile_fail
+/// let var = [3u8, 5, 8];
+/// let _: [u8; 2] = arrcat::concat_arrays!(var[2..]: [_; 2]);
+/// ```
+///
+/// ```compile_fail
+/// let var = [3u8, 5, 8];
+/// let _: [u8; 1] = arrcat::concat_arrays!(var[1..]: [_; 1]);
+/// ```
+///
+/// A range over non-`Copy` elements
+///
+/// ```compile_fail
+/// let var = [String::new(), String::new()];
+/// let _: [String; 1] = arrcat::concat_arrays!(var[1..]: [_; 1]);
+/// ```
+///
+/// A range whose bounds aren't constants
+///
+/// ```compile_fail
+/// let start = 1;
+/// let _: [u8; 2] = arrcat::concat_arrays!([3, 5, 8][start..]: [_; 2]);
+/// ```
+///
+pub struct ConcatArraysRangeArgInvalid;
+
+/// Passing invalid arguments to `concat_interleave_pad`
+///
+/// ```rust
+/// let _: [u8; 6] = arrcat::concat_interleave_pad!(stride = 3, fill = 0; [1, 2], [3, 4]);
+/// ```
+///
+/// More arguments than the stride
+///
+/// ```compile_fail
+/// let _ = arrcat::concat_interleave_pad!(stride = 1, fill = 0; [1, 2], [3, 4]);
+/// ```
+///
+/// Arguments of different lengths
+///
+/// ```compile_fail
+/// let _ = arrcat::concat_interleave_pad!(stride = 3, fill = 0; [1, 2], [3]);
+/// ```
+///
+/// ```compile_fail
+/// let var = [3u8, 5];
+/// let _ = arrcat::concat_interleave_pad!(stride = 3, fill = 0; [1], var: [_; 2]);
+/// ```
+///
+pub struct ConcatInterleavePadInvalidArgs;
+
+/// Passing invalid arguments to `stripe`
+///
+/// ```rust
+/// let _: [u8; 4] = arrcat::stripe!(width = 2; [1, 2], [3, 4]);
+/// ```
+///
+/// A length that isn't a multiple of the width
+///
+/// ```compile_fail
+/// let _: [u8; 3] = arrcat::stripe!(width = 2; [1, 2], [3]);
+/// ```
+///
+/// A zero width
+///
+/// ```compile_fail
+/// let _: [u8; 0] = arrcat::stripe!(width = 0;);
+/// ```
+///
+pub struct StripeInvalidArgs;
+
+/// Passing invalid arguments to `deinterleave`
+///
+/// ```rust
+/// let _: [[u8; 2]; 2] = arrcat::deinterleave!([1, 2, 3, 4], lanes = 2);
+/// ```
+///
+/// A length that isn't a multiple of the amount of lanes
+///
+/// ```compile_fail
+/// let _: [[u8; 1]; 2] = arrcat::deinterleave!([1, 2, 3], lanes = 2);
+/// ```
+///
+/// Zero lanes
+///
+/// ```compile_fail
+/// let _: [[u8; 0]; 0] = arrcat::deinterleave!([0u8; 0], lanes = 0);
+/// ```
+///
+/// A non-constant array without a type annotation
+///
+/// ```compile_fail
+/// let array = [1u8, 2, 3, 4];
+/// let _: [[u8; 2]; 2] = arrcat::deinterleave!(array, lanes = 2);
+/// ```
+///
+pub struct DeinterleaveInvalidArgs;
+
+/// Passing an unsupported alignment to `aligned_concat`
+///
+/// ```rust
+/// let _: arrcat::Aligned32<[u8; 3]> = arrcat::aligned_concat!(align = 32; [1, 2], [3]);
+/// ```
+///
+/// ```compile_fail
+/// let _ = arrcat::aligned_concat!(align = 3; [1, 2], [3]);
+/// ```
+///
+/// ```compile_fail
+/// let _ = arrcat::aligned_concat!(align = 8192; [1, 2], [3]);
+/// ```
+///
+pub struct AlignedConcatUnsupportedAlign;
+
+/// Passing a wrong length for the array returned by `concat_pair`
+///
+/// ```rust
+/// const _: [u8; 3] = arrcat::concat_pair([3], [5, 8]);
+/// ```
+///
+/// ```compile_fail
+/// const _: [u8; 4] = arrcat::concat_pair([3], [5, 8]);
+/// ```
+///
+pub struct ConcatPairWrongLength;
+
+/// Passing invalid arguments to `concat_sat`
+///
+/// ```rust
+/// let _: [u8; 2] = arrcat::concat_sat!(into = u8; [300u16], [-5i8]);
+/// ```
+///
+/// Arguments with non-integer elements
+///
+/// ```compile_fail
+/// let _: [u8; 2] = arrcat::concat_sat!(into = u8; [300u16], [1.5f32]);
+/// ```
+///
+/// Arguments whose element type isn't inferred
+///
+/// ```compile_fail
+/// let _: [u8; 2] = arrcat::concat_sat!(into = u8; [300u16], [5]);
+/// ```
+///
+/// Converting into a non-integer type
+///
+/// ```compile_fail
+/// let _: [f32; 2] = arrcat::concat_sat!(into = f32; [300u16], [5u8]);
+/// ```
+///
+pub struct ConcatSatInvalidArgs;
+
+/// Passing arrays of different lengths to `xor_arrays`
+///
+/// ```rust
+/// const _: [u8; 2] = arrcat::xor_arrays!([3, 5], [8, 13]);
+/// ```
+///
+/// ```compile_fail
+/// const _: [u8; 2] = arrcat::xor_arrays!([3, 5], [8]);
+/// ```
+///
+/// ```compile_fail
+/// let _ = arrcat::xor_arrays!([3u8], [5, 8]);
+/// ```
+///
+pub struct XorArraysLengthMismatch;
+
+/// Passing the `with_offsets` header to `concat_arrays` without a `length_type` header
+///
+/// ```rust
+/// struct L;
+/// let _: [u8; 2] = arrcat::concat_arrays!(length_type = L; with_offsets; [3], [5]);
+/// ```
+///
+/// ```compile_fail
+/// let _: [u8; 2] = arrcat::concat_arrays!(with_offsets; [3], [5]);
+/// ```
+///
+/// ```compile_fail
+/// struct L;
+/// let _: [u8; 2] = arrcat::concat_arrays!(with_offsets; length_type = L; [3], [5]);
+/// ```
+///
+/// ```compile_fail
+/// trait Len { const LEN: usize; }
+/// let _: [u8; 2] = arrcat::concat_arrays!(length_via = Len; with_offsets; [3], [5]);
+/// ```
+///
+/// Passing the `with_offsets` header twice
+///
+/// ```compile_fail
+/// struct L;
+/// let _: [u8; 2] = arrcat::concat_arrays!(
+///     length_type = L; with_offsets; with_offsets as OTHER; [3], [5]
+/// );
+/// ```
+///
+pub struct ConcatArraysWithOffsetsMisplaced;
+
+/// Passing invalid arguments to `concat_checksummed`
+///
+/// ```rust
+/// let _: ([u8; 2], u8) = arrcat::concat_checksummed!(checksum = xor; [3u8], [5]);
+/// ```
+///
+/// An unknown checksum
+///
+/// ```compile_fail
+/// let _: ([u8; 2], u8) = arrcat::concat_checksummed!(checksum = crc; [3u8], [5]);
+/// ```
+///
+/// Non-integer elements
+///
+/// ```compile_fail
+/// let _: ([f32; 2], f32) = arrcat::concat_checksummed!([3.0f32], [5.0]);
+/// ```
+///
+pub struct ConcatChecksummedInvalidArgs;
+
+/// Tiling an empty pattern with `tile_to`
+///
+/// ```rust
+/// const _: [u8; 0] = arrcat::tile_to!([0u8; 0], 0);
+/// ```
+///
+/// ```compile_fail
+/// const _: [u8; 1] = arrcat::tile_to!([0u8; 0], 1);
+/// ```
+///
+pub struct TileToEmptyPattern;
+
+/// Passing invalid arguments to `bytes_of`
+///
+/// ```rust
+/// let runtime = 5u32;
+/// let _: [u8; 6] = arrcat::bytes_of!(le; 3u16, runtime: u32);
+/// ```
+///
+/// An unknown endianness
+///
+/// ```compile_fail
+/// let _: [u8; 2] = arrcat::bytes_of!(middle; 3u16);
+/// ```
+///
+/// An integer whose type isn't inferred
+///
+/// ```compile_fail
+/// let _: [u8; 4] = arrcat::bytes_of!(le; 3);
+/// ```
+///
+/// A non-constant integer without a type annotation
+///
+/// ```compile_fail
+/// let runtime = 5u32;
+/// let _: [u8; 6] = arrcat::bytes_of!(le; 3u16, runtime);
+/// ```
+///
+pub struct BytesOfInvalidArgs;
+
+/// Passing invalid arguments to `with_len_header`
+///
+/// ```rust
+/// let _: [u8; 257] = arrcat::with_len_header!(u16; [0u8; 255]);
+/// ```
+///
+/// A payload that's too long for the header type
+///
+/// ```compile_fail
+/// let _: [u8; 257] = arrcat::with_len_header!(u8; [0u8; 256]);
+/// ```
+///
+/// A payload whose elements aren't bytes
+///
+/// ```compile_fail
+/// let _: [u8; 4] = arrcat::with_len_header!(u16; [0u16; 2]);
+/// ```
+///
+pub struct WithLenHeaderInvalidArgs;
+
+/// Passing a concatenation shorter than the `max` length to `concat_clamp`
+///
+/// ```rust
+/// const _: [u8; 3] = arrcat::concat_clamp!(max = 3; [1], [2, 3]);
+/// ```
+///
+/// ```compile_fail
+/// const _: [u8; 4] = arrcat::concat_clamp!(max = 4; [1], [2, 3]);
+/// ```
+///
+pub struct ConcatClampTooShort;
+
+/// Passing a length to `identity_perm` that doesn't fit in the element type
+///
+/// ```rust
+/// let _ = arrcat::identity_perm!(256, u8);
+/// let _ = arrcat::identity_perm!(128, i8);
+/// ```
+///
+/// ```compile_fail
+/// let _ = arrcat::identity_perm!(257, u8);
+/// ```
+///
+/// ```compile_fail
+/// let _ = arrcat::identity_perm!(129, i8);
+/// ```
+///
+pub struct IdentityPermOverflow;
+
+/// Passing out of bounds indices to `apply_permutation`
+///
+/// ```rust
+/// const _: [u8; 3] = arrcat::apply_permutation!([3, 5, 8], [2, 1, 0]);
+/// ```
+///
+/// ```compile_fail
+/// const _: [u8; 3] = arrcat::apply_permutation!([3, 5, 8], [3, 1, 0]);
+/// ```
+///
+/// Passing a permutation of a different length than the array
+///
+/// ```compile_fail
+/// const _: [u8; 3] = arrcat::apply_permutation!([3, 5, 8], [1, 0]);
+/// ```
+///
+pub struct ApplyPermutationOutOfBounds;
+
+/// Passing out of bounds indices to `gather`
+///
+/// ```rust
+/// const _: [u8; 4] = arrcat::gather!([3, 5, 8], [2, 1, 2, 0]);
+/// ```
+///
+/// ```compile_fail
+/// const _: [u8; 4] = arrcat::gather!([3, 5, 8], [2, 1, 3, 0]);
+/// ```
+///
+/// ```compile_fail
+/// const _: [u8; 1] = arrcat::gather!([0u8; 0], [0]);
+/// ```
+///
+pub struct GatherOutOfBounds;
+
+/// Passing out of bounds indices to `scatter`
+///
+/// ```rust
+/// const _: [u8; 4] = arrcat::scatter!(0, [3, 5, 8], [2, 1, 3]);
+/// ```
+///
+/// ```compile_fail
+/// const _: [u8; 4] = arrcat::scatter!(0, [3, 5, 8], [2, 1, 4]);
+/// ```
+///
+/// ```compile_fail
+/// const _: [u8; 0] = arrcat::scatter!(0, [3], [0]);
+/// ```
+///
+/// Passing indices of a different length than the array
+///
+/// ```compile_fail
+/// const _: [u8; 4] = arrcat::scatter!(0, [3, 5, 8], [2, 1]);
+/// ```
+///
+pub struct ScatterOutOfBounds;
+
+/// Returning an array of the wrong length from `concat_ref`
+///
+/// ```rust
+/// const _: [&u8; 3] = arrcat::concat_ref([&3], [&5, &8]);
+/// ```
+///
+/// ```compile_fail
+/// const _: [&u8; 4] = arrcat::concat_ref([&3], [&5, &8]);
+/// ```
+///
+/// The returned references can't outlive the argument references
+///
+/// ```compile_fail
+/// fn foo<'a>(x: &'a u8, y: &'static u8) -> [&'static u8; 2] {
+///     arrcat::concat_ref([x], [y])
+/// }
+/// ```
+///
+pub struct ConcatRefWrongLength;
+
+/// Nesting a `concat_arrays` invocation through a path other than `arrcat::concat_arrays`,
+/// without a type annotation
+///
+/// ```rust
+/// let var = [3u8, 5];
+/// let _ = arrcat::concat_arrays!(arrcat::concat_arrays!(var: [_; 2]), [8]);
+/// let _ = arrcat::concat_arrays!(::arrcat::concat_arrays!(var: [_; 2]), [8]);
+/// ```
+///
+/// ```compile_fail
+/// use arrcat as renamed;
+/// let var = [3u8, 5];
+/// let _ = arrcat::concat_arrays!(renamed::concat_arrays!(var: [_; 2]), [8]);
+/// ```
+///
+pub struct ConcatArraysNestedOtherPath;
+
+/// Passing non-array arguments to `concat_struct`
+///
+/// ```rust
+/// let _ = arrcat::concat_struct!([3u8], [5u16; 2]);
+/// ```
+///
+/// ```compile_fail
+/// let _ = arrcat::concat_struct!([3u8], 5u16);
+/// ```
+///
+/// ```compile_fail
+/// let slice: &[u8] = &[3, 5];
+/// let _ = arrcat::concat_struct!(slice);
+/// ```
+///
+pub struct ConcatStructNonArray;
+
+/// Passing non-`&str` literals to `concat_str_bytes`
+///
+/// ```rust
+/// const _: [u8; 4] = arrcat::concat_str_bytes!("ab", (*b"cd"));
+/// ```
+///
+/// ```compile_fail
+/// const _: [u8; 4] = arrcat::concat_str_bytes!("ab", b"cd");
+/// ```
+///
+/// ```compile_fail
+/// const _: [u8; 3] = arrcat::concat_str_bytes!("ab", 'c');
+/// ```
+///
+/// ```compile_fail
+/// const _: [u8; 3] = arrcat::concat_str_bytes!("ab", 3);
+/// ```
+///
+pub struct ConcatStrBytesNonStrLiteral;
@@ -1,12 +1,24 @@
-use core::{marker::PhantomData, mem::ManuallyDrop};
+use core::{
+    marker::PhantomData,
+    mem::{ManuallyDrop, MaybeUninit},
+};
 
 #[doc(hidden)]
 #[derive(Copy, Clone)]
 pub struct Usize<const N: usize>;
 
 impl<const N: usize> Usize<N> {
+    // takes the array by value, because borrowing arrays with
+    // interior mutable elements (eg: `Cell`s) isn't allowed in const contexts before Rust 1.83.
+    #[cfg(not(feature = "rust_1_83"))]
     #[inline(always)]
-    pub const fn infer_mda<T>(self, _: &ManuallyDrop<[T; N]>) {}
+    pub const fn infer_mda<T>(self, _: ManuallyDrop<[T; N]>) {}
+
+    // goes through the `ArrayArgument` trait to replace the mismatched types error
+    // for non-array arguments (eg: slices) with a more specific one.
+    #[cfg(feature = "rust_1_83")]
+    #[inline(always)]
+    pub const fn infer_mda<T>(self, _: ManuallyDrop<impl ArrayArgument<T, N>>) {}
 
     #[inline(always)]
     pub const fn get(self) -> usize {
@@ -14,6 +26,19 @@ impl<const N: usize> Usize<N> {
     }
 }
 
+#[cfg(feature = "rust_1_83")]
+#[doc(hidden)]
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` is not an array `[T; N]`, so it can't be an argument of `concat_arrays`",
+    label = "expected an array",
+    note = "if this is a slice `&[T]`, specify its length with a `: [T; N]` annotation, \
+            or use the `slice <expr>: [T; N]` argument syntax to copy a slice constant into an array"
+)]
+pub trait ArrayArgument<T, const N: usize> {}
+
+#[cfg(feature = "rust_1_83")]
+impl<T, const N: usize> ArrayArgument<T, N> for [T; N] {}
+
 ///
 /// # Safety
 ///
@@ -26,6 +51,7 @@ pub unsafe trait GetTypeParam: Sized {
     const PROOF: TypeParam<Self, Self::T> = TypeParam { types: PhantomData };
 }
 
+#[allow(clippy::type_complexity)]
 pub struct TypeParam<S, T> {
     types: PhantomData<fn() -> (PhantomData<S>, PhantomData<T>)>,
 }
@@ -42,6 +68,9 @@ impl<S, T> TypeParam<S, T> {
     #[inline(always)]
     pub const fn assert_type_param(self, _: PhantomData<T>) {}
 
+    /// # Safety
+    ///
+    /// `T` must be the only type parameter of `S`.
     pub const unsafe fn new_unchecked() -> Self {
         Self::__NEW
     }
@@ -49,29 +78,435 @@ impl<S, T> TypeParam<S, T> {
     const __NEW: Self = Self { types: PhantomData };
 }
 
-pub unsafe trait ArrayLength {
+/// A type with an associated array length.
+///
+/// This is implemented for all arrays,
+/// and can be implemented for marker types to pass lengths as types,
+/// eg: with the `len = $type` annotation of [`concat_arrays`](crate::concat_arrays).
+///
+/// `concat_arrays` checks that the argument has the `LENGTH` length,
+/// so an incorrect `LENGTH` causes a compile-time error.
+///
+/// # Example
+///
+/// ```rust
+/// use arrcat::{concat_arrays, ArrayLength};
+///
+/// struct HeaderLen;
+///
+/// impl ArrayLength for HeaderLen {
+///     const LENGTH: usize = 2;
+/// }
+///
+/// const fn with_header(header: [u8; 2]) -> [u8; 4] {
+///     concat_arrays!(header: len = HeaderLen, [3, 5])
+/// }
+///
+/// assert_eq!(<[u8; 3] as ArrayLength>::LENGTH, 3);
+/// assert_eq!(with_header([0, 1]), [0, 1, 3, 5]);
+/// ```
+pub trait ArrayLength {
+    /// The length of the array.
     const LENGTH: usize;
 }
 
+/// A type with an associated element type,
+/// implemented by the `elem_type = $type` header of [`concat_arrays`](crate::concat_arrays).
+///
+/// # Example
+///
+/// ```rust
+/// use arrcat::{concat_arrays, ElemType};
+///
+/// struct Point;
+///
+/// const POINTS: [<Point as ElemType>::Elem; 3] = concat_arrays!{
+///     elem_type = Point;
+///     [(3, 5)]: [(u8, u16); _],
+///     [(8, 13), (21, 34)],
+/// };
+///
+/// let last: <Point as ElemType>::Elem = POINTS[2];
+/// assert_eq!(last, (21u8, 34u16));
+/// ```
+pub trait ElemType {
+    /// The element type.
+    type Elem;
+}
+
+/// Gets the length of a two-level nested array type, as if it were flattened.
+///
+/// The flattened length of `[[T; M]; N]` is `M * N`,
+/// for one-level arrays use [`ArrayLength`] instead.
+///
+/// This trait isn't implemented for one-level `[T; N]` arrays,
+/// because that impl would overlap with the `[[T; M]; N]` impl
+/// (`T` could itself be an array), which coherence rejects.
+///
+/// # Safety
+///
+/// `FLAT_LENGTH` must be the amount of `T`s in the implementor array type.
+pub unsafe trait FlatArrayLength {
+    const FLAT_LENGTH: usize;
+}
+
 unsafe impl<T, const L: usize> GetTypeParam for [T; L] {
     type T = T;
 }
 
-unsafe impl<T, const L: usize> ArrayLength for [T; L] {
+impl<T, const L: usize> ArrayLength for [T; L] {
     const LENGTH: usize = L;
 }
 
-#[repr(transparent)]
-pub struct Identity<T> {
-    pub inner: T,
+unsafe impl<T, const M: usize, const N: usize> FlatArrayLength for [[T; M]; N] {
+    const FLAT_LENGTH: usize = M * N;
+}
+
+#[inline(always)]
+pub const fn assert_same_length<T, U, const N: usize>(_: &[T; N], _: &[U; N]) {}
+
+#[inline(always)]
+pub const fn assert_square<T, const N: usize>(_: &[[T; N]; N]) {}
+
+pub struct ArrayLen<const N: usize>;
+
+#[inline(always)]
+pub const fn array_len_of<T, const N: usize>(_: &[T; N]) -> ArrayLen<N> {
+    ArrayLen
+}
+
+#[inline(always)]
+pub const fn assert_same_array_len<const N: usize>(_: ArrayLen<N>, _: ArrayLen<N>) {}
+
+pub struct RangeArgEnd<const END: usize>;
+
+impl<const END: usize> RangeArgEnd<END> {
+    #[inline(always)]
+    pub const fn assert_array_len<T, const N: usize>(self, _: &[T; N]) {
+        AssertRangeArgEnd::<END, N>::ASSERT
+    }
+}
+
+struct AssertRangeArgEnd<const END: usize, const N: usize>;
+
+impl<const END: usize, const N: usize> AssertRangeArgEnd<END, N> {
+    // an associated constant, so that this is checked at compile-time
+    // even when the array isn't a constant.
+    const ASSERT: () = assert!(
+        END == N,
+        "the length of the range of an argument of `concat_arrays` \
+         doesn't match its type annotation",
+    );
+}
+
+pub const fn str_to_bytes<const N: usize>(string: &str) -> [u8; N] {
+    let bytes = string.as_bytes();
+    assert!(bytes.len() == N);
+
+    let mut out = [0u8; N];
+    let mut i = 0;
+    while i < N {
+        out[i] = bytes[i];
+        i += 1;
+    }
+    out
+}
+
+#[inline(always)]
+pub const fn uninit_array<T, const N: usize>() -> [MaybeUninit<T>; N] {
+    // SAFETY: `[MaybeUninit<T>; N]` has the same layout as `MaybeUninit<[T; N]>`,
+    // and doesn't require its contents to be initialized.
+    unsafe {
+        const_transmute!(
+            MaybeUninit<[T; N]>,
+            [MaybeUninit<T>; N],
+            MaybeUninit::<[T; N]>::uninit()
+        )
+    }
+}
+
+#[inline(always)]
+pub const fn array_into_uninit<T, const N: usize>(arr: [T; N]) -> [MaybeUninit<T>; N] {
+    // SAFETY: `MaybeUninit<T>` has the same layout as `T`
+    unsafe { const_transmute!([T; N], [MaybeUninit<T>; N], arr) }
+}
+
+/// # Safety
+///
+/// `val` must be initialized.
+#[inline(always)]
+pub const unsafe fn maybe_uninit_into_inner<T>(val: MaybeUninit<T>) -> T {
+    const_transmute!(MaybeUninit<T>, T, val)
+}
+
+/// # Safety
+///
+/// All elements of `arr` must be initialized.
+#[inline(always)]
+pub const unsafe fn array_assume_init<T, const N: usize>(arr: [MaybeUninit<T>; N]) -> [T; N] {
+    const_transmute!([MaybeUninit<T>; N], [T; N], arr)
+}
+
+pub const fn count_true<const N: usize>(bits: &[bool; N]) -> usize {
+    let mut count = 0;
+    let mut i = 0;
+    while i < N {
+        count += bits[i] as usize;
+        i += 1;
+    }
+    count
+}
+
+pub const fn mask_indices<const N: usize>(mask: &[bool; N]) -> ([usize; N], usize) {
+    let mut indices = [0usize; N];
+    let mut count = 0;
+    let mut i = 0;
+    while i < N {
+        if mask[i] {
+            indices[count] = i;
+            count += 1;
+        }
+        i += 1;
+    }
+    (indices, count)
+}
+
+pub const fn prefix_offsets<const N: usize>(lens: [usize; N]) -> [usize; N] {
+    let mut offsets = [0usize; N];
+    let mut offset = 0;
+    let mut i = 0;
+    while i < N {
+        offsets[i] = offset;
+        offset += lens[i];
+        i += 1;
+    }
+    offsets
+}
+
+const CRC32_TABLE: [u32; 256] = {
+    let mut table = [0u32; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut crc = i as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xEDB8_8320
+            } else {
+                crc >> 1
+            };
+            bit += 1;
+        }
+        table[i] = crc;
+        i += 1;
+    }
+    table
+};
+
+pub const fn crc32<const N: usize>(bytes: &[u8; N]) -> u32 {
+    let mut crc = !0u32;
+    let mut i = 0;
+    while i < N {
+        crc = (crc >> 8) ^ CRC32_TABLE[((crc ^ bytes[i] as u32) & 0xFF) as usize];
+        i += 1;
+    }
+    !crc
+}
+
+pub struct Widen<From_, To> {
+    types: PhantomData<fn(From_) -> To>,
+}
+
+impl<From_, To> Copy for Widen<From_, To> {}
+
+impl<From_, To> Clone for Widen<From_, To> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<From_, To> Widen<From_, To> {
+    #[inline(always)]
+    pub const fn new<const N: usize>(_: &[From_; N], _: PhantomData<To>) -> Self {
+        Self { types: PhantomData }
+    }
+}
+
+// `as` casts zero-extend unsigned integers and sign-extend signed integers,
+// these impls only allow the casts that don't lose information.
+macro_rules! impl_widen {
+    ($($from:ident => [$($to:ident),*],)*) => {$($(
+        impl Widen<$from, $to> {
+            #[inline(always)]
+            pub const fn zeroed<const N: usize>(self, _: &[$from; N]) -> [$to; N] {
+                [0; N]
+            }
+
+            #[inline(always)]
+            pub const fn widen(self, x: $from) -> $to {
+                x as $to
+            }
+        }
+    )*)*};
+}
+
+impl_widen! {
+    u8 => [u16, u32, u64, u128, usize, i16, i32, i64, i128, isize],
+    u16 => [u32, u64, u128, usize, i32, i64, i128],
+    u32 => [u64, u128, i64, i128],
+    u64 => [u128, i128],
+    i8 => [i16, i32, i64, i128, isize],
+    i16 => [i32, i64, i128, isize],
+    i32 => [i64, i128],
+    i64 => [i128],
 }
 
-#[repr(transparent)]
-pub struct ArrayAndGhost<T, const LEN: usize> {
-    pub inner: [T; LEN],
-    pub elem_ty: PhantomData<T>,
+pub struct Checksum<'a, T, const N: usize> {
+    array: &'a [T; N],
 }
 
+impl<'a, T, const N: usize> Checksum<'a, T, N> {
+    #[inline(always)]
+    pub const fn new(array: &'a [T; N]) -> Self {
+        Self { array }
+    }
+}
+
+macro_rules! impl_checksum {
+    ($($int:ident)*) => {$(
+        impl<const N: usize> Checksum<'_, $int, N> {
+            pub const fn sum(self) -> $int {
+                let mut sum: $int = 0;
+                let mut i = 0;
+                while i < N {
+                    sum = sum.wrapping_add(self.array[i]);
+                    i += 1;
+                }
+                sum
+            }
+
+            pub const fn xor(self) -> $int {
+                let mut xor: $int = 0;
+                let mut i = 0;
+                while i < N {
+                    xor ^= self.array[i];
+                    i += 1;
+                }
+                xor
+            }
+        }
+    )*};
+}
+
+impl_checksum! {u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize}
+
+pub struct Saturating<From_, To, const N: usize> {
+    array: [From_; N],
+    to: PhantomData<fn() -> To>,
+}
+
+impl<From_, To, const N: usize> Saturating<From_, To, N> {
+    #[inline(always)]
+    pub const fn new(array: [From_; N], to: PhantomData<To>) -> Self {
+        let _ = to;
+        Self {
+            array,
+            to: PhantomData,
+        }
+    }
+}
+
+// Comparing the bounds of both types as `i128`s (for signed sources)
+// or `u128`s (for unsigned sources), since every integer converts losslessly
+// to one of those types.
+macro_rules! impl_saturating {
+    (
+        signed[$($signed:ident),*]
+        unsigned[$($unsigned:ident),*]
+        into $to_types:tt
+    ) => {
+        $( impl_saturating!{@signed $signed => $to_types} )*
+        $( impl_saturating!{@unsigned $unsigned => $to_types} )*
+    };
+    (@signed $from:ident => [$($to:ident),*]) => {$(
+        impl<const N: usize> Saturating<$from, $to, N> {
+            pub const fn saturate(self) -> [$to; N] {
+                let mut out = [0; N];
+                let mut i = 0;
+                while i < N {
+                    let x = self.array[i];
+                    out[i] = if (x as i128) < ($to::MIN as i128) {
+                        $to::MIN
+                    } else if x > 0 && (x as u128) > ($to::MAX as u128) {
+                        $to::MAX
+                    } else {
+                        x as $to
+                    };
+                    i += 1;
+                }
+                out
+            }
+        }
+    )*};
+    (@unsigned $from:ident => [$($to:ident),*]) => {$(
+        impl<const N: usize> Saturating<$from, $to, N> {
+            pub const fn saturate(self) -> [$to; N] {
+                let mut out = [0; N];
+                let mut i = 0;
+                while i < N {
+                    let x = self.array[i];
+                    out[i] = if (x as u128) > ($to::MAX as u128) {
+                        $to::MAX
+                    } else {
+                        x as $to
+                    };
+                    i += 1;
+                }
+                out
+            }
+        }
+    )*};
+}
+
+impl_saturating! {
+    signed[i8, i16, i32, i64, i128, isize]
+    unsigned[u8, u16, u32, u64, u128, usize]
+    into[u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize]
+}
+
+#[inline(always)]
+pub const fn identity<T>(val: T) -> T {
+    val
+}
+
+#[inline(always)]
+pub const fn array_with_elem_ty<T, const LEN: usize>(
+    array: [T; LEN],
+    _: PhantomData<T>,
+) -> [T; LEN] {
+    array
+}
+
+pub const fn flatten_repeated<T, const L: usize, const C: usize, const LC: usize>(
+    array: [[T; L]; C],
+) -> [T; LC] {
+    assert!(L * C == LC);
+
+    // SAFETY: `[[T; L]; C]` has the same layout as `[T; LC]`, since `L * C == LC`
+    unsafe { const_transmute!([[T; L]; C], [T; LC], array) }
+}
+
+/// # Safety
+///
+/// `From_` must be a `#[repr(C, packed)]` struct whose fields are all `[T; _]` arrays.
+///
+/// The size assertion is trivially true for zero-sized `T`s,
+/// the lengths are instead checked by the types of `From_`'s fields and `CONCAT_LEN`,
+/// which are computed from the arguments of `concat_arrays`.
+///
+/// Since every field is an array of the same `T` type,
+/// the layout of `T` itself (eg: the field order of a tuple) doesn't matter,
+/// it's the same for every element of both `From_` and the returned array.
 #[doc(hidden)]
 pub const unsafe fn concat_arrays<From_, T, const CONCAT_LEN: usize>(
     this: From_,
@@ -107,4 +542,4 @@ macro_rules! const_transmute {
         )
     };
 }
-use const_transmute;
+pub(crate) use const_transmute;
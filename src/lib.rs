@@ -36,6 +36,17 @@
 //!
 //! ```
 //!
+//! # Cargo features
+//!
+//! - `"rust_1_83"`:
+//!   Enables const functions that manipulate arrays of generic length,
+//!   like `swap_halves`, and the `select` function, which has a `T: Copy` bound.
+//!   This requires Rust 1.83.0.
+//!
+//! - `"bytemuck"`:
+//!   Enables the `pod_concat` and `bytes_concat` macros, and the `PodBytes` type,
+//!   which require the element type to implement `bytemuck::Pod`.
+//!
 //! # No-std support
 //!
 //! `arrcat` is `#![no_std]`, it can be used anywhere Rust can be used.
@@ -48,24 +59,77 @@
 //! [concat_arrays_examples]: crate::concat_arrays#examples
 
 #![no_std]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+#![cfg_attr(all(test, feature = "__test_nightly"), feature(const_trait_impl))]
 
 #[cfg(test)]
 mod tests;
 
+#[cfg(doctest)]
+mod doctests;
+
 mod internals;
 
 #[macro_use]
 mod macros;
 
+mod concat_fns;
+
+mod aligned;
+
+#[cfg(feature = "rust_1_83")]
+#[clippy::msrv = "1.83.0"]
+mod array_fns;
+
+#[cfg(feature = "bytemuck")]
+mod pod;
+
+pub use crate::concat_fns::{concat_pair, concat_ref};
+
+#[cfg(feature = "rust_1_83")]
+pub use crate::concat_fns::select;
+
+pub use crate::aligned::{
+    Aligned1024, Aligned128, Aligned16, Aligned2, Aligned2048, Aligned256, Aligned32, Aligned4,
+    Aligned4096, Aligned512, Aligned64, Aligned8,
+};
+
+pub use crate::internals::{ArrayLength, ElemType};
+
+#[cfg(feature = "bytemuck")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bytemuck")))]
+pub use crate::pod::PodBytes;
+
+#[cfg(feature = "rust_1_83")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rust_1_83")))]
+pub use crate::array_fns::{
+    as_chunks, chunks, concat_exact, concat_refs, first, last, rchunks, repeat, rotate_left,
+    rotate_right, split_at, swap_halves, windows,
+};
+
 #[doc(hidden)]
 pub mod __ {
     pub use core::{
-        compile_error, concat, marker::PhantomData, mem::ManuallyDrop, primitive::usize, stringify,
+        assert,
+        cmp::Ordering,
+        compile_error, concat,
+        marker::PhantomData,
+        matches,
+        mem::{size_of, ManuallyDrop, MaybeUninit},
+        option::Option::{None, Some},
+        primitive::{bool, u128, u8, usize},
+        stringify,
     };
 
     pub use crate::internals::*;
+
+    #[cfg(feature = "rust_1_83")]
+    pub use crate::array_fns::{concat_tuple, ArrayTuple};
+
+    #[cfg(feature = "bytemuck")]
+    pub use crate::pod::pod_array;
 }
 
-#[cfg(feature = "derive")]
+#[cfg(doctest)]
 #[doc = include_str!("../README.md")]
 pub struct ReadmeTest;
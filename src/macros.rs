@@ -1,2 +1,73 @@
 #[macro_use]
 mod concat_array_macro;
+
+#[macro_use]
+mod concat_struct_macro;
+
+#[macro_use]
+mod index_of_macros;
+
+#[macro_use]
+mod permutation_macros;
+
+#[macro_use]
+mod concat_str_bytes_macro;
+
+#[macro_use]
+mod concat_map_macro;
+
+#[macro_use]
+mod for_each_concat_macro;
+
+#[macro_use]
+mod assertion_macros;
+
+#[macro_use]
+mod arr_concat_macro;
+
+#[macro_use]
+mod trim_macros;
+
+#[macro_use]
+mod fold_macros;
+
+#[cfg(feature = "rust_1_83")]
+#[macro_use]
+mod concat_tuple_macro;
+
+#[cfg(feature = "rust_1_83")]
+#[macro_use]
+mod static_concat_macro;
+
+#[macro_use]
+mod bits_macros;
+
+#[macro_use]
+mod dedup_macro;
+
+#[macro_use]
+mod checksum_macros;
+
+#[macro_use]
+mod extend_array_macro;
+
+#[macro_use]
+mod concat_from_fn_macro;
+
+#[macro_use]
+mod fill_concat_macro;
+
+#[macro_use]
+mod enum_array_macro;
+
+#[macro_use]
+mod sort_macros;
+
+#[macro_use]
+mod concat_rows_macro;
+
+#[macro_use]
+mod rotate_macros;
+
+#[macro_use]
+mod companion_array_macro;
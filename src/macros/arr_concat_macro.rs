@@ -0,0 +1,57 @@
+/// Concatenates multiple arrays into one, with the arrays separated by `++`.
+///
+/// This macro is callable in const contexts.
+///
+/// This is an alternative syntax for [`concat_arrays`](crate::concat_arrays),
+/// every argument and header of that macro is supported by this one.
+///
+/// # Syntax
+///
+/// The syntax of this macro, using `macro_rules!`-like input syntax
+///
+/// ```text
+/// arr_concat!{
+///     $( $header:header ;)*
+///     
+///     $( $array_arg:array_expr $(* $count:tt)? $(: $argument_type:ty )? )++*
+/// }
+/// ```
+///
+/// Where `$header`, `$array_arg`, `$count`, and `$argument_type`
+/// are described in the docs for [`concat_arrays`](crate::concat_arrays#syntax).
+///
+/// # Example
+///
+/// ```rust
+/// use arrcat::arr_concat;
+///
+/// const FOO: [u8; 2] = [8, 13];
+///
+/// const ARR: [u8; 7] = {
+///     let var = [21, 34];
+///     arr_concat!([3, 5] ++ FOO ++ var: [_; 2] ++ [55])
+/// };
+///
+/// assert_eq!(ARR, [3, 5, 8, 13, 21, 34, 55]);
+///
+/// ```
+#[macro_export]
+macro_rules! arr_concat {
+    ($($tokens:tt)*) => {
+        $crate::__arr_concat_separators!{() ($($tokens)*)}
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __arr_concat_separators {
+    (($($out:tt)*) ()) => {
+        $crate::concat_arrays!($($out)*)
+    };
+    (($($out:tt)*) (+ + $($rem:tt)*)) => {
+        $crate::__arr_concat_separators!{($($out)* ,) ($($rem)*)}
+    };
+    (($($out:tt)*) ($token:tt $($rem:tt)*)) => {
+        $crate::__arr_concat_separators!{($($out)* $token) ($($rem)*)}
+    };
+}
@@ -0,0 +1,152 @@
+/// Asserts at compile-time that an array has a specific length.
+///
+/// This macro is callable in const contexts,
+/// and evaluates to `()`.
+///
+/// The array expression is only type checked, it's never evaluated.
+///
+/// # Syntax
+///
+/// ```text
+/// assert_array_len!($array:expr, $length:expr $(,)?)
+/// ```
+///
+/// Where `$array` is an expression of array type.
+///
+/// Where `$length` is a constant `usize` expression.
+///
+/// # Compile-time errors
+///
+/// This macro causes a compile-time error if the length of `$array` isn't `$length`,
+/// which mentions both lengths, eg:
+/// ```text
+/// expected an array with a size of 4, found one with a size of 3
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// use arrcat::{assert_array_len, concat_arrays};
+///
+/// const HEADER: [u8; 2] = [0xAB, 0xCD];
+/// const BODY: [u8; 3] = [3, 5, 8];
+///
+/// assert_array_len!(HEADER, 2);
+///
+/// const PACKET: [u8; 5] = {
+///     assert_array_len!(concat_arrays!(HEADER, BODY), 5);
+///     concat_arrays!(HEADER, BODY)
+/// };
+///
+/// let runtime = [PACKET[0]; 4];
+/// assert_array_len!(runtime, 4);
+///
+/// ```
+#[macro_export]
+macro_rules! assert_array_len {
+    ($array:expr, $length:expr $(,)?) => {
+        if false {
+            let _: $crate::__::ArrayLen<{ $length }> = $crate::__::array_len_of(&$array);
+        }
+    };
+}
+
+/// Asserts at compile-time that two arrays have the same length.
+///
+/// This macro is callable in const contexts,
+/// and evaluates to `()`.
+///
+/// The array expressions are only type checked, they're never evaluated.
+///
+/// # Syntax
+///
+/// ```text
+/// assert_same_len!($left:expr, $right:expr $(,)?)
+/// ```
+///
+/// Where `$left` and `$right` are expressions of array types,
+/// which can have different element types.
+///
+/// # Compile-time errors
+///
+/// This macro causes a compile-time error if the lengths of the arrays differ,
+/// which mentions both lengths, eg:
+/// ```text
+/// expected an array with a size of 2, found one with a size of 3
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// use arrcat::assert_same_len;
+///
+/// const NAMES: [&str; 3] = ["foo", "bar", "baz"];
+/// const VALUES: [u32; 3] = [3, 5, 8];
+///
+/// assert_same_len!(NAMES, VALUES);
+///
+/// ```
+#[macro_export]
+macro_rules! assert_same_len {
+    ($left:expr, $right:expr $(,)?) => {
+        if false {
+            $crate::__::assert_same_array_len(
+                $crate::__::array_len_of(&$left),
+                $crate::__::array_len_of(&$right),
+            );
+        }
+    };
+}
+
+/// Asserts at compile-time that an array (usually a concatenation) equals an expected array.
+///
+/// This macro expands to a `const _: () = ...;` item,
+/// so it can be used wherever items can be declared.
+///
+/// # Syntax
+///
+/// ```text
+/// const_assert_concat_eq!($left:expr, $right:expr $(,)?)
+/// ```
+///
+/// Where `$left` and `$right` are constant expressions of `[T; N]` type,
+/// and `T` is a primitive type that can be compared with `==` in const contexts
+/// (eg: integers, `bool`, `char`).
+///
+/// # Compile-time errors
+///
+/// This macro causes a compile-time error if the lengths of the arrays differ,
+/// or if any pair of elements at the same position aren't equal.
+///
+/// # Example
+///
+/// ```rust
+/// use arrcat::{concat_arrays, const_assert_concat_eq};
+///
+/// const HEADER: [u8; 2] = [0xAB, 0xCD];
+/// const BODY: [u8; 3] = [3, 5, 8];
+/// const PACKET: [u8; 5] = concat_arrays!(HEADER, BODY);
+///
+/// const_assert_concat_eq!(PACKET, [0xAB, 0xCD, 3, 5, 8]);
+/// const_assert_concat_eq!(concat_arrays!([1], [2]), [1, 2]);
+///
+/// ```
+#[macro_export]
+macro_rules! const_assert_concat_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        const _: () = {
+            let left = $left;
+            let right = $right;
+            $crate::__::assert_same_length(&left, &right);
+
+            let mut i = 0;
+            while i < left.len() {
+                $crate::__::assert!(
+                    left[i] == right[i],
+                    "the arrays passed to `const_assert_concat_eq` aren't equal",
+                );
+                i += 1;
+            }
+        };
+    };
+}
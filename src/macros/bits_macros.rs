@@ -0,0 +1,275 @@
+/// Packs `bool`s into bytes or an integer, with the first `bool` in the least significant bit.
+///
+/// This macro is callable in const contexts.
+///
+/// For the variant that puts the first `bool` in the most significant bit,
+/// there's [`pack_bits_msb`](crate::pack_bits_msb).
+///
+/// # Syntax
+///
+/// The syntax of this macro, using `macro_rules!`-like input syntax
+///
+/// ```text
+/// pack_bits!{
+///     $( $integer_type:ident ; )?
+///     $($array_args:tt)*
+/// }
+/// ```
+///
+/// Where `$array_args` are the same arguments that
+/// [`concat_arrays`](crate::concat_arrays#syntax) takes,
+/// which are concatenated into a `[bool; N]` array before being packed.
+/// The length of each argument must be known the same way as in `concat_arrays`.
+///
+/// Without an `$integer_type`, this evaluates to a `[u8; (N + 7) / 8]` array,
+/// where the `bool` at index `i` is stored in the `i % 8` bit of the `i / 8` byte.
+/// The unused bits of the last byte are zeroed.
+///
+/// With an `$integer_type` (eg: `u32`),
+/// this evaluates to an integer of that type,
+/// where the `bool` at index `i` is stored in the `i` bit.
+///
+/// # Compile-time errors
+///
+/// This macro causes a compile-time error if an `$integer_type` is passed,
+/// and `N` is larger than its amount of bits.
+///
+/// # Example
+///
+/// ```rust
+/// use arrcat::pack_bits;
+///
+/// const FLAGS: [bool; 10] = [
+///     true, false, true, true, false, false, false, false,
+///     false, true,
+/// ];
+///
+/// const BYTES: [u8; 2] = pack_bits!(FLAGS);
+/// assert_eq!(BYTES, [0b0000_1101, 0b0000_0010]);
+///
+/// const INTEGER: u16 = pack_bits!(u16; FLAGS);
+/// assert_eq!(INTEGER, 0b10_0000_1101);
+///
+/// // the arguments are concatenated before being packed
+/// assert_eq!(pack_bits!(u16; [true, true], FLAGS), 0b1000_0011_0111);
+///
+/// ```
+#[macro_export]
+macro_rules! pack_bits {
+    ($integer_type:ident; $($array_args:tt)*) => {
+        $crate::__pack_bits!{lsb ($integer_type) ($($array_args)*)}
+    };
+    ($($array_args:tt)*) => {
+        $crate::__pack_bits!{lsb () ($($array_args)*)}
+    };
+}
+
+/// Packs `bool`s into bytes or an integer, with the first `bool` in the most significant bit.
+///
+/// This macro is callable in const contexts.
+///
+/// This takes the same arguments as [`pack_bits`](crate::pack_bits#syntax),
+/// but the bits are stored in the opposite order:
+///
+/// - When packing into bytes, the `bool` at index `i` is stored in
+///   the `7 - i % 8` bit of the `i / 8` byte.
+///   The unused bits of the last byte are zeroed.
+///
+/// - When packing into an integer, the `bool` at index `i` is stored in
+///   the `N - 1 - i` bit, so that the integer reads like the array when written in binary.
+///
+/// # Compile-time errors
+///
+/// This macro causes a compile-time error if an `$integer_type` is passed,
+/// and `N` is larger than its amount of bits.
+///
+/// # Example
+///
+/// ```rust
+/// use arrcat::pack_bits_msb;
+///
+/// const FLAGS: [bool; 10] = [
+///     true, false, true, true, false, false, false, false,
+///     false, true,
+/// ];
+///
+/// const BYTES: [u8; 2] = pack_bits_msb!(FLAGS);
+/// assert_eq!(BYTES, [0b1011_0000, 0b0100_0000]);
+///
+/// const INTEGER: u16 = pack_bits_msb!(u16; FLAGS);
+/// assert_eq!(INTEGER, 0b10_1100_0001);
+///
+/// ```
+#[macro_export]
+macro_rules! pack_bits_msb {
+    ($integer_type:ident; $($array_args:tt)*) => {
+        $crate::__pack_bits!{msb ($integer_type) ($($array_args)*)}
+    };
+    ($($array_args:tt)*) => {
+        $crate::__pack_bits!{msb () ($($array_args)*)}
+    };
+}
+
+/// XORs two arrays of integers element-wise.
+///
+/// This macro is callable in const contexts.
+///
+/// # Syntax
+///
+/// ```text
+/// xor_arrays!($left:expr, $right:expr $(,)?)
+/// ```
+///
+/// Where `$left` and `$right` are `[T; N]` arrays of the same length,
+/// and `T` is a primitive integer type (or `bool`).
+///
+/// # Compile-time errors
+///
+/// This macro causes a compile-time error if the lengths of the arrays differ.
+///
+/// # Example
+///
+/// ```rust
+/// use arrcat::xor_arrays;
+///
+/// const MASKED: [u8; 2] = xor_arrays!([0x0F, 0xF0], [0xFF, 0x00]);
+/// assert_eq!(MASKED, [0xF0, 0xF0]);
+///
+/// const KEY: [u32; 2] = [0xDEAD_BEEF, 0x0BAD_F00D];
+/// assert_eq!(xor_arrays!(xor_arrays!([3, 5], KEY), KEY), [3, 5]);
+///
+/// ```
+#[macro_export]
+macro_rules! xor_arrays {
+    ($left:expr, $right:expr $(,)?) => {{
+        let left = $left;
+        let right = $right;
+        $crate::__::assert_same_length(&left, &right);
+
+        let mut out = left;
+        let mut i = 0;
+        while i < out.len() {
+            out[i] = left[i] ^ right[i];
+            i += 1;
+        }
+        out
+    }};
+}
+
+/// Counts the `true`s in the concatenation of `bool` arrays.
+///
+/// This macro is callable in const contexts.
+///
+/// # Syntax
+///
+/// This takes the same arguments as [`concat_arrays`](crate::concat_arrays#syntax),
+/// whose concatenation must be a `[bool; N]` array.
+///
+/// # Example
+///
+/// ```rust
+/// use arrcat::count_true;
+///
+/// const FLAGS: [bool; 3] = [true, false, true];
+///
+/// const COUNT: usize = count_true!([true, false], FLAGS);
+///
+/// assert_eq!(COUNT, 3);
+///
+/// ```
+#[macro_export]
+macro_rules! count_true {
+    ($($array_args:tt)*) => {
+        $crate::__::count_true(&$crate::concat_arrays!($($array_args)*))
+    };
+}
+
+/// Gets the indices of the `true`s in the concatenation of `bool` arrays.
+///
+/// This macro is callable in const contexts.
+///
+/// Since the length of the returned array can't depend on its contents,
+/// this evaluates to a `([usize; N], usize)` tuple,
+/// with the indices of the `true`s in ascending order at the start of the array,
+/// followed by their amount.
+/// The elements after the indices are zeroed.
+///
+/// # Syntax
+///
+/// This takes the same arguments as [`concat_arrays`](crate::concat_arrays#syntax),
+/// whose concatenation must be a `[bool; N]` array.
+///
+/// # Example
+///
+/// ```rust
+/// use arrcat::mask_indices;
+///
+/// const MASK: [bool; 4] = [false, true, true, false];
+///
+/// const INDICES: ([usize; 6], usize) = mask_indices!([true, false], MASK);
+///
+/// assert_eq!(INDICES, ([0, 3, 4, 0, 0, 0], 3));
+/// assert_eq!(INDICES.0[..INDICES.1], [0, 3, 4]);
+///
+/// ```
+#[macro_export]
+macro_rules! mask_indices {
+    ($($array_args:tt)*) => {
+        $crate::__::mask_indices(&$crate::concat_arrays!($($array_args)*))
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __pack_bits {
+    ($order:ident () ($($array_args:tt)*)) => {{
+        const __BITS_LEN: $crate::__::usize = $crate::__concat_arrays_length_of!($($array_args)*);
+
+        let bits: [$crate::__::bool; __BITS_LEN] = $crate::concat_arrays!($($array_args)*);
+
+        let mut out = [0u8; (__BITS_LEN + 7) / 8];
+        let mut i = 0;
+        while i < __BITS_LEN {
+            if bits[i] {
+                out[i / 8] |= 1 << $crate::__pack_bits_shift!($order, i % 8, 8);
+            }
+            i += 1;
+        }
+        out
+    }};
+    ($order:ident ($integer_type:ident) ($($array_args:tt)*)) => {{
+        const __BITS_LEN: $crate::__::usize = $crate::__concat_arrays_length_of!($($array_args)*);
+
+        const _: () = $crate::__::assert!(
+            __BITS_LEN <= <$integer_type>::BITS as $crate::__::usize,
+            $crate::__::concat!(
+                "cannot pack more bits than there are in `",
+                $crate::__::stringify!($integer_type),
+                "`",
+            ),
+        );
+
+        let bits: [$crate::__::bool; __BITS_LEN] = $crate::concat_arrays!($($array_args)*);
+
+        let mut out: $integer_type = 0;
+        let mut i = 0;
+        while i < __BITS_LEN {
+            if bits[i] {
+                out |= 1 << $crate::__pack_bits_shift!($order, i, __BITS_LEN);
+            }
+            i += 1;
+        }
+        out
+    }};
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __pack_bits_shift {
+    (lsb, $index:expr, $width:expr) => {
+        $index
+    };
+    (msb, $index:expr, $width:expr) => {
+        $width - 1 - $index
+    };
+}
@@ -0,0 +1,95 @@
+/// Computes the CRC-32 checksum of the concatenation of byte arrays.
+///
+/// This macro is callable in const contexts.
+///
+/// This uses the CRC-32 variant of zlib, PNG, and Ethernet
+/// (also known as CRC-32/ISO-HDLC),
+/// with the reflected `0xEDB88320` polynomial.
+///
+/// # Syntax
+///
+/// This takes the same arguments as [`concat_arrays`](crate::concat_arrays#syntax),
+/// whose concatenation must be a `[u8; N]` array.
+///
+/// # Example
+///
+/// ```rust
+/// use arrcat::const_crc32;
+///
+/// const HEADER: [u8; 4] = *b"1234";
+///
+/// const CRC: u32 = const_crc32!(HEADER, (*b"56789"));
+///
+/// assert_eq!(CRC, 0xCBF4_3926);
+///
+/// ```
+#[macro_export]
+macro_rules! const_crc32 {
+    ($($array_args:tt)*) => {
+        $crate::__::crc32(&$crate::concat_arrays!($($array_args)*))
+    };
+}
+
+/// Concatenates arrays of integers,
+/// returning the concatenation along with a checksum of its elements.
+///
+/// This macro is callable in const contexts.
+///
+/// # Syntax
+///
+/// ```text
+/// concat_checksummed!{
+///     $(checksum = $checksum:ident;)?
+///     $($array_args:tt)*
+/// }
+/// ```
+///
+/// Where `$checksum` can be any of:
+///
+/// - `sum` (the default): the wrapping sum of the elements.
+///
+/// - `xor`: the bitwise xor of the elements.
+///
+/// Where `$array_args` are the same arguments as [`concat_arrays`](crate::concat_arrays#syntax),
+/// whose concatenation must be a `[T; N]` array of any primitive integer type.
+///
+/// This returns a `([T; N], T)` tuple, with the concatenation and its checksum.
+/// The checksum of an empty array is `0`.
+///
+/// # Example
+///
+/// ```rust
+/// use arrcat::concat_checksummed;
+///
+/// const TABLE: [u8; 4] = [8, 13, 21, 255];
+///
+/// const SUMMED: ([u8; 6], u8) = concat_checksummed!([3u8, 5], TABLE);
+/// assert_eq!(SUMMED, ([3, 5, 8, 13, 21, 255], 49));
+///
+/// const XORED: ([u8; 6], u8) = concat_checksummed!(checksum = xor; [3u8, 5], TABLE);
+/// assert_eq!(XORED, ([3, 5, 8, 13, 21, 255], 0b1110_1001));
+///
+/// ```
+#[macro_export]
+macro_rules! concat_checksummed {
+    (checksum = sum; $($array_args:tt)*) => {{
+        let concat = $crate::concat_arrays!($($array_args)*);
+        let checksum = $crate::__::Checksum::new(&concat).sum();
+        (concat, checksum)
+    }};
+    (checksum = xor; $($array_args:tt)*) => {{
+        let concat = $crate::concat_arrays!($($array_args)*);
+        let checksum = $crate::__::Checksum::new(&concat).xor();
+        (concat, checksum)
+    }};
+    (checksum = $checksum:tt; $($array_args:tt)*) => {
+        $crate::__::compile_error!{$crate::__::concat!(
+            "unknown checksum `",
+            $crate::__::stringify!($checksum),
+            "`, expected `sum` or `xor`",
+        )}
+    };
+    ($($array_args:tt)*) => {
+        $crate::concat_checksummed!(checksum = sum; $($array_args)*)
+    };
+}
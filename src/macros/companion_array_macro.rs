@@ -0,0 +1,65 @@
+/// Constructs an array with the same length as a concatenation,
+/// with every element initialized to a default value.
+///
+/// This macro is callable in const contexts.
+///
+/// The concatenation is never evaluated,
+/// its length is computed the same way that
+/// [`concat_arrays`](crate::concat_arrays) computes the length of the returned array.
+///
+/// # Syntax
+///
+/// ```text
+/// companion_array!($default:expr; length_of = concat_arrays!($($array_args:tt)*))
+/// ```
+///
+/// Where `$default` is a value of a `Copy` type, or a path to a constant,
+/// as required by the `[$default; LEN]` array repeat expression.
+///
+/// Where `$array_args` are the same arguments as [`concat_arrays`](crate::concat_arrays#syntax),
+/// so non-constant arguments require type annotations with their length.
+/// The `concat_arrays` macro can also be referred to by its path, eg: `arrcat::concat_arrays`.
+///
+/// # Example
+///
+/// ```rust
+/// use arrcat::{companion_array, concat_arrays};
+///
+/// const HEADER: [u8; 2] = [0xAB, 0xCD];
+///
+/// const fn packet(payload: [u8; 3]) -> ([u8; 6], [bool; 6]) {
+///     let data = concat_arrays!(HEADER, payload: [_; 3], [0xFF]);
+///
+///     let mut flags = companion_array!(
+///         false;
+///         length_of = concat_arrays!(HEADER, payload: [_; 3], [0xFF])
+///     );
+///     flags[0] = true;
+///
+///     (data, flags)
+/// }
+///
+/// let (data, flags) = packet([3, 5, 8]);
+/// assert_eq!(data, [0xAB, 0xCD, 3, 5, 8, 0xFF]);
+/// assert_eq!(flags, [true, false, false, false, false, false]);
+///
+/// ```
+#[macro_export]
+macro_rules! companion_array {
+    ($default:expr; length_of = concat_arrays! $args:tt $(,)?) => {
+        [$default; $crate::__concat_arrays_length_of! $args]
+    };
+    ($default:expr; length_of = $crate :: concat_arrays! $args:tt $(,)?) => {
+        [$default; $crate::__concat_arrays_length_of! $args]
+    };
+    ($default:expr; length_of = $(::)? arrcat :: concat_arrays! $args:tt $(,)?) => {
+        [$default; $crate::__concat_arrays_length_of! $args]
+    };
+    ($default:expr; length_of = $($rem:tt)*) => {
+        $crate::__::compile_error!{$crate::__::concat!(
+            "expected a `concat_arrays!(...)` invocation after `length_of = `, found `",
+            $crate::__::stringify!($($rem)*),
+            "`",
+        )}
+    };
+}
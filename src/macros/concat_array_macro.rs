@@ -1,6 +1,10 @@
 /// Concatenates multiple arrays into one.
 ///
 /// This macro is callable in const contexts.
+/// It expands to a single block expression,
+/// so it can be used anywhere a constant expression is expected,
+/// eg: the initializer of a `const` or `static`, an array length, an enum discriminant,
+/// or a (braced) const generic argument or default value.
 ///
 /// [**examples below**](#examples)
 ///
@@ -10,18 +14,125 @@
 ///
 /// ```text
 /// concat_arrays!{
-///     $( length_type = $length_type:ty ;)?
+///     $( $header:header ;)*
 ///     
-///     $( $array_arg:array_expr $(: $argument_type:ty )? ),*
+///     $(
+///         $(#[cfg $cfg:tt])? $(rev)?
+///         $array_arg:array_expr $([$range:range])? $(* $count:tt)? $(: $argument_type:ty )?
+///         |
+///         $(#[cfg $cfg:tt])? slice $slice:slice_expr : [$elem_type:ty; $length:expr]
+///     ),*
 ///     $(,)?
 /// }
 /// ```
 ///
-/// Where `$length_type` is a concrete type.
-/// When this argument is passed,
-/// a `$length_type::LEN` associated constant
-/// is defined with the length of the returned array.
-/// [example below](#length-inference-example)
+/// Where `$header` can be any of:
+///
+/// - `length_type = $length_type:ty`:
+///   defines a `$length_type::LEN` inherent associated constant
+///   with the length of the returned array.
+///   `$length_type` must be a concrete type defined in the current crate,
+///   that doesn't already have a `LEN` associated constant.
+///   [example below](#length-inference-example)
+///
+/// - `length_type = $length_type:ty as $const_name:ident`:
+///   like the above, but names the associated constant `$const_name` instead of `LEN`,
+///   allowing multiple invocations to declare constants on the same type.
+///
+/// - `with_offsets`:
+///   must come after a `length_type = $length_type` header,
+///   additionally defining a `$length_type::OFFSETS` inherent associated constant,
+///   a `[usize; ARGUMENT_COUNT]` array with the position of each argument
+///   in the returned array.
+///   [example below](#offsets-example)
+///
+/// - `with_offsets as $offsets_name:ident`:
+///   like the above, but names the associated constant `$offsets_name` instead of `OFFSETS`.
+///
+/// - `length_via = <$via_type:ty as $length_trait:path>`:
+///   implements the `$length_trait` trait for `$via_type`,
+///   defining its `const LEN: usize` associated constant
+///   with the length of the returned array.
+///   `$length_trait` must be a trait defined in the current crate,
+///   whose only item is `const LEN: usize;`, `$via_type` can be any concrete type.
+///   [example below](#length-trait-example)
+///
+/// - `length_via = $length_trait:path`:
+///   equivalent to `length_via = <() as $length_trait>`.
+///
+/// - `elem_type = $elem_type:ty`:
+///   implements the [`ElemType`](crate::ElemType) trait for `$elem_type`,
+///   with the element type of the returned array as its `Elem` associated type.
+///   The element type isn't inferred, it's taken from the first non-repeated argument
+///   with a type annotation, which must specify it (eg: `[u8; _]`, not `[_; 3]`).
+///   `$elem_type` must be a concrete type defined in the current crate,
+///   that doesn't already implement `ElemType`,
+///   so this header can only be passed once per `$elem_type`.
+///   [example below](#element-type-example)
+///
+/// - `checked`:
+///   computes the length of the returned array with overflow checks,
+///   causing a compile-time error that mentions this macro
+///   when the sum of the argument lengths overflows `usize`.
+///
+/// - `max_len = $max_len:expr`:
+///   causes a compile-time error if the length of the returned array
+///   is greater than `$max_len`, a constant `usize` expression.
+///   This guards against accidentally creating huge arrays.
+///
+/// - `lens = [$($length:expr),*]`:
+///   annotates each argument with the length at the same position, as `: [_; $length]`,
+///   the amount of lengths must equal the amount of arguments.
+///   Arguments must not have type annotations when this header is passed.
+///
+/// Multiple headers can be passed, each one terminated by a `;`.
+///
+/// Where `#[cfg $cfg]` is an optional `cfg` attribute, eg: `#[cfg(feature = "foo")]`,
+/// which only includes the argument when the configuration predicate is true,
+/// changing the length of the returned array accordingly.
+/// Arguments with a `cfg` attribute must not contain commas outside of delimiters,
+/// like the ones in generic arguments, they can be parenthesized to work around this.
+/// [example below](#conditional-arguments-example)
+///
+/// Where `rev` is an optional modifier that reverses the elements of the argument
+/// (after repeating it, if it has a `$count`),
+/// which requires the elements to be `Copy`.
+/// An argument starting with parentheses is parsed as a call to a `rev` function,
+/// so reversed expressions must be braced instead, eg: `rev {foo.bar()}: [_; 3]`.
+/// [example below](#reversed-arguments-example)
+///
+/// Arguments can also be slice constants, with the
+/// `slice $slice:slice_expr : [$elem_type:ty; $length:expr]` syntax,
+/// which copies the slice into an array of the annotated type, eg: `slice FOO: [u8; 3]`.
+/// The element type must be `Copy`, and the slice must be a constant
+/// whose length equals `$length`, otherwise it's a compile-time error.
+/// Like with `rev`, slice expressions must be braced instead of parenthesized,
+/// eg: `slice {&[3, 5]}: [u8; 2]`.
+/// [example below](#slice-arguments-example)
+///
+/// Passing a slice without the `slice` prefix is a compile-time error
+/// that suggests the above syntax when the argument has a slice type annotation
+/// (eg: `FOO: &[u8]`).
+/// For unannotated slice arguments the error only suggests it
+/// with the `"rust_1_83"` feature enabled,
+/// otherwise it's a mismatched types error.
+///
+/// Arguments can be followed by a range of constant bounds, eg: `foo[1..3]`,
+/// which only includes the elements of the argument in that range,
+/// requiring the elements to be `Copy`.
+/// The range can be any of `[$start..$end]`, `[..$end]`, `[$start..=$end]`, `[..=$end]`,
+/// or `[$start..]`.
+/// Bounds that are more than one token must be parenthesized, eg: `foo[(N - 1)..]`,
+/// and so must multi-segment paths, eg: `(Foo::BAR)[1..3]`.
+/// The type annotation of a range argument is the type of the range, not the entire array.
+/// [example below](#range-arguments-example)
+///
+/// Where `$count` is an optional constant `usize` expression
+/// (it must be parenthesized if it's more than one token),
+/// which repeats the `$array_arg` array `$count` times.
+/// Repeated arrays must be `Copy`, and `$argument_type` is the type of a single copy.
+/// Paths with multiple segments must be parenthesized to be repeated, eg: `(Foo::BAR) * 2`.
+/// [example below](#repeated-arguments-example)
 ///
 /// Where `$array_arg` can be any of:
 ///
@@ -31,7 +142,25 @@
 ///
 /// - `$path:path` expression of array type. Eg: `foo`, `::foo::bar`, `Foo::<T>::BAR`.
 ///
-/// Where `$argument_type` is the type of that argument (always an array).
+/// - A call to a function of array type, whose path has no generic arguments.
+///   Eg: `make()`, `foo::bar(3)`.
+///   Calls to `const fn`s with constant arguments, that return an array of a concrete type,
+///   don't require a type annotation.
+///
+/// - A macro invocation of array type, or a dereferenced invocation of a macro that
+///   returns a reference to an array. Eg: `foo!(3)`, `*include_bytes!("foo.bin")`.
+///
+/// - A nested `concat_arrays` invocation, Eg: `concat_arrays!(foo: [_; 2], [3])`.
+///   The length of nested invocations is computed from their arguments,
+///   so it doesn't need a type annotation.
+///   Only the `concat_arrays!` and `arrcat::concat_arrays!` paths are recognized,
+///   other paths are treated like any other macro invocation.
+///   [example below](#nested-invocations-example)
+///
+/// Where `$argument_type` is the type of that argument (always an array),
+/// or `len = $length_type:ty`, which annotates the argument as `[_; LENGTH]`,
+/// using the `LENGTH` associated constant of
+/// `$length_type`'s [`ArrayLength`](crate::ArrayLength) impl.
 ///
 /// ### Special syntax
 ///
@@ -46,10 +175,24 @@
 /// # );
 /// ```
 ///
+/// Both the element type and the length can be inferred, with a `[_; _]` type annotation.
+/// Inferring the length requires the argument to be a constant expression
+/// whose type is fully known, Eg: a constant, a field of a constant,
+/// or a call to a `const fn` that returns an array of a concrete type.
+/// Variables are not constants, so they must specify the length of their type annotation.
+///
+/// # Element types
+///
+/// The arrays can have any element type, including non-`Copy` types
+/// and types whose layout is unspecified (eg: tuples),
+/// since the elements are moved into the returned array without being inspected.
+///
 /// # Limitations
 ///
 /// This macro cannot concatenate arrays whose length depends on a
 /// surrounding generic parameter.
+/// The [`concat_pair`](crate::concat_pair) function can concatenate them instead,
+/// taking the length of the returned array as an extra const parameter.
 ///
 /// These are the only kinds of arguments that don't require a type annotation:
 ///
@@ -57,6 +200,9 @@
 ///
 /// - Array literals (they can contain runtime values).
 ///
+/// - Macro invocations that expand to constant expressions of fully inferred type,
+///   Eg: `*include_bytes!("foo.bin")`.
+///
 /// ### Argument from other macros
 ///
 /// Note that due to how `:expr` macro parameters work,
@@ -141,6 +287,109 @@
 /// }
 /// ```
 ///
+/// <span id = "repeated-arguments-example"></span>
+/// ### Repeated arguments
+///
+/// ```rust
+/// use arrcat::concat_arrays;
+///
+/// assert_eq!(repeated([3, 5]), [3, 5, 3, 5, 3, 5, 8, 13, 8, 13]);
+///
+/// const fn repeated(pair: [u8; 2]) -> [u8; 10] {
+///     concat_arrays!(
+///         // the type annotation is for a single copy of `pair`
+///         pair * 3: [_; 2],
+///         [8, 13] * 2,
+///     )
+/// }
+/// ```
+///
+/// <span id = "reversed-arguments-example"></span>
+/// ### Reversed arguments
+///
+/// ```rust
+/// use arrcat::concat_arrays;
+///
+/// assert_eq!(palindrome([3, 5, 8]), [3, 5, 8, 13, 8, 5, 3]);
+///
+/// const fn palindrome(half: [u8; 3]) -> [u8; 7] {
+///     concat_arrays!(half: [_; 3], [13], rev half: [_; 3])
+/// }
+/// ```
+///
+/// <span id = "slice-arguments-example"></span>
+/// ### Slice arguments
+///
+/// ```rust
+/// use arrcat::concat_arrays;
+///
+/// const MAGIC: &[u8] = b"PNG";
+///
+/// const HEADER: [u8; 5] = concat_arrays!([0x89], slice MAGIC: [u8; 3], [0x0D]);
+///
+/// assert_eq!(HEADER, [0x89, b'P', b'N', b'G', 0x0D]);
+/// ```
+///
+/// <span id = "range-arguments-example"></span>
+/// ### Range arguments
+///
+/// ```rust
+/// use arrcat::concat_arrays;
+///
+/// assert_eq!(middle([3, 5, 8, 13]), [0, 5, 8, 0, 3]);
+///
+/// const fn middle(array: [u8; 4]) -> [u8; 5] {
+///     concat_arrays!([0], array[1..3], [0], array[..1])
+/// }
+/// ```
+///
+/// <span id = "conditional-arguments-example"></span>
+/// ### Conditional arguments
+///
+/// ```rust
+/// use arrcat::concat_arrays;
+///
+/// const MAGIC: [u8; 2] = [0xBE, 0xEF];
+///
+/// const HEADER: [u8; HEADER_LEN] = concat_arrays!(
+///     MAGIC,
+///     #[cfg(target_pointer_width = "64")]
+///     [64],
+///     #[cfg(not(target_pointer_width = "64"))]
+///     [0, 0],
+/// );
+///
+/// #[cfg(target_pointer_width = "64")]
+/// const HEADER_LEN: usize = 3;
+///
+/// #[cfg(not(target_pointer_width = "64"))]
+/// const HEADER_LEN: usize = 4;
+///
+/// assert_eq!(HEADER[..2], MAGIC);
+/// ```
+///
+/// <span id = "nested-invocations-example"></span>
+/// ### Nested invocations
+///
+/// Nested `concat_arrays` invocations don't need type annotations,
+/// even if they have non-constant arguments.
+///
+/// ```rust
+/// use arrcat::concat_arrays;
+///
+/// assert_eq!(nested(3), [1, 2, 3, 4, 5, 6, 7]);
+///
+/// const fn nested(x: u8) -> [u8; 7] {
+///     let pair = [x, x + 1];
+///
+///     concat_arrays!(
+///         concat_arrays!([1, 2], pair: [_; 2]),
+///         // nested invocations can be nested any number of levels
+///         concat_arrays!(concat_arrays!([5], [6]), [7]),
+///     )
+/// }
+/// ```
+///
 /// <span id = "length-inference-example"></span>
 /// ### Length constant
 ///
@@ -169,25 +418,323 @@
 /// }
 ///
 /// ```
+///
+/// The associated constant can be given a name other than `LEN`,
+/// to declare multiple lengths on the same type:
+///
+/// ```rust
+/// use arrcat::concat_arrays;
+///
+/// struct Lens;
+///
+/// const HEAD: [u8; Lens::HEAD] = concat_arrays!{
+///     length_type = Lens as HEAD;
+///     [3, 5], [8],
+/// };
+///
+/// const TAIL: [u8; Lens::TAIL] = concat_arrays!{
+///     length_type = Lens as TAIL;
+///     [13, 21, 34, 55, 89],
+/// };
+///
+/// assert_eq!(HEAD, [3, 5, 8]);
+/// assert_eq!(TAIL, [13, 21, 34, 55, 89]);
+/// ```
+///
+/// <span id = "offsets-example"></span>
+/// The `with_offsets` header declares the position of each argument in the returned array,
+/// allowing indexing into the returned array by argument:
+///
+/// ```rust
+/// use arrcat::concat_arrays;
+///
+/// struct Packet;
+///
+/// const PACKET: [u8; Packet::LEN] = concat_arrays!{
+///     length_type = Packet;
+///     with_offsets;
+///     [0xFF, 0xFE], (*b"hello"), [0],
+/// };
+///
+/// assert_eq!(Packet::OFFSETS, [0, 2, 7]);
+///
+/// const TERMINATOR: u8 = PACKET[Packet::OFFSETS[2]];
+/// assert_eq!(TERMINATOR, 0);
+/// assert_eq!(PACKET[Packet::OFFSETS[1]..Packet::OFFSETS[2]], *b"hello");
+/// ```
+///
+/// <span id = "element-type-example"></span>
+/// ### Element type
+///
+/// This macro allows naming the element type of the returned array
+/// through the [`ElemType`](crate::ElemType) trait.
+///
+/// ```rust
+/// use arrcat::{concat_arrays, ElemType};
+///
+/// type Entry = (&'static str, Option<u32>);
+///
+/// enum Table {}
+///
+/// const TABLE: [<Table as ElemType>::Elem; 3] = concat_arrays!{
+///     elem_type = Table;
+///
+///     [("answer", Some(42))]: [Entry; _],
+///     [("none", None), ("zero", Some(0))],
+/// };
+///
+/// fn lookup<T: ElemType<Elem = Entry>>(table: &[T::Elem], name: &str) -> Option<u32> {
+///     table.iter().find(|(n, _)| *n == name)?.1
+/// }
+///
+/// assert_eq!(lookup::<Table>(&TABLE, "answer"), Some(42));
+/// assert_eq!(lookup::<Table>(&TABLE, "none"), None);
+/// ```
+///
+/// <span id = "length-trait-example"></span>
+/// ### Length trait
+///
+/// This macro allows getting the length of the returned array
+/// through a trait implementation,
+/// which can be implemented for types defined outside the current crate.
+///
+/// ```rust
+/// use arrcat::concat_arrays;
+///
+/// assert_eq!(BYTES, [3, 5, 8, 13, 21]);
+/// assert_eq!(<u8 as BytesLen>::LEN, 5);
+///
+/// trait BytesLen {
+///     const LEN: usize;
+/// }
+///
+/// const BYTES: [u8; <u8 as BytesLen>::LEN] = concat_arrays!{
+///     // makes the macro implement `BytesLen` for `u8`,
+///     // defining the `LEN` associated constant with
+///     // the length of the returned array
+///     length_via = <u8 as BytesLen>;
+///     
+///     [3, 5, 8],
+///     [13, 21],
+/// };
+///
+/// ```
 #[macro_export]
 macro_rules! concat_arrays {
     () => ([]);
-    ($(length_type = $length_type:ty)?; $($args:tt)* ) => (
-        $crate::__concat_arrays_preprocess_inner!{
-            (config(length_type($($length_type)?)))
+    ( $($args:tt)* ) => (
+        $crate::__concat_arrays_header!{
+            (output(array) length_type() checked() elem_type() max_len())
             ($($args)*)
         }
     );
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __concat_arrays_header {
+    (
+        (
+            output $output:tt length_type $length_type:tt checked $checked:tt
+            elem_type $elem_type:tt max_len $max_len:tt
+        )
+        (length_type = $new_length_type:ty; $($args:tt)*)
+    ) => {
+        $crate::__concat_arrays_header!{
+            (
+                output $output length_type($new_length_type as LEN) checked $checked
+                elem_type $elem_type max_len $max_len
+            )
+            ($($args)*)
+        }
+    };
+    (
+        (
+            output $output:tt length_type $length_type:tt checked $checked:tt
+            elem_type $elem_type:tt max_len $max_len:tt
+        )
+        (length_type = $new_length_type:ty as $const_name:ident; $($args:tt)*)
+    ) => {
+        $crate::__concat_arrays_header!{
+            (
+                output $output length_type($new_length_type as $const_name) checked $checked
+                elem_type $elem_type max_len $max_len
+            )
+            ($($args)*)
+        }
+    };
+    ($config:tt (with_offsets $(as $offsets_name:ident)?; $($args:tt)*)) => {
+        $crate::__concat_arrays_with_offsets!{$config ($($offsets_name)? OFFSETS) ($($args)*)}
+    };
+    (
+        (
+            output $output:tt length_type $length_type:tt checked $checked:tt
+            elem_type $elem_type:tt max_len $max_len:tt
+        )
+        (length_via = <$via_type:ty as $length_trait:path>; $($args:tt)*)
+    ) => {
+        $crate::__concat_arrays_header!{
+            (
+                output $output length_type(<$via_type as $length_trait>) checked $checked
+                elem_type $elem_type max_len $max_len
+            )
+            ($($args)*)
+        }
+    };
+    (
+        (
+            output $output:tt length_type $length_type:tt checked $checked:tt
+            elem_type $elem_type:tt max_len $max_len:tt
+        )
+        (length_via = $length_trait:path; $($args:tt)*)
+    ) => {
+        $crate::__concat_arrays_header!{
+            (
+                output $output length_type(<() as $length_trait>) checked $checked
+                elem_type $elem_type max_len $max_len
+            )
+            ($($args)*)
+        }
+    };
+    (
+        (
+            output $output:tt length_type $length_type:tt checked $checked:tt
+            elem_type $elem_type:tt max_len $max_len:tt
+        )
+        (checked; $($args:tt)*)
+    ) => {
+        $crate::__concat_arrays_header!{
+            (
+                output $output length_type $length_type checked(checked)
+                elem_type $elem_type max_len $max_len
+            )
+            ($($args)*)
+        }
+    };
+    (
+        (
+            output $output:tt length_type $length_type:tt checked $checked:tt
+            elem_type $elem_type:tt max_len $max_len:tt
+        )
+        (elem_type = $new_elem_type:ty; $($args:tt)*)
+    ) => {
+        $crate::__concat_arrays_header!{
+            (
+                output $output length_type $length_type checked $checked
+                elem_type($new_elem_type) max_len $max_len
+            )
+            ($($args)*)
+        }
+    };
+    (
+        (
+            output $output:tt length_type $length_type:tt checked $checked:tt
+            elem_type $elem_type:tt max_len $max_len:tt
+        )
+        (max_len = $new_max_len:expr; $($args:tt)*)
+    ) => {
+        $crate::__concat_arrays_header!{
+            (
+                output $output length_type $length_type checked $checked
+                elem_type $elem_type max_len($new_max_len)
+            )
+            ($($args)*)
+        }
+    };
+    ($config:tt (lens = [$($len:expr),* $(,)?]; $($args:tt)*)) => {
+        $crate::__concat_arrays_lens!{$config ($($len),*) () () ($($args)*)}
+    };
+    ($config:tt (; $($args:tt)*)) => {
+        $crate::__concat_arrays_preprocess_inner!{(config $config) ($($args)*)}
+    };
+    ($config:tt ($($args:tt)*)) => {
+        $crate::__concat_arrays_preprocess_inner!{(config $config) ($($args)*)}
+    };
+}
+
+// separates the headers that come after `lens = [...];` from the arguments
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __concat_arrays_lens {
+    ($config:tt $lens:tt ($($headers:tt)*) ($($segment:tt)*) (; $($rem:tt)*)) => {
+        $crate::__concat_arrays_lens!{$config $lens ($($headers)* $($segment)* ;) () ($($rem)*)}
+    };
+    ($config:tt $lens:tt $headers:tt ($($segment:tt)*) ($token:tt $($rem:tt)*)) => {
+        $crate::__concat_arrays_lens!{$config $lens $headers ($($segment)* $token) ($($rem)*)}
+    };
+    ($config:tt $lens:tt $headers:tt $args:tt ()) => {
+        $crate::__concat_arrays_lens_args!{$config $headers $lens () () $args}
+    };
+}
+
+// annotates each argument with the next length of the `lens = [...];` header
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __concat_arrays_lens_args {
+    (
+        $config:tt
+        $headers:tt
+        ($len:expr $(, $rem_lens:expr)*)
+        ($($out:tt)*)
+        ($($arg:tt)+)
+        ($(, $($rem:tt)*)?)
+    ) => {
+        $crate::__concat_arrays_lens_args!{
+            $config
+            $headers
+            ($($rem_lens),*)
+            ($($out)* $($arg)+ : [_; $len],)
+            ()
+            ($($($rem)*)?)
+        }
+    };
+    ($config:tt ($($headers:tt)*) () ($($out:tt)*) () ()) => {
+        $crate::__concat_arrays_header!{$config ($($headers)* $($out)*)}
+    };
+    ($config:tt $headers:tt $lens:tt $out:tt ($($arg:tt)*) ($token:tt $($rem:tt)*)) => {
+        $crate::__concat_arrays_lens_args!{$config $headers $lens $out ($($arg)* $token) ($($rem)*)}
+    };
+    ($config:tt $headers:tt () $out:tt ($($arg:tt)+) ()) => {
+        $crate::__::compile_error!{
+            "there are more arguments than lengths in the `lens` header of `concat_arrays`"
+        }
+    };
+    ($config:tt $headers:tt ($($lens:tt)+) $out:tt () ()) => {
+        $crate::__::compile_error!{
+            "there are fewer arguments than lengths in the `lens` header of `concat_arrays`"
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __concat_arrays_length_of {
+    () => (0);
     ( $($args:tt)* ) => (
-        $crate::__concat_arrays_inner!{(config(length_type())) ($($args)*)}
+        $crate::__concat_arrays_header!{
+            (output(length) length_type() checked() elem_type() max_len())
+            ($($args)*)
+        }
     );
 }
 
 #[doc(hidden)]
 #[macro_export]
 macro_rules! __concat_arrays_preprocess_inner {
-    ( $config:tt () ) => {
-        $crate::__concat_arrays_inner! {$config ([])}
+    // the `[]` argument that's passed when there are no arguments isn't included in the offsets
+    (
+        (config (output $output:tt length_type(offsets $($length_type:tt)*) $($config:tt)*))
+        ()
+    ) => {
+        $crate::__concat_arrays_inner! {
+            (config (output $output length_type(empty_offsets $($length_type)*) $($config)*))
+            ([])
+        }
+    };
+    // only passes an empty array when there are no arguments,
+    // not when the trailing arguments were removed by `#[cfg]`
+    ( (config $config:tt) () ) => {
+        $crate::__concat_arrays_inner! {(config $config) ([])}
     };
     ( $config:tt $args:tt ) => {
         $crate::__concat_arrays_inner! {$config $args}
@@ -199,7 +746,10 @@ macro_rules! __concat_arrays_preprocess_inner {
 macro_rules! __concat_arrays_inner {
     (
         (
-            config(length_type $length_type:tt)
+            config(
+                output(array) length_type $length_type:tt checked $checked:tt
+                elem_type $elem_type:tt max_len $max_len:tt
+            )
 
             $(
                 (
@@ -212,7 +762,7 @@ macro_rules! __concat_arrays_inner {
         )
         ($(,)?)
     ) => {
-        unsafe{
+        {
             #[repr(C, packed)]
             struct __Concater<__PrivT>(
                 $([__PrivT; $len],)*
@@ -224,36 +774,271 @@ macro_rules! __concat_arrays_inner {
                 };
             }
 
-            $crate::__::concat_arrays::<
-                _,
-                _,
-                {$crate::__declare_length_type_and_pass!(
-                    $length_type,
-                    {
-                        let mut len = 0;
-                        $( len += $len; )*
-                        len
-                    }
-                )}
-            >(
-                __Concater(
-                    $($crate::__type_ascription!(($expr) ($($type)*)),)*
-                ),
-                __Concater::PROOF,
-            )
+            $crate::__declare_elem_type!{$elem_type ($(($($elem)?))*)}
+
+            const __CONCAT_LEN: $crate::__::usize = $crate::__declare_length_type_and_pass!(
+                $length_type,
+                $crate::__concat_arrays_length!($checked ($($len,)*)),
+                ($($len),*)
+            );
+
+            $crate::__assert_max_len!{$max_len __CONCAT_LEN}
+
+            // evaluating the arguments outside of the `unsafe` block,
+            // so that they don't inherit its unsafe context.
+            let concater = __Concater(
+                $($crate::__type_ascription!(($expr) ($($type)*)),)*
+            );
+
+            unsafe{
+                $crate::__::concat_arrays::<_, _, __CONCAT_LEN>(concater, __Concater::PROOF)
+            }
         }
     };
 
     (
-        ($($prev:tt)*)
-        ( [$($array:tt)*] $(: [$elem_ty:ty; $($len:tt)*])?  $(, $($rem:tt)*)? )
-    ) => {
-        $crate::__concat_arrays_inner!{
-            (
-                $($prev)*
+        (
+            config(
+                output(length) length_type $length_type:tt checked $checked:tt
+                elem_type $elem_type:tt max_len $max_len:tt
+            )
+
+            $(
                 (
-                    [$($array)*],
-                    ($($elem_ty)?),
+                    $expr:expr,
+                    ($($elem:ty)?),
+                    ($len:expr),
+                    ($($type:tt)*),
+                )
+            )*
+        )
+        ($(,)?)
+    ) => {
+        $crate::__concat_arrays_length!($checked ($($len,)*))
+    };
+
+    ( $prev:tt ( $arg:tt : $(, $($rem:tt)*)? ) ) => {
+        $crate::__::compile_error!{$crate::__::concat!(
+            "expected a type after the `:` in the `",
+            $crate::__::stringify!($arg),
+            "` argument",
+        )}
+    };
+
+    ( $prev:tt ( $arg:tt $(* $count:tt)? : len = $len_type:ty $(, $($rem:tt)*)? ) ) => {
+        $crate::__concat_arrays_inner!{
+            $prev
+            (
+                $arg $(* $count)?: [_; <$len_type as $crate::__::ArrayLength>::LENGTH]
+                $(, $($rem)*)?
+            )
+        }
+    };
+
+    ( $prev:tt ( #[cfg $cfg:tt] $($rem:tt)* ) ) => {
+        $crate::__concat_arrays_cfg_arg!{$prev $cfg () ($($rem)*)}
+    };
+
+    ( $prev:tt ( #[$($attr:tt)*] $($rem:tt)* ) ) => {
+        $crate::__::compile_error!{$crate::__::concat!(
+            "unsupported `#[",
+            $crate::__::stringify!($($attr)*),
+            "]` attribute on an argument of `concat_arrays`, \
+             only `#[cfg(...)]` attributes are supported",
+        )}
+    };
+
+    (
+        ($($prev:tt)*)
+        ( concat_arrays ! $inner:tt $(, $($rem:tt)*)? )
+    ) => {
+        $crate::__concat_arrays_inner!{
+            ($($prev)*)
+            ($crate::concat_arrays! $inner $(, $($rem)*)?)
+        }
+    };
+
+    (
+        $prev:tt
+        ( concat_arrays ! $inner:tt : $($rem:tt)* )
+    ) => {
+        $crate::__concat_arrays_inner!{
+            $prev
+            ($crate::concat_arrays! $inner : $($rem)*)
+        }
+    };
+
+    (
+        ($($prev:tt)*)
+        ( $crate :: concat_arrays ! $inner:tt $(, $($rem:tt)*)? )
+    ) => {
+        $crate::__concat_arrays_inner!{
+            (
+                $($prev)*
+                (
+                    $crate::concat_arrays! $inner,
+                    (),
+                    ($crate::__concat_arrays_length_of! $inner),
+                    (),
+                )
+            )
+            ($($($rem)*)?)
+        }
+    };
+
+    (
+        $prev:tt
+        ( $crate :: concat_arrays ! $inner:tt : $($rem:tt)* )
+    ) => {
+        $crate::__concat_arrays_inner!{
+            $prev
+            (($crate::concat_arrays! $inner) : $($rem)*)
+        }
+    };
+
+    (
+        ($($prev:tt)*)
+        ( $(::)? arrcat :: concat_arrays ! $inner:tt $(, $($rem:tt)*)? )
+    ) => {
+        $crate::__concat_arrays_inner!{
+            (
+                $($prev)*
+                (
+                    $crate::concat_arrays! $inner,
+                    (),
+                    ($crate::__concat_arrays_length_of! $inner),
+                    (),
+                )
+            )
+            ($($($rem)*)?)
+        }
+    };
+
+    (
+        $prev:tt
+        ( $(::)? arrcat :: concat_arrays ! $inner:tt : $($rem:tt)* )
+    ) => {
+        $crate::__concat_arrays_inner!{
+            $prev
+            (($crate::concat_arrays! $inner) : $($rem)*)
+        }
+    };
+
+    ( $prev:tt ( * $macro:ident ! $macro_args:tt $($rem:tt)* ) ) => {
+        $crate::__concat_arrays_inner!{
+            $prev
+            ((* $macro ! $macro_args) $($rem)*)
+        }
+    };
+
+    ( $prev:tt ( $macro:ident ! $macro_args:tt $($rem:tt)* ) ) => {
+        $crate::__concat_arrays_inner!{
+            $prev
+            (($macro ! $macro_args) $($rem)*)
+        }
+    };
+
+    ( $prev:tt ( $($macro:ident)::+ ! $macro_args:tt $($rem:tt)* ) ) => {
+        $crate::__concat_arrays_inner!{
+            $prev
+            (($($macro)::+ ! $macro_args) $($rem)*)
+        }
+    };
+
+    ( $prev:tt ( $($function:ident)::+ ($($fn_args:tt)*) $($rem:tt)* ) ) => {
+        $crate::__concat_arrays_inner!{
+            $prev
+            (($($function)::+ ($($fn_args)*)) $($rem)*)
+        }
+    };
+
+    ( $prev:tt ( :: $($function:ident)::+ ($($fn_args:tt)*) $($rem:tt)* ) ) => {
+        $crate::__concat_arrays_inner!{
+            $prev
+            ((:: $($function)::+ ($($fn_args)*)) $($rem)*)
+        }
+    };
+
+    (
+        $prev:tt
+        (
+            $expr:tt $(* $count:tt)? : $(& $($lt:lifetime)? $(mut)?)? [$elem_ty:ty]
+            $(, $($rem:tt)*)?
+        )
+    ) => {
+        $crate::__::compile_error!{$crate::__::concat!(
+            "the `",
+            $crate::__::stringify!($expr),
+            "` argument of `concat_arrays` is a slice `&[T]`, not an array `[T; N]`; \
+             specify a length with a `: [T; N]` annotation, \
+             or use the `slice ",
+            $crate::__::stringify!($expr),
+            ": [T; N]` argument syntax to copy a slice constant into an array",
+        )}
+    };
+
+    (
+        $prev:tt
+        ( [$($array:tt)*] * $count:tt $(: [$elem_ty:ty; $($len:tt)*])?  $(, $($rem:tt)*)? )
+    ) => {
+        $crate::__concat_arrays_repeated!{
+            $prev
+            ($crate::__type_ascription!(([$($array)*]) ($([$elem_ty; $($len)*])?)))
+            ($crate::__get_array_length!($($array)*))
+            ($count)
+            ($($($rem)*)?)
+        }
+    };
+
+    (
+        $prev:tt
+        ( [$($array:tt)*] * $count:tt $(: $type:ty)?  $(, $($rem:tt)*)? )
+    ) => {
+        $crate::__concat_arrays_repeated!{
+            $prev
+            ($crate::__type_ascription!(([$($array)*]) ($($type)?)))
+            ($crate::__get_array_length!($($array)*))
+            ($count)
+            ($($($rem)*)?)
+        }
+    };
+
+    (
+        $prev:tt
+        ( $expr:tt * $count:tt $(: [$elem_ty:ty; $($len:tt)*])?  $(, $($rem:tt)*)? )
+    ) => {
+        $crate::__concat_arrays_repeated!{
+            $prev
+            ($crate::__type_ascription!(($expr) ($([$elem_ty; $($len)*])?)))
+            ($crate::__length_or_infer!(($expr), ($($elem_ty)?),($(const $($len)*)?)))
+            ($count)
+            ($($($rem)*)?)
+        }
+    };
+
+    (
+        $prev:tt
+        ( $expr:tt * $count:tt $(: $type:ty)?  $(, $($rem:tt)*)? )
+    ) => {
+        $crate::__concat_arrays_repeated!{
+            $prev
+            ($crate::__type_ascription!(($expr) ($($type)?)))
+            ($crate::__length_or_infer!(($expr), (), $((type $type))?))
+            ($count)
+            ($($($rem)*)?)
+        }
+    };
+
+    (
+        ($($prev:tt)*)
+        ( [$($array:tt)*] $(: [$elem_ty:ty; $($len:tt)*])?  $(, $($rem:tt)*)? )
+    ) => {
+        $crate::__concat_arrays_inner!{
+            (
+                $($prev)*
+                (
+                    [$($array)*],
+                    ($($elem_ty)?),
                     ($crate::__get_array_length!($($array)*)),
                     ($([$elem_ty; $($len)*])?),
                 )
@@ -331,6 +1116,329 @@ macro_rules! __concat_arrays_inner {
         }
     };
 
+    // must come after the arms that parse `rev` and `slice` as arguments, eg: `rev * 2`
+    ( $prev:tt ( rev $next:tt $($rem:tt)* ) ) => {
+        $crate::__concat_arrays_rev_arg!{$prev () ($next $($rem)*)}
+    };
+
+    ( $prev:tt ( slice $next:tt $($rem:tt)* ) ) => {
+        $crate::__concat_arrays_slice_arg!{$prev () ($next $($rem)*)}
+    };
+
+    ( $prev:tt ( $array:tt [$($range:tt)*] $($rem:tt)* ) ) => {
+        $crate::__concat_arrays_range_arg!{$prev $array ($($range)*) ($($rem)*)}
+    };
+
+    ( $prev:tt ( length_type $($rem:tt)* ) ) => {
+        $crate::__::compile_error!{
+            "the `length_type = <type>` header must come before the array arguments, \
+             and be followed by a `;`"
+        }
+    };
+
+    ( $prev:tt ( length_via $($rem:tt)* ) ) => {
+        $crate::__::compile_error!{
+            "the `length_via = <trait>` header must come before the array arguments, \
+             and be followed by a `;`"
+        }
+    };
+
+    ( $prev:tt ( max_len = $($rem:tt)* ) ) => {
+        $crate::__::compile_error!{
+            "the `max_len = <expr>` header must come before the array arguments, \
+             and be followed by a `;`"
+        }
+    };
+
+    ( $prev:tt ( elem_type = $($rem:tt)* ) ) => {
+        $crate::__::compile_error!{
+            "the `elem_type = <type>` header must come before the array arguments, \
+             and be followed by a `;`"
+        }
+    };
+
+    ( $prev:tt ( checked ; $($rem:tt)* ) ) => {
+        $crate::__::compile_error!{
+            "the `checked` header must come before the array arguments"
+        }
+    };
+
+    ( $prev:tt ( $($rem:tt)* ) ) => {
+        $crate::__concat_arrays_arg_error!{() ($($rem)*)}
+    };
+
+}
+
+// only expands the invocation for the configuration that's enabled,
+// continuing with or without the `#[cfg(...)]`-annotated argument.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __concat_arrays_cfg_arg {
+    ($prev:tt $cfg:tt ($($arg:tt)*) ($(, $($rem:tt)*)?)) => {{
+        #[cfg $cfg]
+        $crate::__concat_arrays_preprocess_inner!{$prev ($($arg)* $(, $($rem)*)?)}
+
+        #[cfg(not $cfg)]
+        $crate::__concat_arrays_preprocess_inner!{$prev ($($($rem)*)?)}
+    }};
+    ($prev:tt $cfg:tt ($($arg:tt)*) ($token:tt $($rem:tt)*)) => {
+        $crate::__concat_arrays_cfg_arg!{$prev $cfg ($($arg)* $token) ($($rem)*)}
+    };
+}
+
+// passes the tokens up to the next `,` to `concat_rev`,
+// then continues parsing the arguments after it.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __concat_arrays_rev_arg {
+    (($($prev:tt)*) ($($arg:tt)*) ($(, $($rem:tt)*)?)) => {
+        $crate::__concat_arrays_inner!{
+            (
+                $($prev)*
+                (
+                    $crate::concat_rev!($($arg)*),
+                    (),
+                    ($crate::__concat_arrays_length_of!($($arg)*)),
+                    (),
+                )
+            )
+            ($($($rem)*)?)
+        }
+    };
+    ($prev:tt ($($arg:tt)*) ($token:tt $($rem:tt)*)) => {
+        $crate::__concat_arrays_rev_arg!{$prev ($($arg)* $token) ($($rem)*)}
+    };
+}
+
+// copies the slice constant before the `: [T; LEN]` annotation into an array,
+// asserting that the slice has `LEN` elements.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __concat_arrays_slice_arg {
+    (
+        ($($prev:tt)*)
+        ($($slice:tt)+)
+        (: [$elem_ty:ty; $($len:tt)*] $(, $($rem:tt)*)?)
+    ) => {
+        $crate::__concat_arrays_inner!{
+            (
+                $($prev)*
+                (
+                    {
+                        const __SLICE_LEN: $crate::__::usize = $($len)*;
+
+                        const _: () = $crate::__::assert!(
+                            $($slice)+.len() == __SLICE_LEN,
+                            "the length of a `slice` argument of `concat_arrays` \
+                             doesn't match its type annotation",
+                        );
+
+                        let slice: &[$elem_ty] = $($slice)+;
+                        let mut array = $crate::__::uninit_array::<_, __SLICE_LEN>();
+                        let mut i = 0;
+                        while i < __SLICE_LEN {
+                            array[i] = $crate::__::MaybeUninit::new(slice[i]);
+                            i += 1;
+                        }
+
+                        // SAFETY: all elements of `array` were initialized in the loop above
+                        unsafe { $crate::__::array_assume_init(array) }
+                    },
+                    (),
+                    ($($len)*),
+                    (),
+                )
+            )
+            ($($($rem)*)?)
+        }
+    };
+    ($prev:tt ($($slice:tt)*) ($(, $($rem:tt)*)?)) => {
+        $crate::__::compile_error!{$crate::__::concat!(
+            "the `slice ",
+            $crate::__::stringify!($($slice)*),
+            "` argument requires a `: [T; LEN]` type annotation with the length of the slice",
+        )}
+    };
+    ($prev:tt ($($slice:tt)*) ($token:tt $($rem:tt)*)) => {
+        $crate::__concat_arrays_slice_arg!{$prev ($($slice)* $token) ($($rem)*)}
+    };
+}
+
+// parses the `$array[$range]` argument, copying the elements in the range into an array.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __concat_arrays_range_arg {
+    ($prev:tt $array:tt (.. $end:tt) $rem:tt) => {
+        $crate::__concat_arrays_range_arg!{@bounds $prev $array (0) ($end) $rem}
+    };
+    ($prev:tt $array:tt (..= $end:tt) $rem:tt) => {
+        $crate::__concat_arrays_range_arg!{@bounds $prev $array (0) ($end + 1) $rem}
+    };
+    ($prev:tt $array:tt ($start:tt ..= $end:tt) $rem:tt) => {
+        $crate::__concat_arrays_range_arg!{@bounds $prev $array ($start) ($end + 1) $rem}
+    };
+    ($prev:tt $array:tt ($start:tt .. $end:tt) $rem:tt) => {
+        $crate::__concat_arrays_range_arg!{@bounds $prev $array ($start) ($end) $rem}
+    };
+    ($prev:tt $array:tt ($start:tt ..) $rem:tt) => {
+        $crate::__concat_arrays_range_arg!{@bounds $prev $array ($start) () $rem}
+    };
+    (
+        @bounds
+        ($($prev:tt)*)
+        $array:tt
+        ($start:expr)
+        ($($end:expr)?)
+        ($(: [$elem_ty:ty; $($len:tt)*])? $(, $($rem:tt)*)?)
+    ) => {
+        $crate::__concat_arrays_inner!{
+            (
+                $($prev)*
+                (
+                    {
+                        const __START: $crate::__::usize = $crate::__::identity($start);
+                        const __LEN: $crate::__::usize = $crate::__range_arg_length!(
+                            ($array) ($start) ($($end)?) ($($($len)*)?)
+                        );
+
+                        let array = &$array;
+                        $crate::__::assert!(
+                            __START + __LEN <= array.len(),
+                            "the range of an argument of `concat_arrays` is out of bounds",
+                        );
+                        $crate::__assert_range_arg_end!(($($end)?) __START + __LEN, array);
+
+                        let mut out: [$crate::__::MaybeUninit<_>; __LEN] =
+                            $crate::__::uninit_array();
+                        let mut i = 0;
+                        while i < __LEN {
+                            out[i] = $crate::__::MaybeUninit::new(array[__START + i]);
+                            i += 1;
+                        }
+
+                        // SAFETY: all elements of `out` were initialized in the loop above
+                        unsafe { $crate::__::array_assume_init(out) }
+                    },
+                    ($($elem_ty)?),
+                    ($crate::__range_arg_length!(
+                        ($array) ($start) ($($end)?) ($($($len)*)?)
+                    )),
+                    ($([$elem_ty; _])?),
+                )
+            )
+            ($($($rem)*)?)
+        }
+    };
+    (@bounds $prev:tt $array:tt $start:tt $end:tt ($($rem:tt)*)) => {
+        $crate::__::compile_error!{$crate::__::concat!(
+            "could not parse `",
+            $crate::__::stringify!($($rem)*),
+            "` after the range of the `",
+            $crate::__::stringify!($array),
+            "` argument, expected an optional `: [<type>; <length>]` annotation followed by `,`",
+        )}
+    };
+    ($prev:tt $array:tt ($($range:tt)*) ($($rem:tt)*)) => {
+        $crate::__concat_arrays_arg_error!{() ($array [$($range)*] $($rem)*)}
+    };
+}
+
+// asserts at compile-time that unbounded ranges (eg: `[1..]`) end at the end of the array
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __assert_range_arg_end {
+    (() $end:expr, $array:expr) => {
+        $crate::__::RangeArgEnd::<{ $end }>.assert_array_len($array)
+    };
+    (($bound:expr) $end:expr, $array:expr) => {};
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __range_arg_length {
+    ($array:tt ($start:expr) ($end:expr) ($(_)?)) => {
+        ($end) - ($start)
+    };
+    ($array:tt ($start:expr) ($end:expr) ($len:expr)) => {{
+        const _: () = $crate::__::assert!(
+            ($end) - ($start) == $len,
+            "the length of the range of an argument of `concat_arrays` \
+             doesn't match its type annotation",
+        );
+        $len
+    }};
+    ($array:tt ($start:expr) () ($(_)?)) => {
+        $crate::__length_or_infer!($array, (), ()) - ($start)
+    };
+    ($array:tt ($start:expr) () ($len:expr)) => {
+        $len
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __concat_arrays_repeated {
+    // removes the parentheses around the count, to avoid `unused_parens` warnings
+    ($prev:tt $array:tt $single_len:tt (($($count:tt)*)) $rem:tt) => {
+        $crate::__concat_arrays_repeated!{$prev $array $single_len ($($count)*) $rem}
+    };
+    (
+        ($($prev:tt)*)
+        ($array:expr)
+        ($single_len:expr)
+        ($($count:tt)*)
+        ($($rem:tt)*)
+    ) => {
+        $crate::__concat_arrays_inner!{
+            (
+                $($prev)*
+                (
+                    $crate::__::flatten_repeated::<
+                        _,
+                        { $single_len },
+                        { $($count)* },
+                        { ($single_len) * ($($count)*) },
+                    >({
+                        // the `identity` call requires the array to be `Copy`,
+                        // even if it's a constant
+                        #[allow(unknown_lints, clippy::zero_repeat_side_effects)]
+                        let repeated = [$crate::__::identity($array); $($count)*];
+                        repeated
+                    }),
+                    (),
+                    (($single_len) * ($($count)*)),
+                    (),
+                )
+            )
+            ($($rem)*)
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __concat_arrays_arg_error {
+    (($($arg:tt)*) ($(, $($rem:tt)*)?)) => {
+        $crate::__::compile_error!{$crate::__::concat!(
+            "could not parse `",
+            $crate::__::stringify!($($arg)*),
+            "` as an argument of `concat_arrays`, \
+             expected an array literal, a path, or a parenthesized expression, \
+             optionally followed by `: <type>`. \
+             Arguments must be separated by `,`",
+        )}
+    };
+    (($($arg:tt)*) (; $($rem:tt)*)) => {
+        $crate::__::compile_error!{$crate::__::concat!(
+            "expected `,` after the `",
+            $crate::__::stringify!($($arg)*),
+            "` argument, found `;`. \
+             Headers must come before the array arguments",
+        )}
+    };
+    (($($arg:tt)*) ($next:tt $($rem:tt)*)) => {
+        $crate::__concat_arrays_arg_error!{($($arg)* $next) ($($rem)*)}
+    };
 }
 
 #[doc(hidden)]
@@ -351,7 +1459,7 @@ macro_rules! __length_or_infer {
     (($expr:expr), ($($elem_ty:ty)?), ($(const _)?)) => ({
         let len = $crate::__::Usize;
         if false {
-            len.infer_mda $(::<$elem_ty>)? (&$crate::__::ManuallyDrop::new($expr));
+            len.infer_mda $(::<$elem_ty>)? ($crate::__::ManuallyDrop::new($expr));
         }
         len.get()
     });
@@ -373,29 +1481,178 @@ macro_rules! __type_ascription {
     (($e:expr) ()) => {
         $e
     };
+    // using functions instead of struct literals,
+    // so that generic element types are usable in const fns.
     (($e:expr) ([$elem_ty:ty; _])) => {
-        $crate::__::ArrayAndGhost {
-            inner: $e,
-            elem_ty: $crate::__::PhantomData::<$elem_ty>,
-        }
-        .inner
+        $crate::__::array_with_elem_ty($e, $crate::__::PhantomData::<$elem_ty>)
     };
     (($e:expr) ($ty:ty)) => {
-        $crate::__::Identity::<$ty> { inner: $e }.inner
+        $crate::__::identity::<$ty>($e)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __concat_arrays_length {
+    (() ($($len:expr,)*)) => ({
+        let mut len = 0;
+        $( len += $len; )*
+        len
+    });
+    ((checked) ($($len:expr,)*)) => ({
+        let mut len: $crate::__::usize = 0;
+        $(
+            let (sum, overflowed) = len.overflowing_add($len);
+            $crate::__::assert!(
+                !overflowed,
+                "the length of the array returned by `concat_arrays` overflows `usize`",
+            );
+            len = sum;
+        )*
+        len
+    });
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __assert_max_len {
+    (() $length:ident) => {};
+    (($max_len:expr) $length:ident) => {
+        const _: () = $crate::__::assert!(
+            $length <= $max_len,
+            "the length of the array returned by `concat_arrays` exceeds its `max_len`",
+        );
+    };
+}
+
+// implements `ElemType` for the `elem_type` header's type,
+// using the element type of the first argument with a type annotation.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __declare_elem_type {
+    (() $elems:tt) => {};
+    (($elem_type:ty) (() $($rem:tt)*)) => {
+        $crate::__declare_elem_type!{($elem_type) ($($rem)*)}
+    };
+    (($elem_type:ty) (($elem:ty) $($rem:tt)*)) => {
+        #[allow(unknown_lints, non_local_definitions)]
+        impl $crate::ElemType for $elem_type {
+            type Elem = $elem;
+        }
+    };
+    (($elem_type:ty) ()) => {
+        $crate::__::compile_error!{
+            "the `elem_type = <type>` header requires an argument \
+             whose type annotation specifies the element type, eg: `foo: [u8; _]`"
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __concat_arrays_with_offsets {
+    (
+        (
+            output $output:tt length_type($length_type:tt $($_ignored:tt)*)
+            checked $checked:tt elem_type $elem_type:tt max_len $max_len:tt
+        )
+        ($offsets_name:ident $($_default:ident)?)
+        $args:tt
+    ) => {
+        $crate::__concat_arrays_with_offsets!{
+            @inner
+            ($length_type $($_ignored)*)
+            (output $output checked $checked elem_type $elem_type max_len $max_len)
+            $offsets_name
+            $args
+        }
+    };
+    (@inner (<$($_via:tt)*) $($_rem:tt)*) => {
+        $crate::__concat_arrays_with_offsets!{}
+    };
+    (
+        @inner
+        ($length_type:ty as $const_name:ident)
+        (output $output:tt checked $checked:tt elem_type $elem_type:tt max_len $max_len:tt)
+        $offsets_name:ident
+        ($($args:tt)*)
+    ) => {
+        $crate::__concat_arrays_header!{
+            (
+                output $output
+                length_type(offsets $offsets_name, $length_type as $const_name)
+                checked $checked elem_type $elem_type max_len $max_len
+            )
+            ($($args)*)
+        }
+    };
+    ($($_rem:tt)*) => {
+        $crate::__::compile_error!{
+            "the `with_offsets` header must come after a `length_type = <type>` header, \
+             and can only be passed once"
+        }
     };
 }
 
 #[doc(hidden)]
 #[macro_export]
 macro_rules! __declare_length_type_and_pass {
-    (($length_type:ty), $length:expr) => {{
+    (
+        (offsets $offsets_name:ident, $length_type:ty as $const_name:ident),
+        $length:expr,
+        ($($len:expr),*)
+    ) => {
+        $crate::__declare_length_type_and_pass!{
+            @offsets
+            ($length_type as $const_name, $offsets_name),
+            $length,
+            (<[()]>::len(&[$($crate::__as_unit!($len)),*])),
+            ($($len),*)
+        }
+    };
+    (
+        (empty_offsets $offsets_name:ident, $length_type:ty as $const_name:ident),
+        $length:expr,
+        $lens:tt
+    ) => {
+        $crate::__declare_length_type_and_pass!{
+            @offsets ($length_type as $const_name, $offsets_name), $length, (0), ()
+        }
+    };
+    (
+        @offsets
+        ($length_type:ty as $const_name:ident, $offsets_name:ident),
+        $length:expr,
+        ($count:expr),
+        ($($len:expr),*)
+    ) => {{
+        #[allow(unknown_lints, non_local_definitions)]
+        impl $length_type {
+            pub const $const_name: $crate::__::usize = $length;
+
+            pub const $offsets_name: [$crate::__::usize; $count] =
+                $crate::__::prefix_offsets([$($len),*]);
+        }
+
+        <$length_type>::$const_name
+    }};
+    ((<$length_type:ty as $length_trait:path>), $length:expr, $lens:tt) => {{
+        #[allow(unknown_lints, non_local_definitions)]
+        impl $length_trait for $length_type {
+            const LEN: $crate::__::usize = $length;
+        }
+
+        <$length_type as $length_trait>::LEN
+    }};
+    (($length_type:ty as $const_name:ident), $length:expr, $lens:tt) => {{
+        #[allow(unknown_lints, non_local_definitions)]
         impl $length_type {
-            pub const LEN: $crate::__::usize = $length;
+            pub const $const_name: $crate::__::usize = $length;
         }
 
-        <$length_type>::LEN
+        <$length_type>::$const_name
     }};
-    ((), $length:expr) => {
+    ((), $length:expr, $lens:tt) => {
         $length
     };
 }
@@ -0,0 +1,115 @@
+/// Concatenates the arrays returned by calling a function with each index in `0..$count`.
+///
+/// This macro is callable in const contexts, if the function is a `const fn`.
+///
+/// # Syntax
+///
+/// ```text
+/// concat_from_fn!($function:expr, $count:expr $(,)?)
+/// ```
+///
+/// Where `$function` is a function (usually a path to a `const fn`)
+/// callable as `$function(usize) -> [T; N]`, where `[T; N]` is a concrete type.
+///
+/// Where `$count` is a constant `usize` expression,
+/// for the amount of times that `$function` is called.
+///
+/// This evaluates to a `[T; N * $count]` array,
+/// where the `i`th block of `N` elements is the return value of `$function(i)`.
+///
+/// # Example
+///
+/// ```rust
+/// use arrcat::concat_from_fn;
+///
+/// const fn row(i: usize) -> [u8; 3] {
+///     [i as u8, i as u8 * 10, 0xFF]
+/// }
+///
+/// const ROWS: [u8; 9] = concat_from_fn!(row, 3);
+///
+/// assert_eq!(ROWS, [0, 0, 0xFF, 1, 10, 0xFF, 2, 20, 0xFF]);
+///
+/// ```
+#[macro_export]
+macro_rules! concat_from_fn {
+    ($function:expr, $count:expr $(,)?) => {{
+        const __BLOCK_LEN: $crate::__::usize = $crate::__concat_arrays_length_of!(($function(0)));
+        const __COUNT: $crate::__::usize = $count;
+
+        let mut blocks = $crate::__::uninit_array::<_, __COUNT>();
+        let mut i = 0;
+        while i < __COUNT {
+            blocks[i] = $crate::__::MaybeUninit::new($function(i));
+            i += 1;
+        }
+
+        // SAFETY: all elements of `blocks` were initialized in the loop above
+        let blocks = unsafe { $crate::__::array_assume_init(blocks) };
+
+        $crate::__::flatten_repeated::<_, __BLOCK_LEN, __COUNT, { __BLOCK_LEN * __COUNT }>(blocks)
+    }};
+}
+
+/// Constructs an array by calling a function with each index in `0..$len`.
+///
+/// This macro is callable in const contexts, when passed closure syntax,
+/// or when passed a `const fn`.
+///
+/// This is useful for generating arguments of [`concat_arrays`](crate::concat_arrays),
+/// eg: `concat_arrays!(array_from_fn!(|i| i as u8, 3), [9])`.
+///
+/// # Syntax
+///
+/// ```text
+/// array_from_fn!($function:function, $len:expr $(,)?)
+/// ```
+///
+/// Where `$function` can be any of:
+///
+/// - `|$index:pat_param| $body:expr`:
+///   closure syntax, which is expanded inline,
+///   evaluating `$body` with each `usize` index bound to the `$index` pattern.
+///   Because it's expanded inline, it can be used in const contexts.
+///
+/// - `$function:expr`: a function callable as `$function(usize) -> T`,
+///   which must be a `const fn` to use this macro in const contexts.
+///
+/// Where `$len` is a constant `usize` expression,
+/// for the length of the returned `[T; $len]` array.
+///
+/// # Example
+///
+/// ```rust
+/// use arrcat::{array_from_fn, concat_arrays};
+///
+/// const fn square(i: usize) -> u32 {
+///     (i * i) as u32
+/// }
+///
+/// const SQUARES: [u32; 4] = array_from_fn!(square, 4);
+/// assert_eq!(SQUARES, [0, 1, 4, 9]);
+///
+/// const BYTES: [u8; 4] = concat_arrays!(array_from_fn!(|i| i as u8 * 2, 3), [9]);
+/// assert_eq!(BYTES, [0, 2, 4, 9]);
+///
+/// ```
+#[macro_export]
+macro_rules! array_from_fn {
+    (|$index:pat_param| $body:expr, $len:expr $(,)?) => {{
+        let mut out = $crate::__::uninit_array::<_, { $len }>();
+
+        let mut i = 0;
+        while i < out.len() {
+            let $index: $crate::__::usize = i;
+            out[i] = $crate::__::MaybeUninit::new($body);
+            i += 1;
+        }
+
+        // SAFETY: all elements of `out` were initialized in the loop above
+        unsafe { $crate::__::array_assume_init(out) }
+    }};
+    ($function:expr, $len:expr $(,)?) => {
+        $crate::array_from_fn!(|i| $function(i), $len)
+    };
+}
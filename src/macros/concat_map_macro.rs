@@ -0,0 +1,196 @@
+/// Concatenates multiple arrays into one, applying a function to every element.
+///
+/// This macro is callable in const contexts,
+/// if the mapping function is a `const fn`.
+///
+/// # Syntax
+///
+/// The syntax of this macro, using `macro_rules!`-like input syntax
+///
+/// ```text
+/// concat_map_arrays!{
+///     $mapper:expr;
+///     $( $array_arg:array_expr $(: $argument_type:ty )? ),*
+///     $(,)?
+/// }
+/// ```
+///
+/// Where `$mapper` is a function (or closure, outside of const contexts)
+/// that's callable as `$mapper(T) -> U`,
+/// where `T` is the element type of the arguments.
+/// To pass expressions other than paths, they need to be parenthesized.
+///
+/// Where `T` must be a `Copy` type, unless the `"rust_1_83"` feature is enabled.
+///
+/// The arguments are passed as in [`concat_arrays`](crate::concat_arrays),
+/// including type annotations and nested `concat_arrays` invocations.
+///
+/// # Example
+///
+/// ```rust
+/// use arrcat::concat_map_arrays;
+///
+/// const fn inc(x: u8) -> u16 {
+///     x as u16 + 1
+/// }
+///
+/// const MAPPED: [u16; 5] = {
+///     let runtime = [8, 13];
+///     concat_map_arrays!(inc; [3, 5], runtime: [_; 2], [255])
+/// };
+///
+/// assert_eq!(MAPPED, [4, 6, 9, 14, 256]);
+///
+/// // closures can be used outside of const contexts
+/// assert_eq!(
+///     concat_map_arrays!((|x: u8| x as char); [b'f', b'o'], [b'o']),
+///     ['f', 'o', 'o'],
+/// );
+///
+/// ```
+#[macro_export]
+macro_rules! concat_map_arrays {
+    ($mapper:expr; $($args:tt)*) => {
+        $crate::__concat_map_arrays!{($mapper, i, ()) () () ($($args)*)}
+    };
+}
+
+/// Concatenates multiple arrays into one, applying a function to every element
+/// along with its index in the returned array.
+///
+/// This macro is callable in const contexts,
+/// if the mapping function is a `const fn`.
+///
+/// # Syntax
+///
+/// The syntax of this macro, using `macro_rules!`-like input syntax
+///
+/// ```text
+/// concat_map_index!{
+///     $mapper:expr;
+///     $( $array_arg:array_expr $(: $argument_type:ty )? ),*
+///     $(,)?
+/// }
+/// ```
+///
+/// Where `$mapper` is a function (or closure, outside of const contexts)
+/// that's callable as `$mapper(usize, T) -> U`,
+/// where `T` is the element type of the arguments,
+/// and the `usize` is the index of the element in the concatenation.
+/// To pass expressions other than paths, they need to be parenthesized.
+///
+/// Where `T` must be a `Copy` type, unless the `"rust_1_83"` feature is enabled.
+///
+/// The arguments are passed as in [`concat_arrays`](crate::concat_arrays),
+/// including type annotations and nested `concat_arrays` invocations.
+///
+/// # Example
+///
+/// ```rust
+/// use arrcat::concat_map_index;
+///
+/// const fn add_index(i: usize, x: u32) -> u32 {
+///     x + i as u32
+/// }
+///
+/// const MAPPED: [u32; 3] = concat_map_index!(add_index; [10, 20], [30]);
+///
+/// assert_eq!(MAPPED, [10, 21, 32]);
+///
+/// // closures can be used outside of const contexts
+/// assert_eq!(
+///     concat_map_index!((|i, name| (i, name)); ["foo"], ["bar"]),
+///     [(0, "foo"), (1, "bar")],
+/// );
+///
+/// ```
+#[macro_export]
+macro_rules! concat_map_index {
+    ($mapper:expr; $($args:tt)*) => {
+        $crate::__concat_map_arrays!{($mapper, i, (i)) () () ($($args)*)}
+    };
+}
+
+// Splits the arguments on top-level commas,
+// then moves the elements of each argument into the returned array,
+// passing them to the mapper along the way.
+//
+// `$i` is the running index of the element in the returned array,
+// which is also passed to the mapper if `$index` is passed.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __concat_map_arrays {
+    ($fixed:tt ()) => {
+        []
+    };
+    (($mapper:expr, $i:ident, $index:tt) ($(($($arg:tt)*))*)) => {{
+        const __LEN: $crate::__::usize = $crate::__concat_arrays_length_of!($($($arg)*),*);
+
+        let mut out = $crate::__::uninit_array::<_, __LEN>();
+        let elem_ty = $crate::__::PhantomData;
+        let mut $i = 0;
+        $({
+            // elements are moved out of `arg` exactly once, and `MaybeUninit`s aren't dropped
+            let arg = $crate::__::array_into_uninit($crate::__::array_with_elem_ty(
+                $crate::concat_arrays!($($arg)*),
+                elem_ty,
+            ));
+
+            let mut j = 0;
+            while j < arg.len() {
+                let elem = $crate::__read_uninit_elem!(arg[j]);
+                out[$i] = $crate::__::MaybeUninit::new($crate::__concat_map_call!($mapper, $index, elem));
+                $i += 1;
+                j += 1;
+            }
+        })*
+
+        // SAFETY: all `__LEN` elements of `out` were initialized in the loops above
+        unsafe { $crate::__::array_assume_init(out) }
+    }};
+    ($fixed:tt ($($prev:tt)*) () ($(,)?)) => {
+        $crate::__concat_map_arrays!{$fixed ($($prev)*)}
+    };
+    ($fixed:tt ($($prev:tt)*) ($($arg:tt)+) ($(,)?)) => {
+        $crate::__concat_map_arrays!{$fixed ($($prev)* ($($arg)+))}
+    };
+    ($fixed:tt ($($prev:tt)*) ($($arg:tt)+) (, $($rem:tt)*)) => {
+        $crate::__concat_map_arrays!{$fixed ($($prev)* ($($arg)+)) () ($($rem)*)}
+    };
+    ($fixed:tt $prev:tt ($($arg:tt)*) ($token:tt $($rem:tt)*)) => {
+        $crate::__concat_map_arrays!{$fixed $prev ($($arg)* $token) ($($rem)*)}
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __concat_map_call {
+    ($mapper:expr, ($($index:ident)?), $elem:ident) => {
+        $mapper($($index,)? $elem)
+    };
+}
+
+// Moves an element out of a `[MaybeUninit<T>; N]` array.
+//
+// Moving non-`Copy` elements out of an array by index in const contexts
+// requires `MaybeUninit::assume_init_read`, which is const since Rust 1.75.
+#[cfg(feature = "rust_1_83")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __read_uninit_elem {
+    ($array:ident [$index:ident]) => {
+        // SAFETY: the caller must ensure that the element is initialized,
+        // and that it's only read once.
+        unsafe { $array[$index].assume_init_read() }
+    };
+}
+
+#[cfg(not(feature = "rust_1_83"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __read_uninit_elem {
+    ($array:ident [$index:ident]) => {
+        // SAFETY: the caller must ensure that the element is initialized
+        unsafe { $crate::__::maybe_uninit_into_inner($array[$index]) }
+    };
+}
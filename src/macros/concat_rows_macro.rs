@@ -0,0 +1,475 @@
+/// Concatenates arrays that must all have the same length.
+///
+/// This macro is callable in const contexts.
+///
+/// This is like [`concat_arrays`](crate::concat_arrays),
+/// but causes a compile-time error if any argument has a different length
+/// than the first one, which is useful for catching wrong row lengths in matrix tables.
+///
+/// # Syntax
+///
+/// The syntax of this macro, using `macro_rules!`-like input syntax
+///
+/// ```text
+/// concat_rows!{
+///     $( $row:tt $(: $row_type:array_type )? ),*
+///     $(,)?
+/// }
+/// ```
+///
+/// Where `$row` is an array literal, a constant, or a parenthesized expression
+/// of array type, that's passed to `concat_arrays` along with its optional
+/// `$row_type` array type annotation, eg: `[_; 3]`.
+///
+/// # Example
+///
+/// ```rust
+/// use arrcat::concat_rows;
+///
+/// const IDENTITY: [u8; 9] = concat_rows!(
+///     [1, 0, 0],
+///     [0, 1, 0],
+///     [0, 0, 1],
+/// );
+///
+/// assert_eq!(IDENTITY, [1, 0, 0, 0, 1, 0, 0, 0, 1]);
+///
+/// ```
+///
+/// Passing rows of different lengths is a compile-time error:
+///
+/// ```compile_fail
+/// use arrcat::concat_rows;
+///
+/// const MATRIX: [u8; 5] = concat_rows!(
+///     [1, 0, 0],
+///     [0, 1],
+/// );
+/// ```
+#[macro_export]
+macro_rules! concat_rows {
+    () => {
+        $crate::concat_arrays!()
+    };
+    ($first:tt $(: $first_ty:tt)? $(, $row:tt $(: $row_ty:tt)?)* $(,)?) => {{
+        $(
+            $crate::assert_same_len!($first, $row);
+        )*
+
+        $crate::concat_arrays!($first $(: $first_ty)? $(, $row $(: $row_ty)?)*)
+    }};
+}
+
+/// Constructs a two-dimensional array from rows that must all have the same length.
+///
+/// This macro is callable in const contexts.
+///
+/// This is the nested counterpart of [`concat_rows`](crate::concat_rows),
+/// it evaluates to a `[[T; COLUMNS]; ROWS]` array instead of a flat one.
+///
+/// # Syntax
+///
+/// ```text
+/// matrix!( $( $row:expr ),* $(,)? )
+/// ```
+///
+/// Where `$row` is an expression of array type.
+///
+/// # Compile-time errors
+///
+/// This macro causes a compile-time error if any row has a different length
+/// than the first one, which mentions both lengths, eg:
+/// ```text
+/// expected an array with a size of 3, found one with a size of 2
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// use arrcat::{concat_arrays, matrix};
+///
+/// const LAST: [u8; 3] = [7, 8, 9];
+///
+/// const MATRIX: [[u8; 3]; 3] = matrix!(
+///     [1, 2, 3],
+///     concat_arrays!([4], [5, 6]),
+///     LAST,
+/// );
+///
+/// assert_eq!(MATRIX, [[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+///
+/// ```
+///
+/// Passing rows of different lengths is a compile-time error:
+///
+/// ```compile_fail
+/// use arrcat::matrix;
+///
+/// const MATRIX: [[u8; 3]; 2] = matrix!(
+///     [1, 2, 3],
+///     [4, 5],
+/// );
+/// ```
+#[macro_export]
+macro_rules! matrix {
+    () => {
+        []
+    };
+    ($first:expr $(, $row:expr)* $(,)?) => {{
+        $(
+            $crate::assert_same_len!($first, $row);
+        )*
+
+        [$first $(, $row)*]
+    }};
+}
+
+/// Concatenates arrays, padding each one to the same width with a fill value.
+///
+/// This macro is callable in const contexts.
+///
+/// This evaluates to a `[T; WIDTH * ARGS]` array,
+/// where `ARGS` is the amount of arguments.
+///
+/// # Syntax
+///
+/// The syntax of this macro, using `macro_rules!`-like input syntax
+///
+/// ```text
+/// concat_pad_each!{
+///     width = $width:expr, fill = $fill:expr;
+///     $( $array_arg:tt $(: $argument_type:array_type )? ),*
+///     $(,)?
+/// }
+/// ```
+///
+/// Where `$width` is a constant `usize` expression, for the width of each padded argument.
+///
+/// Where `$fill` is the `T` value used to pad each argument, `T` must be `Copy`.
+///
+/// Where `$array_arg` is an array literal, a constant, or a parenthesized expression
+/// of array type, that's passed to `concat_arrays` along with its optional
+/// `$argument_type` array type annotation, eg: `[_; 3]`.
+///
+/// # Compile-time errors
+///
+/// This macro causes a compile-time error if any argument is longer than `$width`.
+///
+/// # Example
+///
+/// ```rust
+/// use arrcat::concat_pad_each;
+///
+/// const NAMES: [u8; 12] = concat_pad_each!(
+///     width = 4, fill = b' ';
+///     (*b"foo"),
+///     (*b"ab"),
+///     (*b"quux"),
+/// );
+///
+/// assert_eq!(&NAMES, b"foo ab  quux");
+///
+/// ```
+///
+/// Arguments longer than the width cause a compile-time error:
+///
+/// ```compile_fail
+/// use arrcat::concat_pad_each;
+///
+/// const COLUMNS: [u8; 4] = concat_pad_each!(width = 2, fill = 0; [1, 2], [3, 4, 5]);
+/// ```
+#[macro_export]
+macro_rules! concat_pad_each {
+    (
+        width = $width:expr, fill = $fill:expr;
+        $($array_arg:tt $(: $arg_ty:tt)?),* $(,)?
+    ) => {{
+        const __WIDTH: $crate::__::usize = $width;
+
+        #[allow(unused_variables)]
+        let fill = $fill;
+
+        $crate::concat_arrays!($(
+            {
+                const __LEN: $crate::__::usize =
+                    $crate::__concat_arrays_length_of!($array_arg $(: $arg_ty)?);
+
+                const _: () = $crate::__::assert!(
+                    __LEN <= __WIDTH,
+                    "the arguments of `concat_pad_each` must not be longer than its `width`",
+                );
+
+                let array: [_; __LEN] = $crate::concat_arrays!($array_arg $(: $arg_ty)?);
+
+                let mut padded = [fill; __WIDTH];
+                let mut i = 0;
+                while i < array.len() {
+                    padded[i] = array[i];
+                    i += 1;
+                }
+                padded
+            }: [_; __WIDTH]
+        ),*)
+    }};
+}
+
+/// Interleaves arrays of the same length into a strided array,
+/// padding each stride with a fill value.
+///
+/// This macro is callable in const contexts.
+///
+/// This evaluates to a `[T; N * STRIDE]` array, where `N` is the length of the arguments,
+/// and the `i`th argument's `j`th element is at the `j * STRIDE + i` index.
+/// The remaining `STRIDE - ARGS` elements of each stride are `$fill`,
+/// where `ARGS` is the amount of arguments.
+///
+/// # Syntax
+///
+/// The syntax of this macro, using `macro_rules!`-like input syntax
+///
+/// ```text
+/// concat_interleave_pad!{
+///     stride = $stride:expr, fill = $fill:expr;
+///     $( $array_arg:tt $(: $argument_type:array_type )? ),*
+///     $(,)?
+/// }
+/// ```
+///
+/// Where `$stride` is a constant `usize` expression, for the length of each stride.
+///
+/// Where `$fill` is the `T` value used to pad each stride, `T` must be `Copy`.
+///
+/// Where `$array_arg` is an array literal, a constant, or a parenthesized expression
+/// of array type, that's passed to `concat_arrays` along with its optional
+/// `$argument_type` array type annotation, eg: `[_; 3]`.
+///
+/// # Compile-time errors
+///
+/// This macro causes a compile-time error if any of these is true:
+/// - There are more arguments than `$stride`.
+/// - The arguments don't all have the same length.
+///
+/// # Example
+///
+/// ```rust
+/// use arrcat::concat_interleave_pad;
+///
+/// const RED: [u8; 2] = [0xF0, 0xF1];
+/// const GREEN: [u8; 2] = [0x80, 0x81];
+///
+/// const PIXELS: [u8; 8] = concat_interleave_pad!(
+///     stride = 4, fill = 0;
+///     RED,
+///     GREEN,
+///     [0x10, 0x11],
+/// );
+///
+/// assert_eq!(PIXELS, [0xF0, 0x80, 0x10, 0, 0xF1, 0x81, 0x11, 0]);
+///
+/// ```
+#[macro_export]
+macro_rules! concat_interleave_pad {
+    (stride = $stride:expr, fill = $fill:expr $(;)?) => {
+        []
+    };
+    (
+        stride = $stride:expr, fill = $fill:expr;
+        $first:tt $(: $first_ty:tt)? $(, $array_arg:tt $(: $arg_ty:tt)?)* $(,)?
+    ) => {{
+        const __STRIDE: $crate::__::usize = $stride;
+        const __ARGS: $crate::__::usize =
+            <[()]>::len(&[(), $($crate::__as_unit!($array_arg)),*]);
+        const __LEN: $crate::__::usize =
+            $crate::__concat_arrays_length_of!($first $(: $first_ty)?);
+
+        const _: () = $crate::__::assert!(
+            __ARGS <= __STRIDE,
+            "`concat_interleave_pad` must not have more arguments than its `stride`",
+        );
+
+        let first: [_; __LEN] = $crate::concat_arrays!($first $(: $first_ty)?);
+        $(
+            $crate::assert_same_len!(first, $array_arg);
+        )*
+
+        let arrays: [[_; __LEN]; __ARGS] = [
+            first,
+            $($crate::concat_arrays!($array_arg $(: $arg_ty)?),)*
+        ];
+
+        let mut out = [$fill; __LEN * __STRIDE];
+        let mut i = 0;
+        while i < __ARGS {
+            let mut j = 0;
+            while j < __LEN {
+                out[j * __STRIDE + i] = arrays[i][j];
+                j += 1;
+            }
+            i += 1;
+        }
+        out
+    }};
+}
+
+/// Concatenates arrays, then reads the concatenation column by column.
+///
+/// This macro is callable in const contexts.
+///
+/// This treats the concatenation as a row-major matrix with `$width` columns,
+/// evaluating to a `[T; N]` array of the same elements in column-major order,
+/// where the element at the `r * $width + c` index
+/// is moved to the `c * (N / $width) + r` index.
+///
+/// # Syntax
+///
+/// ```text
+/// stripe!(width = $width:expr; $($array_args:tt)*)
+/// ```
+///
+/// Where `$width` is a constant `usize` expression, for the amount of columns.
+///
+/// Where `$array_args` are the same arguments as [`concat_arrays`](crate::concat_arrays#syntax),
+/// whose concatenation must be a `[T; N]` array, and `T` is a `Copy` type.
+///
+/// # Compile-time errors
+///
+/// This macro causes a compile-time error if `$width` is zero,
+/// or if the length of the concatenation isn't a multiple of `$width`.
+///
+/// # Example
+///
+/// ```rust
+/// use arrcat::stripe;
+///
+/// const COLUMNS: [u8; 6] = stripe!(width = 3; [1, 2, 3], [4, 5, 6]);
+/// assert_eq!(COLUMNS, [1, 4, 2, 5, 3, 6]);
+///
+/// ```
+///
+/// Concatenations whose length isn't a multiple of the width cause a compile-time error:
+///
+/// ```compile_fail
+/// use arrcat::stripe;
+///
+/// const COLUMNS: [u8; 5] = stripe!(width = 3; [1, 2, 3], [4, 5]);
+/// ```
+#[macro_export]
+macro_rules! stripe {
+    (width = $width:expr; $($array_args:tt)*) => {{
+        const __WIDTH: $crate::__::usize = $width;
+        const __LEN: $crate::__::usize = $crate::__concat_arrays_length_of!($($array_args)*);
+
+        const _: () = $crate::__::assert!(
+            __WIDTH != 0 && __LEN / __WIDTH * __WIDTH == __LEN,
+            "the length of the concatenation passed to `stripe` must be a multiple of its `width`",
+        );
+
+        let concat: [_; __LEN] = $crate::concat_arrays!($($array_args)*);
+
+        let rows = __LEN / __WIDTH;
+        let mut out = concat;
+        let mut r = 0;
+        while r < rows {
+            let mut c = 0;
+            while c < __WIDTH {
+                out[c * rows + r] = concat[r * __WIDTH + c];
+                c += 1;
+            }
+            r += 1;
+        }
+        out
+    }};
+}
+
+/// Splits an interleaved array into its lanes.
+///
+/// This macro is callable in const contexts.
+///
+/// This is the inverse of [`concat_interleave_pad`] with a `stride` equal to
+/// the amount of arguments,
+/// evaluating to a `[[T; N / $lanes]; $lanes]` array,
+/// where the `k`th lane contains the elements at the `j * $lanes + k` indices of `$array`.
+///
+/// # Syntax
+///
+/// The syntax of this macro, using `macro_rules!`-like input syntax
+///
+/// ```text
+/// deinterleave!{
+///     $array:tt $(: $array_type:array_type )?,
+///     lanes = $lanes:expr
+///     $(,)?
+/// }
+/// ```
+///
+/// Where `$array` is an array literal, a constant, or a parenthesized expression
+/// of `[T; N]` type, that's passed to `concat_arrays` along with its optional
+/// `$array_type` array type annotation, eg: `[_; 6]`.
+/// `T` must be `Copy`.
+///
+/// Where `$lanes` is a constant `usize` expression, for the amount of lanes.
+///
+/// # Compile-time errors
+///
+/// This macro causes a compile-time error if `$lanes` is zero,
+/// or if the length of `$array` isn't a multiple of `$lanes`.
+///
+/// # Example
+///
+/// ```rust
+/// use arrcat::{concat_interleave_pad, deinterleave};
+///
+/// const PIXELS: [u8; 6] = concat_interleave_pad!(
+///     stride = 3, fill = 0;
+///     [0xF0, 0xF1],
+///     [0x80, 0x81],
+///     [0x10, 0x11],
+/// );
+/// assert_eq!(PIXELS, [0xF0, 0x80, 0x10, 0xF1, 0x81, 0x11]);
+///
+/// const CHANNELS: [[u8; 2]; 3] = deinterleave!(PIXELS, lanes = 3);
+/// assert_eq!(CHANNELS, [[0xF0, 0xF1], [0x80, 0x81], [0x10, 0x11]]);
+///
+/// ```
+///
+/// Arrays whose length isn't a multiple of the amount of lanes cause a compile-time error:
+///
+/// ```compile_fail
+/// use arrcat::deinterleave;
+///
+/// const LANES: [[u8; 2]; 2] = deinterleave!([1, 2, 3, 4, 5], lanes = 2);
+/// ```
+///
+/// [`concat_interleave_pad`]: crate::concat_interleave_pad
+#[macro_export]
+macro_rules! deinterleave {
+    ($array:tt $(: $array_ty:tt)?, lanes = $lanes:expr $(,)?) => {{
+        const __LANES: $crate::__::usize = $lanes;
+        const __LEN: $crate::__::usize =
+            $crate::__concat_arrays_length_of!($array $(: $array_ty)?);
+
+        const _: () = $crate::__::assert!(
+            __LANES != 0 && __LEN / __LANES * __LANES == __LEN,
+            "the length of the array passed to `deinterleave` must be a multiple of its `lanes`",
+        );
+
+        let array: [_; __LEN] = $crate::concat_arrays!($array $(: $array_ty)?);
+
+        let mut out = $crate::__::uninit_array::<[_; __LEN / __LANES], __LANES>();
+        let mut k = 0;
+        while k < __LANES {
+            let mut lane = $crate::__::uninit_array::<_, { __LEN / __LANES }>();
+            let mut j = 0;
+            while j < lane.len() {
+                lane[j] = $crate::__::MaybeUninit::new(array[j * __LANES + k]);
+                j += 1;
+            }
+            // SAFETY: all elements of `lane` were initialized in the loop above
+            out[k] = $crate::__::MaybeUninit::new(unsafe { $crate::__::array_assume_init(lane) });
+            k += 1;
+        }
+
+        // SAFETY: all elements of `out` were initialized in the loop above
+        unsafe { $crate::__::array_assume_init(out) }
+    }};
+}
@@ -0,0 +1,247 @@
+/// Concatenates the bytes of string literals and byte arrays into a `[u8; N]` array.
+///
+/// This macro is callable in const contexts.
+///
+/// # Syntax
+///
+/// The syntax of this macro, using `macro_rules!`-like input syntax
+///
+/// ```text
+/// concat_str_bytes!{
+///     $( $argument:str_or_array ),*
+///     $(,)?
+/// }
+/// ```
+///
+/// Where `$argument` can be either:
+///
+/// - A string literal, which is converted to its UTF-8 bytes.
+///   Other literals (eg: byte strings, chars, integers) cause type errors,
+///   byte strings must be passed as arrays, eg: `(*b"foo")`.
+///
+/// - A `[u8; N]` array argument,
+///   which uses the same syntax as [`concat_arrays`](crate::concat_arrays) arguments.
+///
+/// # Example
+///
+/// ```rust
+/// use arrcat::concat_str_bytes;
+///
+/// const KEYWORD: [u8; 3] = *b"let";
+///
+/// const TOKENS: [u8; 10] = concat_str_bytes!(KEYWORD, [b' '], "x", " = ", "ñ");
+///
+/// assert_eq!(&TOKENS, "let x = ñ".as_bytes());
+///
+/// ```
+#[macro_export]
+macro_rules! concat_str_bytes {
+    () => ([0u8; 0]);
+    ( $($args:tt)* ) => {
+        $crate::__concat_str_bytes_start!{ () ($($args)*) }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __concat_str_bytes_start {
+    ( ($($prev:tt)*) () ) => {
+        $crate::concat_arrays!($($prev)*)
+    };
+    ( ($($prev:tt)*) ($string:literal $(, $($rem:tt)*)?) ) => {
+        $crate::__concat_str_bytes_start!{
+            (
+                $($prev)*
+                ($crate::__::str_to_bytes::<{$string.len()}>($string)): [u8; $string.len()],
+            )
+            ($($($rem)*)?)
+        }
+    };
+    ( $prev:tt $args:tt ) => {
+        $crate::__concat_str_bytes_array!{ $prev () $args }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __concat_str_bytes_array {
+    ( ($($prev:tt)*) ($($array:tt)*) ($(,)?) ) => {
+        $crate::concat_arrays!($($prev)* $($array)*)
+    };
+    ( ($($prev:tt)*) ($($array:tt)*) (, $($rem:tt)*) ) => {
+        $crate::__concat_str_bytes_start!{ ($($prev)* $($array)*,) ($($rem)*) }
+    };
+    ( $prev:tt ($($array:tt)*) ($token:tt $($rem:tt)*) ) => {
+        $crate::__concat_str_bytes_array!{ $prev ($($array)* $token) ($($rem)*) }
+    };
+}
+
+/// Concatenates the bytes of integers into a `[u8; N]` array,
+/// in little-endian, big-endian, or native-endian byte order.
+///
+/// This macro is callable in const contexts.
+///
+/// # Syntax
+///
+/// The syntax of this macro, using `macro_rules!`-like input syntax
+///
+/// ```text
+/// bytes_of!{
+///     $endianness:ident;
+///     $( $integer:tt $(: $integer_type:ty)? ),*
+///     $(,)?
+/// }
+/// ```
+///
+/// Where `$endianness` can be any of:
+///
+/// - `le`: little-endian, converting each integer with its `to_le_bytes` method.
+///
+/// - `be`: big-endian, converting each integer with its `to_be_bytes` method.
+///
+/// - `ne`: native-endian, converting each integer with its `to_ne_bytes` method.
+///
+/// Where `$integer` is an integer of any primitive integer type,
+/// each argument can be of a different type.
+/// Expressions other than literals and single identifiers must be parenthesized.
+///
+/// Like with [`concat_arrays`](crate::concat_arrays),
+/// non-constant arguments must have a type annotation,
+/// in this case the `$integer_type` integer type.
+/// Constant arguments without a type annotation must have a fully inferred type
+/// (eg: `3u16` instead of `3`).
+///
+/// # Example
+///
+/// ```rust
+/// use arrcat::bytes_of;
+///
+/// const LE: [u8; 4] = bytes_of!(le; 0x1234u16, 0x5678u16);
+/// assert_eq!(LE, [0x34, 0x12, 0x78, 0x56]);
+///
+/// const fn header(len: u32) -> [u8; 7] {
+///     bytes_of!(be; 0xCAFEu16, len: u32, (-1i8))
+/// }
+/// assert_eq!(header(0x0102_0304), [0xCA, 0xFE, 1, 2, 3, 4, 0xFF]);
+///
+/// ```
+#[macro_export]
+macro_rules! bytes_of {
+    (le; $($args:tt)*) => {
+        $crate::__bytes_of!{to_le_bytes () ($($args)*)}
+    };
+    (be; $($args:tt)*) => {
+        $crate::__bytes_of!{to_be_bytes () ($($args)*)}
+    };
+    (ne; $($args:tt)*) => {
+        $crate::__bytes_of!{to_ne_bytes () ($($args)*)}
+    };
+    ($endianness:tt; $($args:tt)*) => {
+        $crate::__::compile_error!{$crate::__::concat!(
+            "unknown endianness `",
+            $crate::__::stringify!($endianness),
+            "`, expected `le`, `be`, or `ne`",
+        )}
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __bytes_of {
+    ($method:ident () ($(,)?)) => {
+        [0u8; 0]
+    };
+    ($method:ident ($($prev:tt)*) ($(,)?)) => {
+        $crate::concat_arrays!($($prev)*)
+    };
+    ($method:ident ($($prev:tt)*) ($integer:tt : $integer_type:ty $(, $($rem:tt)*)?)) => {
+        $crate::__bytes_of!{
+            $method
+            (
+                $($prev)*
+                ($crate::__::identity::<$integer_type>($integer).$method())
+                    : [_; $crate::__::size_of::<$integer_type>()],
+            )
+            ($($($rem)*)?)
+        }
+    };
+    ($method:ident ($($prev:tt)*) ($integer:tt $(, $($rem:tt)*)?)) => {
+        $crate::__bytes_of!{
+            $method
+            ($($prev)* (($integer).$method()),)
+            ($($($rem)*)?)
+        }
+    };
+    ($method:ident $prev:tt ($($rem:tt)*)) => {
+        $crate::__::compile_error!{$crate::__::concat!(
+            "could not parse `",
+            $crate::__::stringify!($($rem)*),
+            "` as arguments of `bytes_of`, expected integers separated by `,`. \
+             Expressions other than literals and single identifiers must be parenthesized",
+        )}
+    };
+}
+
+/// Concatenates byte arrays, prefixed with the little-endian length of the concatenation.
+///
+/// This macro is callable in const contexts.
+///
+/// This evaluates to a `[u8; size_of::<$int_type>() + N]` array,
+/// where `N` is the length of the concatenated payload,
+/// and the first `size_of::<$int_type>()` bytes are `N` converted to `$int_type`,
+/// in little-endian byte order.
+///
+/// # Syntax
+///
+/// ```text
+/// with_len_header!($int_type:ty; $($array_args:tt)*)
+/// ```
+///
+/// Where `$int_type` is a primitive integer type, for the type of the length header.
+///
+/// Where `$array_args` are the same arguments as [`concat_arrays`](crate::concat_arrays#syntax),
+/// whose concatenation must be a `[u8; N]` array.
+///
+/// # Compile-time errors
+///
+/// This macro causes a compile-time error if the length of the payload
+/// is larger than the maximum value of `$int_type`.
+///
+/// # Example
+///
+/// ```rust
+/// use arrcat::with_len_header;
+///
+/// const fn frame(data: [u8; 3]) -> [u8; 7] {
+///     with_len_header!(u16; [0x01, 0x02], data: [_; 3])
+/// }
+///
+/// assert_eq!(frame([7, 8, 9]), [5, 0, 0x01, 0x02, 7, 8, 9]);
+///
+/// ```
+///
+/// Payloads that don't fit in the header cause a compile-time error:
+///
+/// ```compile_fail
+/// use arrcat::with_len_header;
+///
+/// const FRAME: [u8; 257] = with_len_header!(u8; [0u8; 256]);
+/// ```
+#[macro_export]
+macro_rules! with_len_header {
+    ($int_type:ty; $($array_args:tt)*) => {{
+        const __LEN: $crate::__::usize = $crate::__concat_arrays_length_of!($($array_args)*);
+
+        const _: () = $crate::__::assert!(
+            __LEN as $crate::__::u128 <= <$int_type>::MAX as $crate::__::u128,
+            "the payload passed to `with_len_header` is too long for the length header type",
+        );
+
+        let payload: [$crate::__::u8; __LEN] = $crate::concat_arrays!($($array_args)*);
+
+        $crate::concat_arrays!(
+            ($crate::bytes_of!(le; (__LEN as $int_type))),
+            payload: [_; __LEN],
+        )
+    }};
+}
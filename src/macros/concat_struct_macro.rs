@@ -0,0 +1,92 @@
+/// Concatenates arrays of (possibly) different element types into a packed struct.
+///
+/// This macro is callable in const contexts.
+///
+/// The returned value is of a `#[repr(C, packed)]` tuple struct defined by this macro,
+/// with one field for each argument, in the order that they're passed.
+/// Because the struct is packed, there is no padding between the arguments,
+/// which makes it useful to experiment with FFI layouts.
+///
+/// Fields of the returned struct must be read by value (eg: `{ foo.1 }`),
+/// since references to fields of packed structs aren't allowed.
+///
+/// # Syntax
+///
+/// The syntax of this macro, using `macro_rules!`-like input syntax
+///
+/// ```text
+/// concat_struct!{
+///     $( $array_arg:expr ),*
+///     $(,)?
+/// }
+/// ```
+///
+/// Where `$array_arg` is an expression of array type, of any element type and length.
+///
+/// # Limitations
+///
+/// This macro accepts at most 32 arguments.
+///
+/// # Example
+///
+/// ```rust
+/// use arrcat::concat_struct;
+///
+/// use core::mem::size_of_val;
+///
+/// const HEADER: [u8; 2] = [0xAB, 0xCD];
+///
+/// let packed = concat_struct!(HEADER, [3u16, 5], [8u32]);
+///
+/// assert_eq!({ packed.0 }, [0xAB, 0xCD]);
+/// assert_eq!({ packed.1 }, [3, 5]);
+/// assert_eq!({ packed.2 }, [8]);
+///
+/// // 2 bytes for the `u8`s, 4 bytes for the `u16`s, 4 bytes for the `u32`
+/// assert_eq!(size_of_val(&packed), 10);
+///
+/// ```
+#[macro_export]
+macro_rules! concat_struct {
+    ( $($args:expr),* $(,)? ) => {
+        $crate::__concat_struct_inner!{
+            ()
+            ($($args,)*)
+            (
+                __A0 __A1 __A2 __A3 __A4 __A5 __A6 __A7
+                __A8 __A9 __A10 __A11 __A12 __A13 __A14 __A15
+                __A16 __A17 __A18 __A19 __A20 __A21 __A22 __A23
+                __A24 __A25 __A26 __A27 __A28 __A29 __A30 __A31
+            )
+        }
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __concat_struct_inner {
+    (
+        ($(($expr:expr, $param:ident))*)
+        ()
+        $unused_params:tt
+    ) => {{
+        #[repr(C, packed)]
+        struct __ConcatStruct<$($param,)*>($($param,)*);
+
+        __ConcatStruct($($crate::__type_ascription!(($expr) ([_; _])),)*)
+    }};
+    (
+        ($($prev:tt)*)
+        ($expr:expr, $($rem:tt)*)
+        ($param:ident $($rem_params:ident)*)
+    ) => {
+        $crate::__concat_struct_inner!{
+            ($($prev)* ($expr, $param))
+            ($($rem)*)
+            ($($rem_params)*)
+        }
+    };
+    ($prev:tt $rem:tt ()) => {
+        $crate::__::compile_error!{"`concat_struct` accepts at most 32 arguments"}
+    };
+}
@@ -0,0 +1,41 @@
+/// Concatenates the arrays in a tuple into a single array.
+///
+/// This macro is callable in const contexts,
+/// and requires the `"rust_1_83"` crate feature.
+///
+/// The tuple can have from 1 up to 12 arrays, all of which must have the same element type.
+/// The length of the returned array is inferred,
+/// and must be the sum of the lengths of the tuple's arrays.
+///
+/// # Compile-time errors
+///
+/// This macro causes a compile-time error if the length of the returned array
+/// isn't the sum of the lengths of the tuple's arrays.
+///
+/// # Example
+///
+/// ```rust
+/// use arrcat::concat_tuple;
+///
+/// const fn make_parts() -> ([u8; 2], [u8; 3]) {
+///     ([3, 5], [8, 13, 21])
+/// }
+///
+/// const ARR: [u8; 5] = concat_tuple!(make_parts());
+/// assert_eq!(ARR, [3, 5, 8, 13, 21]);
+///
+/// let strings: [String; 3] = concat_tuple!((
+///     ["foo".to_string()],
+///     ["bar".to_string()],
+///     ["baz".to_string()],
+/// ));
+/// assert_eq!(strings, ["foo", "bar", "baz"]);
+///
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "rust_1_83")))]
+#[macro_export]
+macro_rules! concat_tuple {
+    ($tuple:expr $(,)?) => {
+        $crate::__::concat_tuple($tuple)
+    };
+}
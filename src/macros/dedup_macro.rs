@@ -0,0 +1,260 @@
+/// Removes consecutive duplicate elements from an array.
+///
+/// This macro is callable in const contexts.
+///
+/// Since the length of the returned array can't depend on its contents,
+/// this evaluates to a `([T; MAX_OUT], usize)` tuple,
+/// with the deduplicated elements at the start of the array, followed by their amount.
+/// The elements after the deduplicated ones are copies of the last element of `$array`.
+///
+/// # Syntax
+///
+/// ```text
+/// dedup_consecutive!($array:expr, $max_out:expr $(,)?)
+/// ```
+///
+/// Where `$array` is a `[T; N]` array, and `T` is a `Copy` type.
+/// Elements are compared with the `==` operator,
+/// which requires `T` to be a primitive type in const contexts.
+///
+/// Where `$max_out` is a constant `usize` expression,
+/// for the length of the returned array.
+///
+/// # Panics
+///
+/// Panics if there are more than `$max_out` deduplicated elements,
+/// or if `$array` is empty and `$max_out` isn't zero.
+///
+/// # Example
+///
+/// ```rust
+/// use arrcat::{concat_arrays, dedup_consecutive};
+///
+/// const RUNS: [u8; 7] = concat_arrays!([3; 2], [5], [8; 3], [3]);
+///
+/// const DEDUPED: ([u8; 5], usize) = dedup_consecutive!(RUNS, 5);
+///
+/// assert_eq!(DEDUPED, ([3, 5, 8, 3, 3], 4));
+/// assert_eq!(DEDUPED.0[..DEDUPED.1], [3, 5, 8, 3]);
+///
+/// ```
+#[macro_export]
+macro_rules! dedup_consecutive {
+    ($array:expr, $max_out:expr $(,)?) => {{
+        let array = $array;
+
+        let mut out = $crate::__::uninit_array::<_, { $max_out }>();
+        let mut len = 0usize;
+        let mut i = 0usize;
+        while i < array.len() {
+            if i == 0 || array[i] != array[i - 1] {
+                $crate::__::assert!(
+                    len < out.len(),
+                    "there are more deduplicated elements than the `max_out` of `dedup_consecutive`",
+                );
+                out[len] = $crate::__::MaybeUninit::new(array[i]);
+                len += 1;
+            }
+            i += 1;
+        }
+
+        $crate::__::assert!(
+            array.len() != 0 || out.len() == 0,
+            "`dedup_consecutive` requires a `max_out` of zero for empty arrays",
+        );
+
+        let mut i = len;
+        while i < out.len() {
+            out[i] = $crate::__::MaybeUninit::new(array[array.len() - 1]);
+            i += 1;
+        }
+
+        // SAFETY: all elements of `out` were initialized in the loops above
+        (unsafe { $crate::__::array_assume_init(out) }, len)
+    }};
+}
+
+/// Concatenates multiple arrays, asserting that the concatenation has no duplicate elements.
+///
+/// This macro is callable in const contexts.
+///
+/// Elements are compared with the `==` operator,
+/// which requires them to be primitive types in const contexts.
+///
+/// # Syntax
+///
+/// This takes the same arguments as [`concat_arrays`](crate::concat_arrays#syntax),
+/// the element type of the concatenation must be `Copy`.
+///
+/// # Panics
+///
+/// Panics if any two elements of the concatenation are equal,
+/// which is a compile-time error when this macro is used in a constant.
+///
+/// The panic message doesn't include the positions of the duplicate elements,
+/// because panics in const contexts can't format values in Rust 1.57.
+///
+/// # Example
+///
+/// ```rust
+/// use arrcat::concat_unique;
+///
+/// const RESERVED: [u16; 2] = [0, 1];
+///
+/// const KEYS: [u16; 5] = concat_unique!(RESERVED, [80, 443], [8080]);
+///
+/// assert_eq!(KEYS, [0, 1, 80, 443, 8080]);
+///
+/// ```
+///
+/// Duplicate elements cause a compile-time error in constants:
+///
+/// ```compile_fail
+/// const KEYS: [u16; 4] = arrcat::concat_unique!([1, 2], [3, 2]);
+/// ```
+#[macro_export]
+macro_rules! concat_unique {
+    ($($args:tt)*) => {{
+        let array = $crate::concat_arrays!($($args)*);
+
+        let mut i = 0;
+        while i < array.len() {
+            let mut j = i + 1;
+            while j < array.len() {
+                $crate::__::assert!(
+                    array[i] != array[j],
+                    "the arguments of `concat_unique` contain duplicate elements",
+                );
+                j += 1;
+            }
+            i += 1;
+        }
+        array
+    }};
+}
+
+/// Moves the `Some` values of an array of `Option`s to the start of an array,
+/// removing the `None`s.
+///
+/// This macro is callable in const contexts.
+///
+/// Since the length of the returned array can't depend on its contents,
+/// this evaluates to a `([T; N], usize)` tuple,
+/// with the `Some` values at the start of the array (in their original order),
+/// followed by their amount.
+/// The elements after the `Some` values are copies of `$fill`,
+/// which is also needed because every element of the array could be `None`.
+///
+/// # Syntax
+///
+/// ```text
+/// compact_options!($array:expr, $fill:expr $(,)?)
+/// ```
+///
+/// Where `$array` is a `[Option<T>; N]` array, and `T` is a `Copy` type.
+///
+/// Where `$fill` is a `T`.
+///
+/// # Example
+///
+/// ```rust
+/// use arrcat::{compact_options, concat_arrays};
+///
+/// const SPARSE: [Option<u8>; 5] = concat_arrays!([Some(3), None], [None, Some(5), Some(8)]);
+///
+/// const DENSE: ([u8; 5], usize) = compact_options!(SPARSE, 0);
+///
+/// assert_eq!(DENSE, ([3, 5, 8, 0, 0], 3));
+/// assert_eq!(DENSE.0[..DENSE.1], [3, 5, 8]);
+///
+/// ```
+#[macro_export]
+macro_rules! compact_options {
+    ($array:expr, $fill:expr $(,)?) => {{
+        let array = &$array;
+
+        let mut out = $crate::__::uninit_array();
+        $crate::__::assert_same_length(array, &out);
+
+        let mut len = 0usize;
+        let mut i = 0usize;
+        while i < array.len() {
+            if let $crate::__::Some(x) = array[i] {
+                out[len] = $crate::__::MaybeUninit::new(x);
+                len += 1;
+            }
+            i += 1;
+        }
+
+        let fill = $fill;
+        let mut i = len;
+        while i < out.len() {
+            out[i] = $crate::__::MaybeUninit::new(fill);
+            i += 1;
+        }
+
+        // SAFETY: all elements of `out` were initialized in the loops above
+        (unsafe { $crate::__::array_assume_init(out) }, len)
+    }};
+}
+
+/// Concatenates arrays of `(key, value)` pairs sorted by key,
+/// returning the offsets at which each group of pairs with equal keys starts.
+///
+/// This macro is callable in const contexts.
+///
+/// # Syntax
+///
+/// This takes the same arguments as [`concat_arrays`](crate::concat_arrays#syntax),
+/// whose concatenation must be a `[(K, V); N]` array of pairs sorted by key,
+/// or where at least the pairs with equal keys are next to each other.
+///
+/// Keys are compared with the `==` operator,
+/// which requires `K` to be a primitive type in const contexts.
+///
+/// This evaluates to a `([usize; N + 1], usize)` tuple,
+/// with the offset of the first pair of each group at the start of the array,
+/// followed by the amount of groups.
+/// The elements after the offsets of the groups are `N`,
+/// so that the pairs of the `g`th group are in the `offsets[g]..offsets[g + 1]` range.
+///
+/// # Example
+///
+/// ```rust
+/// use arrcat::group_offsets;
+///
+/// const PAIRS: [(char, u32); 5] = [('a', 3), ('a', 5), ('b', 8), ('c', 13), ('c', 21)];
+///
+/// const GROUPS: ([usize; 6], usize) = group_offsets!(PAIRS);
+///
+/// assert_eq!(GROUPS, ([0, 2, 3, 5, 5, 5], 3));
+///
+/// let (offsets, count) = GROUPS;
+/// let groups = (0..count)
+///     .map(|g| &PAIRS[offsets[g]..offsets[g + 1]])
+///     .collect::<Vec<_>>();
+///
+/// assert_eq!(groups, [&PAIRS[..2], &PAIRS[2..3], &PAIRS[3..]]);
+///
+/// ```
+#[macro_export]
+macro_rules! group_offsets {
+    ($($array_args:tt)*) => {{
+        const __PAIRS_LEN: $crate::__::usize = $crate::__concat_arrays_length_of!($($array_args)*);
+
+        let pairs: [_; __PAIRS_LEN] = $crate::concat_arrays!($($array_args)*);
+
+        let mut offsets = [__PAIRS_LEN; __PAIRS_LEN + 1];
+        let mut count = 0usize;
+        let mut i = 0usize;
+        while i < __PAIRS_LEN {
+            if i == 0 || pairs[i].0 != pairs[i - 1].0 {
+                offsets[count] = i;
+                count += 1;
+            }
+            i += 1;
+        }
+
+        (offsets, count)
+    }};
+}
@@ -0,0 +1,78 @@
+/// Constructs an array by mapping each listed variant of a fieldless enum.
+///
+/// This macro is callable in const contexts, when passed closure syntax,
+/// or when passed a `const fn`.
+///
+/// # Syntax
+///
+/// The syntax of this macro, using `macro_rules!`-like input syntax
+///
+/// ```text
+/// enum_array!{
+///     [$($variant:ident),* $(,)?] : $enum:ty;
+///     $mapper:function
+/// }
+/// ```
+///
+/// Where `$variant`s are the variants of the `$enum` type,
+/// which are mapped in the order that they're listed.
+/// The length of the returned array is the amount of listed variants.
+///
+/// Where `$mapper` can be any of:
+///
+/// - `|$variant:pat_param| $body:expr`:
+///   closure syntax, which is expanded inline,
+///   evaluating `$body` with each variant bound to the `$variant` pattern.
+///   Because it's expanded inline, it can be used in const contexts.
+///
+/// - `$function:expr`: a function callable as `$function($enum) -> U`,
+///   which must be a `const fn` to use this macro in const contexts.
+///
+/// # Example
+///
+/// ```rust
+/// use arrcat::{concat_arrays, enum_array};
+///
+/// #[derive(Copy, Clone)]
+/// enum Color {
+///     Red = 1,
+///     Green = 2,
+///     Blue = 4,
+/// }
+///
+/// const BITS: [u8; 3] = enum_array!([Red, Green, Blue]: Color; |v| v as u8);
+/// assert_eq!(BITS, [1, 2, 4]);
+///
+/// const fn name(color: Color) -> &'static str {
+///     match color {
+///         Color::Red => "red",
+///         Color::Green => "green",
+///         Color::Blue => "blue",
+///     }
+/// }
+///
+/// const NAMES: [&str; 4] = concat_arrays!(
+///     ["none"],
+///     enum_array!([Red, Green, Blue]: Color; name): [_; 3],
+/// );
+/// assert_eq!(NAMES, ["none", "red", "green", "blue"]);
+///
+/// ```
+#[macro_export]
+macro_rules! enum_array {
+    (
+        [$($variant:ident),* $(,)?] : $enum:ty;
+        |$elem:pat_param| $body:expr $(,)?
+    ) => {
+        [$({
+            let $elem = <$enum>::$variant;
+            $body
+        }),*]
+    };
+    (
+        [$($variant:ident),* $(,)?] : $enum:ty;
+        $function:expr $(,)?
+    ) => {
+        [$($function(<$enum>::$variant)),*]
+    };
+}
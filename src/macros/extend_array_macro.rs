@@ -0,0 +1,279 @@
+/// Converts an array of integers to a wider integer type,
+/// sign-extending signed integers and zero-extending unsigned integers.
+///
+/// This macro is callable in const contexts.
+///
+/// # Syntax
+///
+/// ```text
+/// extend_array!(widen = $integer_type:ty; $array:expr $(,)?)
+/// ```
+///
+/// Where `$array` is an array of integers, whose type must be fully inferred
+/// (eg: `[3u8, 5]` instead of `[3, 5]`).
+///
+/// Where `$integer_type` is a type that every value of the element type of `$array`
+/// can be losslessly converted into, these are the allowed conversions:
+///
+/// - `u8` into `u16`, `u32`, `u64`, `u128`, `usize`, `i16`, `i32`, `i64`, `i128`, `isize`
+/// - `u16` into `u32`, `u64`, `u128`, `usize`, `i32`, `i64`, `i128`
+/// - `u32` into `u64`, `u128`, `i64`, `i128`
+/// - `u64` into `u128`, `i128`
+/// - `i8` into `i16`, `i32`, `i64`, `i128`, `isize`
+/// - `i16` into `i32`, `i64`, `i128`, `isize`
+/// - `i32` into `i64`, `i128`
+/// - `i64` into `i128`
+///
+/// # Example
+///
+/// ```rust
+/// use arrcat::{concat_arrays, extend_array};
+///
+/// const SIGNED: [i32; 3] = extend_array!(widen = i32; [1i8, -2, 3]);
+/// assert_eq!(SIGNED, [1, -2, 3]);
+///
+/// const BYTES: [u8; 4] = concat_arrays!([0x00], [0x7F, 0x80, 0xFF]);
+/// const UNSIGNED: [u16; 4] = extend_array!(widen = u16; BYTES);
+/// assert_eq!(UNSIGNED, [0x00, 0x7F, 0x80, 0xFF]);
+///
+/// ```
+#[macro_export]
+macro_rules! extend_array {
+    (widen = $integer_type:ty; $array:expr $(,)?) => {{
+        let array = $array;
+
+        let widen = $crate::__::Widen::new(&array, $crate::__::PhantomData::<$integer_type>);
+
+        let mut out = widen.zeroed(&array);
+        let mut i = 0;
+        while i < array.len() {
+            out[i] = widen.widen(array[i]);
+            i += 1;
+        }
+        out
+    }};
+}
+
+/// Concatenates arrays of integers into an array of `$integer_type`,
+/// saturating the elements that don't fit in `$integer_type`.
+///
+/// This macro is callable in const contexts.
+///
+/// # Syntax
+///
+/// ```text
+/// concat_sat!{
+///     into = $integer_type:ty;
+///     $( $array_arg:tt $(: [$elem_type:ty; $length:expr])? ),*
+///     $(,)?
+/// }
+/// ```
+///
+/// Where `$integer_type` can be any primitive integer type.
+///
+/// Where each `$array_arg` is an array of any primitive integer type,
+/// whose type must be fully inferred (eg: `[3u8, 5]` instead of `[3, 5]`),
+/// or annotated with the `: [$elem_type; $length]` type annotation.
+/// Expressions other than array literals and single identifiers must be parenthesized.
+///
+/// Elements greater than `$integer_type::MAX` become `$integer_type::MAX`,
+/// and elements less than `$integer_type::MIN` become `$integer_type::MIN`.
+///
+/// Like with [`concat_arrays`](crate::concat_arrays),
+/// the length of non-constant arguments must be passed with a type annotation.
+///
+/// # Example
+///
+/// ```rust
+/// use arrcat::concat_sat;
+///
+/// const BYTES: [u8; 3] = concat_sat!(into = u8; [300i16, 5], [10u8]);
+/// assert_eq!(BYTES, [255, 5, 10]);
+///
+/// const fn to_i8(runtime: [i32; 2]) -> [i8; 4] {
+///     concat_sat!(into = i8; [-1i64, 100], runtime: [_; 2])
+/// }
+/// assert_eq!(to_i8([-200, 3]), [-1, 100, -128, 3]);
+///
+/// ```
+#[macro_export]
+macro_rules! concat_sat {
+    (
+        into = $integer_type:ty;
+        $( $array:tt $(: [$elem_ty:ty; $($len:tt)*])? ),*
+        $(,)?
+    ) => {
+        $crate::concat_arrays!(
+            $(
+                (
+                    $crate::__::Saturating::new(
+                        $crate::__type_ascription!(($array) ($([$elem_ty; _])?)),
+                        $crate::__::PhantomData::<$integer_type>,
+                    )
+                    .saturate()
+                )
+                $(: [_; $($len)*])?
+            ),*
+        )
+    };
+}
+
+/// Resizes an array, truncating it if it's longer than the new length,
+/// or padding it with a fill value if it's shorter.
+///
+/// This macro is callable in const contexts.
+///
+/// # Syntax
+///
+/// ```text
+/// resize_array!($array:expr, $new_len:expr, $fill:expr $(,)?)
+/// ```
+///
+/// Where `$array` is a `[T; N]` array, and `T` is a `Copy` type.
+///
+/// Where `$new_len` is a constant `usize` expression,
+/// for the length of the returned `[T; $new_len]` array.
+///
+/// Where `$fill` is the `T` value used for the elements after the first `N`.
+///
+/// # Example
+///
+/// ```rust
+/// use arrcat::resize_array;
+///
+/// const NAME: [u8; 3] = *b"foo";
+///
+/// const PADDED: [u8; 5] = resize_array!(NAME, 5, b' ');
+/// assert_eq!(PADDED, *b"foo  ");
+///
+/// const TRUNCATED: [u8; 2] = resize_array!(NAME, 2, b' ');
+/// assert_eq!(TRUNCATED, *b"fo");
+///
+/// ```
+#[macro_export]
+macro_rules! resize_array {
+    ($array:expr, $new_len:expr, $fill:expr $(,)?) => {{
+        let array = $array;
+
+        let mut out = [$fill; $new_len];
+        let mut i = 0;
+        while i < array.len() && i < out.len() {
+            out[i] = array[i];
+            i += 1;
+        }
+        out
+    }};
+}
+
+/// Repeats an array until it reaches a length,
+/// truncating the last repetition if the length isn't a multiple of the array's length.
+///
+/// This macro is callable in const contexts.
+///
+/// # Syntax
+///
+/// ```text
+/// tile_to!($pattern:expr, $len:expr $(,)?)
+/// ```
+///
+/// Where `$pattern` is a `[T; N]` array, and `T` is a `Copy` type.
+///
+/// Where `$len` is a constant `usize` expression,
+/// for the length of the returned `[T; $len]` array.
+///
+/// # Panics
+///
+/// Panics if `$pattern` is empty and `$len` isn't zero,
+/// which is a compile-time error in const contexts.
+///
+/// # Example
+///
+/// ```rust
+/// use arrcat::tile_to;
+///
+/// const STRIPES: [u8; 5] = tile_to!([0xAA, 0x55], 5);
+/// assert_eq!(STRIPES, [0xAA, 0x55, 0xAA, 0x55, 0xAA]);
+///
+/// const PREFIX: [char; 2] = tile_to!(['a', 'b', 'c'], 2);
+/// assert_eq!(PREFIX, ['a', 'b']);
+///
+/// ```
+#[macro_export]
+macro_rules! tile_to {
+    ($pattern:expr, $len:expr $(,)?) => {{
+        let pattern = $pattern;
+
+        let mut out = $crate::__::uninit_array::<_, { $len }>();
+        $crate::__::assert!(
+            !pattern.is_empty() || out.is_empty(),
+            "cannot tile an empty pattern to a non-zero length",
+        );
+
+        let mut i = 0;
+        while i < out.len() {
+            out[i] = $crate::__::MaybeUninit::new(pattern[i % pattern.len()]);
+            i += 1;
+        }
+
+        // SAFETY: all elements of `out` were initialized in the loop above
+        unsafe { $crate::__::array_assume_init(out) }
+    }};
+}
+
+/// Concatenates multiple arrays, truncating the concatenation to a maximum length.
+///
+/// This macro is callable in const contexts.
+///
+/// # Syntax
+///
+/// ```text
+/// concat_clamp!(max = $max:expr; $($array_args:tt)*)
+/// ```
+///
+/// Where `$max` is a constant `usize` expression,
+/// for the length of the returned `[T; $max]` array.
+///
+/// Where `$array_args` are the same arguments as [`concat_arrays`](crate::concat_arrays#syntax),
+/// whose concatenation must be a `[T; N]` array, and `T` is a `Copy` type.
+///
+/// The returned array contains the first `$max` elements of the concatenation.
+/// To pad concatenations shorter than `$max`, use [`resize_array`](crate::resize_array).
+///
+/// # Panics
+///
+/// Panics if the concatenation is shorter than `$max`,
+/// which is a compile-time error in const contexts.
+///
+/// # Example
+///
+/// ```rust
+/// use arrcat::concat_clamp;
+///
+/// const CLAMPED: [u8; 4] = concat_clamp!(max = 4; [1, 2, 3], [4, 5, 6]);
+/// assert_eq!(CLAMPED, [1, 2, 3, 4]);
+///
+/// const EXACT: [u8; 6] = concat_clamp!(max = 6; [1, 2, 3], [4, 5, 6]);
+/// assert_eq!(EXACT, [1, 2, 3, 4, 5, 6]);
+///
+/// ```
+#[macro_export]
+macro_rules! concat_clamp {
+    (max = $max:expr; $($array_args:tt)*) => {{
+        let concat = $crate::concat_arrays!($($array_args)*);
+
+        let mut out = $crate::__::uninit_array::<_, { $max }>();
+        $crate::__::assert!(
+            concat.len() >= out.len(),
+            "the concatenation passed to `concat_clamp` is shorter than the `max` length",
+        );
+
+        let mut i = 0;
+        while i < out.len() {
+            out[i] = $crate::__::MaybeUninit::new(concat[i]);
+            i += 1;
+        }
+
+        // SAFETY: all elements of `out` were initialized in the loop above
+        unsafe { $crate::__::array_assume_init(out) }
+    }};
+}
@@ -0,0 +1,68 @@
+/// Writes the concatenation of arrays into an existing array.
+///
+/// This macro is callable in const contexts,
+/// which requires Rust 1.83.0 for the mutable reference to the destination array.
+///
+/// Unlike [`concat_arrays`](crate::concat_arrays),
+/// the arguments are evaluated and written one at a time,
+/// and don't need type annotations, since their length isn't needed in a constant.
+///
+/// # Syntax
+///
+/// ```text
+/// fill_concat!($out:expr $(, $array:expr)* $(,)?)
+/// ```
+///
+/// Where `$out` is a `&mut [T; N]`, and `T` is a `Copy` type.
+///
+/// Where each `$array` is a `[T; _]` array or a reference to one.
+///
+/// # Panics
+///
+/// Panics if the sum of the lengths of the `$array`s isn't `N`.
+///
+/// # Example
+///
+/// ```rust
+/// use arrcat::fill_concat;
+///
+/// const HEADER: [u8; 2] = [0xAB, 0xCD];
+///
+/// let mut buffer = [0u8; 6];
+///
+/// let payload = [3, 5, 8];
+/// fill_concat!(&mut buffer, HEADER, payload, [0xFF]);
+///
+/// assert_eq!(buffer, [0xAB, 0xCD, 3, 5, 8, 0xFF]);
+///
+/// ```
+#[macro_export]
+macro_rules! fill_concat {
+    ($out:expr $(, $array:expr)* $(,)?) => {{
+        let out = $out;
+
+        // not mutated when there are no `$array` arguments
+        #[allow(unused_mut)]
+        let mut offset = 0usize;
+
+        $({
+            let array = $array;
+            $crate::__::assert!(
+                array.len() <= out.len() - offset,
+                "the arguments of `fill_concat` are longer than the destination array",
+            );
+
+            let mut i = 0;
+            while i < array.len() {
+                out[offset] = array[i];
+                offset += 1;
+                i += 1;
+            }
+        })*
+
+        $crate::__::assert!(
+            offset == out.len(),
+            "the arguments of `fill_concat` are shorter than the destination array",
+        );
+    }};
+}
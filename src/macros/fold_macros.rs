@@ -0,0 +1,184 @@
+/// Folds every element of an array into an accumulator, by repeatedly calling a function.
+///
+/// This macro is callable in const contexts, if the function is a `const fn`.
+///
+/// # Syntax
+///
+/// ```text
+/// array_fold!($init:expr, $function:expr, $array:expr $(,)?)
+/// ```
+///
+/// Where `$init` is the initial value of the accumulator, of type `A`.
+///
+/// Where `$function` is callable as `$function(A, T) -> A`.
+///
+/// Where `$array` is a `[T; N]` array, and `T` is a `Copy` type.
+///
+/// # Example
+///
+/// ```rust
+/// use arrcat::{array_fold, concat_arrays};
+///
+/// const fn checksum(acc: u8, byte: u8) -> u8 {
+///     acc.wrapping_add(byte)
+/// }
+///
+/// const TABLE: [u8; 5] = concat_arrays!([3, 5], [8, 13, 250]);
+/// const CHECKSUM: u8 = array_fold!(0, checksum, TABLE);
+///
+/// assert_eq!(CHECKSUM, 23);
+///
+/// ```
+#[macro_export]
+macro_rules! array_fold {
+    ($init:expr, $function:expr, $array:expr $(,)?) => {{
+        let array = $array;
+
+        let mut acc = $init;
+        let mut i = 0;
+        while i < array.len() {
+            acc = $function(acc, array[i]);
+            i += 1;
+        }
+        acc
+    }};
+}
+
+/// Checks whether a predicate returns `true` for every element of an array.
+///
+/// This macro is callable in const contexts, if the predicate is a `const fn`.
+///
+/// Returns `true` for empty arrays,
+/// and stops calling the predicate at the first element that it returns `false` for.
+///
+/// # Syntax
+///
+/// ```text
+/// array_all!($predicate:expr, $array:expr $(,)?)
+/// ```
+///
+/// Where `$predicate` is callable as `$predicate(&T) -> bool`.
+///
+/// Where `$array` is a `[T; N]` array, or a reference to one.
+///
+/// # Example
+///
+/// ```rust
+/// use arrcat::{array_all, concat_arrays};
+///
+/// const fn is_nonzero(x: &u8) -> bool {
+///     *x != 0
+/// }
+///
+/// const TABLE: [u8; 5] = concat_arrays!([3, 5], [8, 13, 21]);
+/// const _: () = assert!(array_all!(is_nonzero, TABLE));
+///
+/// assert!(!array_all!(is_nonzero, [3, 0, 5]));
+///
+/// ```
+#[macro_export]
+macro_rules! array_all {
+    ($predicate:expr, $array:expr $(,)?) => {{
+        let array = &$array;
+
+        let mut i = 0;
+        while i < array.len() && $predicate(&array[i]) {
+            i += 1;
+        }
+        i == array.len()
+    }};
+}
+
+/// Checks whether a predicate returns `true` for any element of an array.
+///
+/// This macro is callable in const contexts, if the predicate is a `const fn`.
+///
+/// Returns `false` for empty arrays,
+/// and stops calling the predicate at the first element that it returns `true` for.
+///
+/// # Syntax
+///
+/// ```text
+/// array_any!($predicate:expr, $array:expr $(,)?)
+/// ```
+///
+/// Where `$predicate` is callable as `$predicate(&T) -> bool`.
+///
+/// Where `$array` is a `[T; N]` array, or a reference to one.
+///
+/// # Example
+///
+/// ```rust
+/// use arrcat::{array_any, concat_arrays};
+///
+/// const fn is_zero(x: &u8) -> bool {
+///     *x == 0
+/// }
+///
+/// const TABLE: [u8; 5] = concat_arrays!([3, 5], [8, 0, 21]);
+/// const HAS_ZERO: bool = array_any!(is_zero, TABLE);
+///
+/// assert!(HAS_ZERO);
+/// assert!(!array_any!(is_zero, [3, 5]));
+///
+/// ```
+#[macro_export]
+macro_rules! array_any {
+    ($predicate:expr, $array:expr $(,)?) => {{
+        let array = &$array;
+
+        let mut i = 0;
+        while i < array.len() && !$predicate(&array[i]) {
+            i += 1;
+        }
+        i != array.len()
+    }};
+}
+
+/// Computes the running sums of the concatenation of integer arrays.
+///
+/// This macro is callable in const contexts.
+///
+/// This evaluates to an `([T; N], [T; N + 1])` tuple,
+/// with the concatenation of the arguments, and its prefix sums.
+/// The prefix sums start with `0` and end with the sum of every element,
+/// so `sums[i]` is the offset of the `i`th element when used as lengths.
+///
+/// # Syntax
+///
+/// This takes the same arguments as [`concat_arrays`](crate::concat_arrays#syntax),
+/// whose concatenation must be a `[T; N]` array, where `T` is an integer type.
+///
+/// # Panics
+///
+/// Panics (in debug builds) if any sum overflows `T`.
+///
+/// # Example
+///
+/// ```rust
+/// use arrcat::prefix_sums;
+///
+/// const FIELD_LENS: [usize; 3] = [2, 3, 1];
+///
+/// const LAYOUT: ([usize; 3], [usize; 4]) = prefix_sums!(FIELD_LENS);
+///
+/// assert_eq!(LAYOUT, ([2, 3, 1], [0, 2, 5, 6]));
+///
+/// ```
+#[macro_export]
+macro_rules! prefix_sums {
+    ($($array_args:tt)*) => {{
+        const __SUMMED_LEN: $crate::__::usize =
+            $crate::__concat_arrays_length_of!($($array_args)*);
+
+        let array: [_; __SUMMED_LEN] = $crate::concat_arrays!($($array_args)*);
+
+        let mut sums = [0; __SUMMED_LEN + 1];
+        let mut i = 0;
+        while i < __SUMMED_LEN {
+            sums[i + 1] = sums[i] + array[i];
+            i += 1;
+        }
+        (array, sums)
+    }};
+}
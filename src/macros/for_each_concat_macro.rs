@@ -0,0 +1,88 @@
+/// Concatenates multiple arrays, running some code for each element of the concatenation.
+///
+/// This macro is callable in const contexts, when passed closure syntax,
+/// or when passed a `const fn`.
+///
+/// # Syntax
+///
+/// The syntax of this macro, using `macro_rules!`-like input syntax
+///
+/// ```text
+/// for_each_concat!{
+///     $function:function;
+///     $( $array_arg:array_expr $(: $argument_type:ty )? ),*
+///     $(,)?
+/// }
+/// ```
+///
+/// Where `$function` can be any of:
+///
+/// - `|$elem:pat_param| $body:expr`:
+///   closure syntax, which is expanded inline,
+///   running `$body` with each element bound to the `$elem` pattern.
+///   The element type can be annotated with `|$elem:ident: $elem_type:ty|`.
+///   Because it's expanded inline, it can be used in const contexts,
+///   and `$body` can use `continue` to skip to the next element.
+///
+/// - `$function:expr`: a function callable as `$function(T)`,
+///   which must be a `const fn` to use this macro in const contexts.
+///
+/// Where `T` is the element type of the arguments, which must be `Copy`.
+///
+/// The arguments are passed as in [`concat_arrays`](crate::concat_arrays),
+/// including type annotations and nested `concat_arrays` invocations.
+///
+/// # Example
+///
+/// ```rust
+/// use arrcat::for_each_concat;
+///
+/// const SUM: u32 = {
+///     let runtime = [8, 13];
+///     let mut sum = 0;
+///     for_each_concat!(|x| sum += x; [3, 5], runtime: [_; 2], [21]);
+///     sum
+/// };
+///
+/// assert_eq!(SUM, 50);
+///
+/// let mut evens = Vec::new();
+/// for_each_concat!(
+///     |x| {
+///         if x % 2 != 0 {
+///             continue
+///         }
+///         evens.push(x)
+///     };
+///     [1, 2, 3],
+///     [4, 5, 6],
+/// );
+/// assert_eq!(evens, [2, 4, 6]);
+///
+/// ```
+#[macro_export]
+macro_rules! for_each_concat {
+    (|$elem:ident : $elem_ty:ty| $body:expr; $($args:tt)*) => {{
+        let concat = $crate::concat_arrays!($($args)*);
+
+        let mut i = 0;
+        while i < concat.len() {
+            let $elem: $elem_ty = concat[i];
+            i += 1;
+            $body;
+        }
+    }};
+    (|$elem:pat_param| $body:expr; $($args:tt)*) => {{
+        let concat = $crate::concat_arrays!($($args)*);
+
+        let mut i = 0;
+        while i < concat.len() {
+            let $elem = concat[i];
+            i += 1;
+            $body;
+        }
+    }};
+    ($function:expr; $($args:tt)*) => {
+        $crate::for_each_concat!(|elem| $function(elem); $($args)*)
+    };
+}
@@ -0,0 +1,221 @@
+/// Gets the index of the maximum element of an array.
+///
+/// This macro is callable in const contexts.
+///
+/// If there are multiple maximum elements, the index of the first one is returned.
+///
+/// The elements are compared with the `>` operator,
+/// to use a comparator function, use [`array_index_of_max_by`](crate::array_index_of_max_by) instead.
+///
+/// # Panics
+///
+/// Panics if the array is empty.
+///
+/// # Example
+///
+/// ```rust
+/// use arrcat::array_index_of_max;
+///
+/// const INDEX: usize = array_index_of_max!([3u8, 9, 1]);
+/// assert_eq!(INDEX, 1);
+///
+/// // the first occurrence of the maximum wins
+/// assert_eq!(array_index_of_max!([5, 8, 2, 8]), 1);
+/// ```
+#[macro_export]
+macro_rules! array_index_of_max {
+    ($array:expr $(,)?) => {
+        $crate::__array_index_of_extremum! {$array, |l, r| *l > *r}
+    };
+}
+
+/// Gets the index of the minimum element of an array.
+///
+/// This macro is callable in const contexts.
+///
+/// If there are multiple minimum elements, the index of the first one is returned.
+///
+/// The elements are compared with the `<` operator,
+/// to use a comparator function, use [`array_index_of_min_by`](crate::array_index_of_min_by) instead.
+///
+/// # Panics
+///
+/// Panics if the array is empty.
+///
+/// # Example
+///
+/// ```rust
+/// use arrcat::array_index_of_min;
+///
+/// const INDEX: usize = array_index_of_min!([3u8, 9, 1]);
+/// assert_eq!(INDEX, 2);
+///
+/// // the first occurrence of the minimum wins
+/// assert_eq!(array_index_of_min!([5, 2, 8, 2]), 1);
+/// ```
+#[macro_export]
+macro_rules! array_index_of_min {
+    ($array:expr $(,)?) => {
+        $crate::__array_index_of_extremum! {$array, |l, r| *l < *r}
+    };
+}
+
+/// Gets the index of the maximum element of an array, using a comparator function.
+///
+/// This macro is callable in const contexts.
+///
+/// The comparator must be callable as `$comparator(&T, &T) -> core::cmp::Ordering`,
+/// if this macro is used in a const context, it must be a `const fn`.
+///
+/// If there are multiple maximum elements, the index of the first one is returned.
+///
+/// # Panics
+///
+/// Panics if the array is empty.
+///
+/// # Example
+///
+/// ```rust
+/// use arrcat::array_index_of_max_by;
+///
+/// use core::cmp::Ordering;
+///
+/// const fn cmp_len(l: &&str, r: &&str) -> Ordering {
+///     if l.len() < r.len() {
+///         Ordering::Less
+///     } else if l.len() == r.len() {
+///         Ordering::Equal
+///     } else {
+///         Ordering::Greater
+///     }
+/// }
+///
+/// const INDEX: usize = array_index_of_max_by!(["foo", "hello", "bar", "world"], cmp_len);
+/// assert_eq!(INDEX, 1);
+/// ```
+#[macro_export]
+macro_rules! array_index_of_max_by {
+    ($array:expr, $comparator:expr $(,)?) => {
+        $crate::__array_index_of_extremum! {
+            $array,
+            |l, r| $crate::__::matches!($comparator(l, r), $crate::__::Ordering::Greater)
+        }
+    };
+}
+
+/// Gets the index of the minimum element of an array, using a comparator function.
+///
+/// This macro is callable in const contexts.
+///
+/// The comparator must be callable as `$comparator(&T, &T) -> core::cmp::Ordering`,
+/// if this macro is used in a const context, it must be a `const fn`.
+///
+/// If there are multiple minimum elements, the index of the first one is returned.
+///
+/// # Panics
+///
+/// Panics if the array is empty.
+///
+/// # Example
+///
+/// ```rust
+/// use arrcat::array_index_of_min_by;
+///
+/// use core::cmp::Ordering;
+///
+/// const fn cmp_len(l: &&str, r: &&str) -> Ordering {
+///     if l.len() < r.len() {
+///         Ordering::Less
+///     } else if l.len() == r.len() {
+///         Ordering::Equal
+///     } else {
+///         Ordering::Greater
+///     }
+/// }
+///
+/// const INDEX: usize = array_index_of_min_by!(["hello", "foo", "world", "bar"], cmp_len);
+/// assert_eq!(INDEX, 1);
+/// ```
+#[macro_export]
+macro_rules! array_index_of_min_by {
+    ($array:expr, $comparator:expr $(,)?) => {
+        $crate::__array_index_of_extremum! {
+            $array,
+            |l, r| $crate::__::matches!($comparator(l, r), $crate::__::Ordering::Less)
+        }
+    };
+}
+
+/// Gets the index of the first occurrence of `needle` in `haystack`.
+///
+/// This macro is callable in const contexts.
+///
+/// Both arguments must be arrays (or references to arrays),
+/// and their elements are compared with the `==` operator.
+///
+/// Returns `None` if `needle` doesn't occur in `haystack`,
+/// including when `needle` is longer than `haystack`.
+/// An empty `needle` is found at index `0`.
+///
+/// # Example
+///
+/// ```rust
+/// use arrcat::{concat_arrays, find_subarray};
+///
+/// const BLOB: [u8; 8] = concat_arrays!((*b"head"), [0xFF, 0xFE], (*b"ok"));
+///
+/// const MARKER: Option<usize> = find_subarray!(BLOB, [0xFF, 0xFE]);
+/// assert_eq!(MARKER, Some(4));
+///
+/// assert_eq!(find_subarray!(BLOB, *b"ok"), Some(6));
+/// assert_eq!(find_subarray!(BLOB, *b"no"), None);
+/// ```
+#[macro_export]
+macro_rules! find_subarray {
+    ($haystack:expr, $needle:expr $(,)?) => {{
+        let haystack = &$haystack;
+        let needle = &$needle;
+
+        let mut found = $crate::__::None;
+        if needle.len() <= haystack.len() {
+            let mut i = 0usize;
+            while i <= haystack.len() - needle.len() {
+                let mut j = 0usize;
+                while j < needle.len() && haystack[i + j] == needle[j] {
+                    j += 1;
+                }
+                if j == needle.len() {
+                    found = $crate::__::Some(i);
+                    break;
+                }
+                i += 1;
+            }
+        }
+        found
+    }};
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __array_index_of_extremum {
+    ($array:expr, |$l:ident, $r:ident| $is_better:expr) => {{
+        let array = &$array;
+
+        $crate::__::assert!(
+            array.len() != 0,
+            "cannot get the index of the extremum of an empty array",
+        );
+
+        let mut best = 0usize;
+        let mut i = 1usize;
+        while i < array.len() {
+            let $l = &array[i];
+            let $r = &array[best];
+            if $is_better {
+                best = i;
+            }
+            i += 1;
+        }
+        best
+    }};
+}
@@ -0,0 +1,254 @@
+/// Constructs an array with the integers from `0` up to (but excluding) a length.
+///
+/// This macro is callable in const contexts.
+///
+/// This is useful as a starting point for permutation tables.
+///
+/// # Syntax
+///
+/// ```text
+/// identity_perm!($length:expr $(, $integer_type:ty)? $(,)?)
+/// ```
+///
+/// Where `$length` is a constant `usize` expression for the length of the returned array.
+///
+/// Where `$integer_type` is the primitive integer type of the elements,
+/// defaulting to `usize`.
+///
+/// # Compile-time errors
+///
+/// This macro causes a compile-time error if
+/// `$length - 1` can't be represented by `$integer_type`.
+///
+/// # Example
+///
+/// ```rust
+/// use arrcat::identity_perm;
+///
+/// const PERM: [usize; 5] = identity_perm!(5);
+/// assert_eq!(PERM, [0, 1, 2, 3, 4]);
+///
+/// assert_eq!(identity_perm!(3, u8), [0u8, 1, 2]);
+///
+/// assert_eq!(identity_perm!(0, i16), [0i16; 0]);
+///
+/// ```
+#[macro_export]
+macro_rules! identity_perm {
+    ($length:expr $(,)?) => {
+        $crate::identity_perm!($length, $crate::__::usize)
+    };
+    ($length:expr, $integer_type:ty $(,)?) => {{
+        const __LEN: $crate::__::usize = {
+            let len: $crate::__::usize = $length;
+            $crate::__::assert!(
+                len == 0 || (len - 1) as $integer_type as $crate::__::usize == len - 1,
+                "the length passed to `identity_perm` doesn't fit in the element type",
+            );
+            len
+        };
+
+        let mut array: [$integer_type; __LEN] = [0; __LEN];
+        let mut i = 0;
+        while i < __LEN {
+            array[i] = i as $integer_type;
+            i += 1;
+        }
+        array
+    }};
+}
+
+/// Reorders an array by a permutation array of indices.
+///
+/// This macro is callable in const contexts.
+///
+/// For every index `i`, the returned array contains `array[permutation[i]]` at `i`.
+///
+/// # Syntax
+///
+/// ```text
+/// apply_permutation!($array:expr, $permutation:expr $(,)?)
+/// ```
+///
+/// Where `$array` is a `[T; N]` array, and `T` is a `Copy` type.
+///
+/// Where `$permutation` is a `[usize; N]` array of indices into `$array`.
+///
+/// # Panics
+///
+/// Panics if any index in `$permutation` is out of bounds for `$array`,
+/// which is a compile-time error in const contexts.
+///
+/// # Example
+///
+/// ```rust
+/// use arrcat::{apply_permutation, identity_perm};
+///
+/// const ARR: [char; 4] = ['a', 'b', 'c', 'd'];
+///
+/// const REVERSED: [char; 4] = apply_permutation!(ARR, [3, 2, 1, 0]);
+/// assert_eq!(REVERSED, ['d', 'c', 'b', 'a']);
+///
+/// assert_eq!(apply_permutation!(ARR, identity_perm!(4)), ARR);
+///
+/// ```
+#[macro_export]
+macro_rules! apply_permutation {
+    ($array:expr, $permutation:expr $(,)?) => {{
+        let array = &$array;
+        let permutation = &$permutation;
+        $crate::__::assert_same_length(array, permutation);
+
+        let mut out = *array;
+        let mut i = 0;
+        while i < out.len() {
+            let index: $crate::__::usize = permutation[i];
+            $crate::__::assert!(
+                index < out.len(),
+                "index passed to `apply_permutation` is out of bounds",
+            );
+            out[i] = array[index];
+            i += 1;
+        }
+        out
+    }};
+}
+
+/// Constructs an array by selecting elements of another array by index.
+///
+/// This macro is callable in const contexts.
+///
+/// For every index `i`, the returned array contains `array[indices[i]]` at `i`.
+/// Unlike [`apply_permutation`](crate::apply_permutation),
+/// the returned array has the length of `$indices`,
+/// which can be different from the length of `$array`, and can repeat indices.
+///
+/// # Syntax
+///
+/// ```text
+/// gather!($array:expr, $indices:expr $(,)?)
+/// ```
+///
+/// Where `$array` is a `[T; N]` array, and `T` is a `Copy` type.
+///
+/// Where `$indices` is a `[usize; M]` array of indices into `$array`.
+///
+/// # Panics
+///
+/// Panics if any index in `$indices` is out of bounds for `$array`,
+/// which is a compile-time error in const contexts.
+///
+/// # Example
+///
+/// ```rust
+/// use arrcat::gather;
+///
+/// const ARR: [char; 3] = ['a', 'b', 'c'];
+///
+/// const GATHERED: [char; 4] = gather!(ARR, [2, 0, 2, 1]);
+/// assert_eq!(GATHERED, ['c', 'a', 'c', 'b']);
+///
+/// assert_eq!(gather!(ARR, [1]), ['b']);
+///
+/// ```
+#[macro_export]
+macro_rules! gather {
+    ($array:expr, $indices:expr $(,)?) => {{
+        let array = &$array;
+        let indices = &$indices;
+
+        let mut out = $crate::__::uninit_array();
+        $crate::__::assert_same_length(indices, &out);
+
+        let mut i = 0;
+        while i < out.len() {
+            let index: $crate::__::usize = indices[i];
+            $crate::__::assert!(
+                index < array.len(),
+                "index passed to `gather` is out of bounds",
+            );
+            out[i] = $crate::__::MaybeUninit::new(array[index]);
+            i += 1;
+        }
+
+        // SAFETY: all elements of `out` were initialized in the loop above
+        unsafe { $crate::__::array_assume_init(out) }
+    }};
+}
+
+/// Constructs an array by writing the elements of another array to the positions in an
+/// index array, the inverse of [`gather`](crate::gather).
+///
+/// This macro is callable in const contexts.
+///
+/// For every index `i`, the returned array contains `array[i]` at `indices[i]`,
+/// the positions that no index refers to contain `$default`.
+/// If an index is repeated, the element for its last occurrence is the one
+/// in the returned array.
+///
+/// # Syntax
+///
+/// ```text
+/// scatter!($default:expr, $array:expr, $indices:expr $(, $out_len:expr)? $(,)?)
+/// ```
+///
+/// Where `$default` is a `T`, and `T` is a `Copy` type.
+///
+/// Where `$array` is a `[T; N]` array.
+///
+/// Where `$indices` is a `[usize; N]` array of indices into the returned array.
+///
+/// Where `$out_len` is a constant `usize` expression for the length of the returned array,
+/// which is inferred from the context if not passed.
+///
+/// # Panics
+///
+/// Panics if any index in `$indices` is out of bounds for the returned array,
+/// which is a compile-time error in const contexts.
+///
+/// # Example
+///
+/// ```rust
+/// use arrcat::scatter;
+///
+/// const ARR: [char; 3] = ['a', 'b', 'c'];
+///
+/// const SCATTERED: [char; 3] = scatter!('-', ARR, [2, 0, 1]);
+/// assert_eq!(SCATTERED, ['b', 'c', 'a']);
+///
+/// // positions that aren't written to are `'-'`
+/// assert_eq!(scatter!('-', ARR, [4, 0, 2], 5), ['b', '-', 'c', '-', 'a']);
+///
+/// ```
+#[macro_export]
+macro_rules! scatter {
+    ($default:expr, $array:expr, $indices:expr $(, $out_len:expr)? $(,)?) => {{
+        let array = &$array;
+        let indices = &$indices;
+        $crate::__::assert_same_length(array, indices);
+
+        let default = $default;
+        let mut out = $crate::__::uninit_array $(::<_, { $out_len }>)? ();
+
+        let mut i = 0;
+        while i < out.len() {
+            out[i] = $crate::__::MaybeUninit::new(default);
+            i += 1;
+        }
+
+        // SAFETY: all elements of `out` were initialized in the loop above
+        let mut out = unsafe { $crate::__::array_assume_init(out) };
+
+        let mut i = 0;
+        while i < array.len() {
+            let index: $crate::__::usize = indices[i];
+            $crate::__::assert!(
+                index < out.len(),
+                "index passed to `scatter` is out of bounds",
+            );
+            out[index] = array[i];
+            i += 1;
+        }
+        out
+    }};
+}
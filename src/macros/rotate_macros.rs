@@ -0,0 +1,121 @@
+/// Rotates a square matrix 90 degrees clockwise.
+///
+/// This macro is callable in const contexts.
+///
+/// # Syntax
+///
+/// ```text
+/// rotate90!($matrix:expr $(,)?)
+/// ```
+///
+/// Where `$matrix` is a `[[T; N]; N]` array, and `T` is a `Copy` type.
+///
+/// # Compile-time errors
+///
+/// This macro causes a compile-time error if `$matrix` isn't square.
+///
+/// # Example
+///
+/// ```rust
+/// use arrcat::{matrix, rotate90};
+///
+/// const MATRIX: [[u8; 2]; 2] = matrix!([1, 2], [3, 4]);
+///
+/// assert_eq!(rotate90!(MATRIX), [[3, 1], [4, 2]]);
+///
+/// ```
+#[macro_export]
+macro_rules! rotate90 {
+    ($matrix:expr $(,)?) => {
+        $crate::__rotate_square!($matrix, |out, i, j, last| out[j][last - i])
+    };
+}
+
+/// Rotates a square matrix 180 degrees.
+///
+/// This macro is callable in const contexts.
+///
+/// # Syntax
+///
+/// ```text
+/// rotate180!($matrix:expr $(,)?)
+/// ```
+///
+/// Where `$matrix` is a `[[T; N]; N]` array, and `T` is a `Copy` type.
+///
+/// # Compile-time errors
+///
+/// This macro causes a compile-time error if `$matrix` isn't square.
+///
+/// # Example
+///
+/// ```rust
+/// use arrcat::{matrix, rotate180};
+///
+/// const MATRIX: [[u8; 2]; 2] = matrix!([1, 2], [3, 4]);
+///
+/// assert_eq!(rotate180!(MATRIX), [[4, 3], [2, 1]]);
+///
+/// ```
+#[macro_export]
+macro_rules! rotate180 {
+    ($matrix:expr $(,)?) => {
+        $crate::__rotate_square!($matrix, |out, i, j, last| out[last - i][last - j])
+    };
+}
+
+/// Rotates a square matrix 270 degrees clockwise (90 degrees counterclockwise).
+///
+/// This macro is callable in const contexts.
+///
+/// # Syntax
+///
+/// ```text
+/// rotate270!($matrix:expr $(,)?)
+/// ```
+///
+/// Where `$matrix` is a `[[T; N]; N]` array, and `T` is a `Copy` type.
+///
+/// # Compile-time errors
+///
+/// This macro causes a compile-time error if `$matrix` isn't square.
+///
+/// # Example
+///
+/// ```rust
+/// use arrcat::{matrix, rotate270};
+///
+/// const MATRIX: [[u8; 2]; 2] = matrix!([1, 2], [3, 4]);
+///
+/// assert_eq!(rotate270!(MATRIX), [[2, 4], [1, 3]]);
+///
+/// ```
+#[macro_export]
+macro_rules! rotate270 {
+    ($matrix:expr $(,)?) => {
+        $crate::__rotate_square!($matrix, |out, i, j, last| out[last - j][i])
+    };
+}
+
+// moves `matrix[i][j]` into the `$out_elem` position of the returned matrix
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __rotate_square {
+    ($matrix:expr, |$out:ident, $i:ident, $j:ident, $last:ident| $out_elem:expr) => {{
+        let matrix = $matrix;
+        $crate::__::assert_square(&matrix);
+
+        let mut $out = matrix;
+        let mut $i = 0;
+        while $i < matrix.len() {
+            let $last = matrix.len() - 1;
+            let mut $j = 0;
+            while $j < matrix.len() {
+                $out_elem = matrix[$i][$j];
+                $j += 1;
+            }
+            $i += 1;
+        }
+        $out
+    }};
+}
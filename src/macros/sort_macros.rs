@@ -0,0 +1,217 @@
+/// Concatenates multiple arrays, sorting the elements of the concatenation.
+///
+/// This macro is callable in const contexts.
+///
+/// The elements are compared with the `<` operator,
+/// which requires them to be primitive types in const contexts.
+/// To use a comparator function, use [`concat_sorted_by`](crate::concat_sorted_by) instead.
+///
+/// The sort is stable, equal elements keep the order that they were passed in.
+///
+/// # Syntax
+///
+/// This takes the same arguments as [`concat_arrays`](crate::concat_arrays#syntax),
+/// the element type of the concatenation must be `Copy`.
+///
+/// # Example
+///
+/// ```rust
+/// use arrcat::concat_sorted;
+///
+/// const PRIMES: [u8; 3] = [7, 2, 5];
+///
+/// const SORTED: [u8; 5] = concat_sorted!([3, 1], PRIMES);
+///
+/// assert_eq!(SORTED, [1, 2, 3, 5, 7]);
+///
+/// ```
+#[macro_export]
+macro_rules! concat_sorted {
+    ($($args:tt)*) => {
+        $crate::__concat_sorted!{($($args)*) |l, r| *l < *r}
+    };
+}
+
+/// Concatenates multiple arrays, sorting the elements of the concatenation
+/// with a comparator function.
+///
+/// This macro is callable in const contexts, if the comparator is a `const fn`.
+///
+/// The sort is stable, equal elements keep the order that they were passed in.
+///
+/// # Syntax
+///
+/// The syntax of this macro, using `macro_rules!`-like input syntax
+///
+/// ```text
+/// concat_sorted_by!{
+///     $comparator:expr;
+///     $( $array_arg:array_expr $(: $argument_type:ty )? ),*
+///     $(,)?
+/// }
+/// ```
+///
+/// Where `$comparator` is callable as `$comparator(&T, &T) -> core::cmp::Ordering`.
+///
+/// The arguments are passed as in [`concat_arrays`](crate::concat_arrays#syntax),
+/// and `T`, the element type of the concatenation, must be `Copy`.
+///
+/// # Example
+///
+/// ```rust
+/// use arrcat::concat_sorted_by;
+///
+/// use core::cmp::Ordering;
+///
+/// const fn cmp_len(l: &&str, r: &&str) -> Ordering {
+///     if l.len() < r.len() {
+///         Ordering::Less
+///     } else if l.len() == r.len() {
+///         Ordering::Equal
+///     } else {
+///         Ordering::Greater
+///     }
+/// }
+///
+/// const SORTED: [&str; 4] = concat_sorted_by!(cmp_len; ["hello", "foo"], ["ab", "bar"]);
+///
+/// // "foo" and "bar" have the same length, so they keep their order
+/// assert_eq!(SORTED, ["ab", "foo", "bar", "hello"]);
+///
+/// ```
+#[macro_export]
+macro_rules! concat_sorted_by {
+    ($comparator:expr; $($args:tt)*) => {
+        $crate::__concat_sorted!{
+            ($($args)*)
+            |l, r| $crate::__::matches!($comparator(l, r), $crate::__::Ordering::Less)
+        }
+    };
+}
+
+/// Concatenates multiple arrays, reversing the order of the elements of the concatenation.
+///
+/// This macro is callable in const contexts.
+///
+/// The concatenation is reversed in place, without creating another array.
+///
+/// # Syntax
+///
+/// This takes the same arguments as [`concat_arrays`](crate::concat_arrays#syntax),
+/// the element type of the concatenation must be `Copy`.
+///
+/// # Example
+///
+/// ```rust
+/// use arrcat::concat_rev;
+///
+/// const TAIL: [u8; 2] = [3, 4];
+///
+/// const REVERSED: [u8; 4] = concat_rev!([1, 2], TAIL);
+///
+/// assert_eq!(REVERSED, [4, 3, 2, 1]);
+///
+/// ```
+#[macro_export]
+macro_rules! concat_rev {
+    ($($args:tt)*) => {{
+        let mut array = $crate::concat_arrays!($($args)*);
+
+        let mut i = 0;
+        // `<[T]>::swap` isn't const on the minimum supported Rust version
+        #[allow(unknown_lints, clippy::manual_swap)]
+        while i < array.len() / 2 {
+            let j = array.len() - 1 - i;
+            let prev = array[i];
+            array[i] = array[j];
+            array[j] = prev;
+            i += 1;
+        }
+        array
+    }};
+}
+
+/// Concatenates multiple arrays, sorting the elements and removing duplicates.
+///
+/// This macro is callable in const contexts.
+///
+/// The elements are compared with the `<` and `==` operators,
+/// which requires them to be primitive types in const contexts.
+///
+/// Since the length of the returned array can't depend on its contents,
+/// this evaluates to a `([T; LEN], usize)` tuple,
+/// where `LEN` is the length of the concatenation,
+/// with the sorted unique elements at the start of the array, followed by their amount.
+/// The elements after the unique ones are copies of the greatest element.
+///
+/// The concatenation is sorted and deduplicated in place, without creating another array.
+///
+/// # Syntax
+///
+/// This takes the same arguments as [`concat_arrays`](crate::concat_arrays#syntax),
+/// the element type of the concatenation must be `Copy`.
+///
+/// # Example
+///
+/// ```rust
+/// use arrcat::concat_canonical;
+///
+/// const KEYS: ([u8; 5], usize) = concat_canonical!([3, 1], [2, 1, 5]);
+///
+/// assert_eq!(KEYS, ([1, 2, 3, 5, 5], 4));
+/// assert_eq!(KEYS.0[..KEYS.1], [1, 2, 3, 5]);
+///
+/// ```
+#[macro_export]
+macro_rules! concat_canonical {
+    ($($args:tt)*) => {{
+        let mut array = $crate::concat_sorted!($($args)*);
+
+        let mut len = 0usize;
+        let mut i = 0usize;
+        while i < array.len() {
+            if i == 0 || array[i] != array[len - 1] {
+                array[len] = array[i];
+                len += 1;
+            }
+            i += 1;
+        }
+
+        let mut i = len;
+        while i < array.len() {
+            array[i] = array[len - 1];
+            i += 1;
+        }
+
+        (array, len)
+    }};
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __concat_sorted {
+    (($($args:tt)*) |$l:ident, $r:ident| $is_less:expr) => {{
+        let mut array = $crate::concat_arrays!($($args)*);
+
+        // insertion sort, which is stable because elements are only moved
+        // before the elements that they're strictly less than.
+        let mut i = 1;
+        // `<[T]>::swap` isn't const on the minimum supported Rust version
+        #[allow(unknown_lints, clippy::manual_swap)]
+        while i < array.len() {
+            let mut j = i;
+            while j != 0 && {
+                let $l = &array[j];
+                let $r = &array[j - 1];
+                $is_less
+            } {
+                let prev = array[j - 1];
+                array[j - 1] = array[j];
+                array[j] = prev;
+                j -= 1;
+            }
+            i += 1;
+        }
+        array
+    }};
+}
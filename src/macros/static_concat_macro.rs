@@ -0,0 +1,37 @@
+/// Concatenates constant arrays into a `&'static` array.
+///
+/// This macro requires the `"rust_1_83"` crate feature.
+///
+/// This takes the same arguments as [`concat_arrays`](crate::concat_arrays),
+/// and evaluates the concatenation in an inline `const` block,
+/// returning a reference to it.
+/// Since the concatenated array is stored in the binary,
+/// using the returned reference doesn't copy the array.
+///
+/// All arguments must be constants,
+/// and the arguments can't depend on surrounding generic parameters.
+///
+/// # Example
+///
+/// ```rust
+/// use arrcat::static_concat;
+///
+/// const HEADER: [u8; 2] = [0xAB, 0xCD];
+///
+/// fn table() -> &'static [u8; 5] {
+///     static_concat!(HEADER, [3, 5, 8])
+/// }
+///
+/// assert_eq!(table(), &[0xAB, 0xCD, 3, 5, 8]);
+///
+/// // every call returns a reference to the same array
+/// assert!(core::ptr::eq(table(), table()));
+///
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "rust_1_83")))]
+#[macro_export]
+macro_rules! static_concat {
+    ($($args:tt)*) => {
+        const { &$crate::concat_arrays!($($args)*) }
+    };
+}
@@ -0,0 +1,44 @@
+/// Finds the length of an array with the trailing occurrences of a value removed.
+///
+/// This macro is callable in const contexts.
+///
+/// Since the length of the returned array can't depend on its contents,
+/// this evaluates to a `([T; N], usize)` tuple,
+/// with the passed-in array and the trimmed length.
+/// The trimmed elements are `array[..trimmed_length]`.
+///
+/// # Syntax
+///
+/// ```text
+/// trim_trailing!($array:expr, $value:expr $(,)?)
+/// ```
+///
+/// Where `$array` is a `[T; N]` array.
+///
+/// Where `$value` is a `T` value that's compared to the elements with the `==` operator,
+/// this requires `T` to be a primitive type in const contexts.
+///
+/// # Example
+///
+/// ```rust
+/// use arrcat::{concat_arrays, trim_trailing};
+///
+/// const NAME: ([u8; 8], usize) = trim_trailing!(concat_arrays!((*b"hello"), [0; 3]), 0);
+///
+/// assert_eq!(NAME, (*b"hello\0\0\0", 5));
+/// assert_eq!(&NAME.0[..NAME.1], b"hello");
+///
+/// ```
+#[macro_export]
+macro_rules! trim_trailing {
+    ($array:expr, $value:expr $(,)?) => {{
+        let array = $array;
+        let value = $value;
+
+        let mut len = array.len();
+        while len != 0 && array[len - 1] == value {
+            len -= 1;
+        }
+        (array, len)
+    }};
+}
@@ -0,0 +1,130 @@
+use core::ops::Deref;
+
+use bytemuck::Pod;
+
+/// Concatenates multiple arrays of [`Pod`](bytemuck::Pod) elements.
+///
+/// This macro is not callable in const contexts,
+/// because const fns can't have trait bounds in Rust 1.57.0.
+///
+/// This is equivalent to [`concat_arrays`](crate::concat_arrays),
+/// but requires the element type to implement [`Pod`](bytemuck::Pod),
+/// which guarantees that the elements have no padding bytes or invalid bit patterns,
+/// so that the concatenation is valid regardless of how it's reinterpreted
+/// (eg: when passed through FFI).
+///
+/// # Syntax
+///
+/// This takes the same arguments as [`concat_arrays`](crate::concat_arrays#syntax).
+///
+/// # Example
+///
+/// ```rust
+/// use arrcat::pod_concat;
+///
+/// let header = [0xABu16, 0xCD];
+///
+/// let packet: [u16; 5] = pod_concat!(header: [_; 2], [3, 5, 8]);
+///
+/// assert_eq!(packet, [0xAB, 0xCD, 3, 5, 8]);
+/// assert_eq!(bytemuck::bytes_of(&packet[0]), 0xABu16.to_ne_bytes());
+///
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "bytemuck")))]
+#[macro_export]
+macro_rules! pod_concat {
+    ($($array_args:tt)*) => {
+        $crate::__::pod_array($crate::concat_arrays!($($array_args)*))
+    };
+}
+
+/// Concatenates multiple arrays of [`Pod`](bytemuck::Pod) elements,
+/// evaluating to a [`PodBytes`](crate::PodBytes) that derefs to the bytes of the concatenation.
+///
+/// This macro is not callable in const contexts,
+/// because const fns can't have trait bounds in Rust 1.57.0.
+///
+/// # Syntax
+///
+/// This takes the same arguments as [`concat_arrays`](crate::concat_arrays#syntax).
+///
+/// # Example
+///
+/// ```rust
+/// use arrcat::bytes_concat;
+///
+/// let bytes = bytes_concat!([1u16, 2], [0x0304]);
+///
+/// assert_eq!(bytes.len(), 6);
+/// assert_eq!(bytes[..2], 1u16.to_ne_bytes());
+/// assert_eq!(bytes[4..], 0x0304u16.to_ne_bytes());
+///
+/// assert_eq!(bytes.into_array(), [1, 2, 0x0304]);
+///
+/// ```
+#[cfg_attr(docsrs, doc(cfg(feature = "bytemuck")))]
+#[macro_export]
+macro_rules! bytes_concat {
+    ($($array_args:tt)*) => {
+        $crate::PodBytes::new($crate::concat_arrays!($($array_args)*))
+    };
+}
+
+#[doc(hidden)]
+#[inline(always)]
+pub fn pod_array<T: Pod, const N: usize>(array: [T; N]) -> [T; N] {
+    array
+}
+
+/// An array of [`Pod`] elements that derefs to its bytes,
+/// returned by [`bytes_concat`](crate::bytes_concat).
+///
+/// # Example
+///
+/// ```rust
+/// use arrcat::PodBytes;
+///
+/// let bytes = PodBytes::new([0x01020304u32.to_be()]);
+///
+/// assert_eq!(*bytes, [1, 2, 3, 4]);
+/// assert_eq!(bytes.as_array(), &[0x01020304u32.to_be()]);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct PodBytes<T, const N: usize> {
+    array: [T; N],
+}
+
+impl<T: Pod, const N: usize> PodBytes<T, N> {
+    /// Constructs a `PodBytes` from an array.
+    #[inline]
+    pub fn new(array: [T; N]) -> Self {
+        Self { array }
+    }
+
+    /// Gets the bytes of the array.
+    #[inline]
+    pub fn as_bytes(&self) -> &[u8] {
+        bytemuck::cast_slice(&self.array)
+    }
+
+    /// Gets a reference to the array.
+    #[inline]
+    pub fn as_array(&self) -> &[T; N] {
+        &self.array
+    }
+
+    /// Unwraps the array.
+    #[inline]
+    pub fn into_array(self) -> [T; N] {
+        self.array
+    }
+}
+
+impl<T: Pod, const N: usize> Deref for PodBytes<T, N> {
+    type Target = [u8];
+
+    #[inline]
+    fn deref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
@@ -1,6 +1,15 @@
 use crate::concat_arrays;
 
-use core::{cmp::PartialEq, fmt::Debug};
+#[cfg(feature = "rust_1_83")]
+mod array_fn_tests;
+
+#[cfg(feature = "__test_nightly")]
+mod const_trait_tests;
+
+#[cfg(feature = "bytemuck")]
+mod pod_tests;
+
+use core::{cell::Cell, cmp::PartialEq, fmt::Debug};
 
 macro_rules! generic_test {
     ($conv:ident => $($code:tt)*) => {
@@ -37,6 +46,7 @@ fn test_empty_array() {
 }
 
 #[test]
+#[allow(unused_parens)]
 fn test_array_arg() {
     generic_test! {f=>
         // elems
@@ -288,58 +298,3481 @@ fn length_type_arg() {
         asserteq(A, [1, 2, 2, 2, 5, 8, 13, 21, 34]);
         assert_eq!(L::LEN, 9);
     }
+    {
+        enum L {}
+        const A: [u8; L::FIRST] = concat_arrays!(length_type = L as FIRST; [1], [2; 3]);
+        const B: [u8; L::SECOND] = concat_arrays!(length_type = L as SECOND; checked; [3, 5]);
+        const C: [u8; L::LEN] = concat_arrays!(length_type = L; A, B);
+        asserteq(C, [1, 2, 2, 2, 3, 5]);
+        assert_eq!(L::FIRST, 4);
+        assert_eq!(L::SECOND, 2);
+        assert_eq!(L::LEN, 6);
+    }
 }
 
-#[derive(Debug, PartialEq)]
-struct Str(&'static str);
+#[test]
+fn length_type_with_offsets() {
+    {
+        enum L {}
+        let _: [u8; 0] = concat_arrays!(length_type = L; with_offsets;);
+        assert_eq!(L::LEN, 0);
+        assert_eq!(L::OFFSETS, [0usize; 0]);
+    }
+    {
+        enum L {}
+        let _: [u8; 0] = concat_arrays!(length_type = L; with_offsets; #[cfg(any())] [0]);
+        assert_eq!(L::OFFSETS, [0usize; 0]);
+    }
+    {
+        const C: [u8; 5] = [5, 8, 13, 21, 34];
+        enum L {}
+        const A: [u8; L::LEN] = concat_arrays!(
+            length_type = L;
+            with_offsets;
+            [1], [2; 3], C, [0u8; 0], [3, 4] * 2,
+        );
+        assert_eq!(L::LEN, 13);
+        assert_eq!(L::OFFSETS, [0, 1, 4, 9, 9]);
 
-impl ::core_extensions::ConstDefault for Str {
-    const DEFAULT: Self = Self("");
+        const FIRST_C: u8 = A[L::OFFSETS[2]];
+        assert_eq!(FIRST_C, 5);
+
+        // every argument starts at its offset and ends at the next one
+        assert_eq!(A[L::OFFSETS[0]..L::OFFSETS[1]], [1]);
+        assert_eq!(A[L::OFFSETS[1]..L::OFFSETS[2]], [2, 2, 2]);
+        assert_eq!(A[L::OFFSETS[2]..L::OFFSETS[3]], C);
+        assert_eq!(A[L::OFFSETS[3]..L::OFFSETS[4]], [0u8; 0]);
+        assert_eq!(A[L::OFFSETS[4]..], [3, 4, 3, 4]);
+    }
+    {
+        enum L {}
+        const A: [u8; L::HEAD] = concat_arrays!(
+            length_type = L as HEAD;
+            with_offsets as HEAD_OFFSETS;
+            checked;
+            [1, 2], [3],
+        );
+        const B: [u8; L::TAIL] = concat_arrays!(
+            length_type = L as TAIL;
+            with_offsets as TAIL_OFFSETS;
+            [4], A, [5, 6],
+        );
+        asserteq(B, [4, 1, 2, 3, 5, 6]);
+        assert_eq!(L::HEAD_OFFSETS, [0, 2]);
+        assert_eq!(L::TAIL_OFFSETS, [0, 1, 4]);
+        assert_eq!(L::TAIL, 6);
+    }
+    {
+        enum L {}
+        let runtime = [3u8, 5];
+        let arr = concat_arrays!(
+            length_type = L;
+            with_offsets;
+            #[cfg(any())]
+            [0],
+            runtime: [_; 2], [8],
+            #[cfg(any())]
+            [13],
+        );
+        asserteq(arr, [3, 5, 8]);
+        assert_eq!(L::OFFSETS, [0, 2]);
+    }
 }
 
-impl Drop for Str {
-    fn drop(&mut self) {}
+#[test]
+fn flat_array_length() {
+    use crate::__::{ArrayLength, FlatArrayLength};
+
+    assert_eq!(<[u8; 0] as ArrayLength>::LENGTH, 0);
+    assert_eq!(<[u8; 3] as ArrayLength>::LENGTH, 3);
+
+    assert_eq!(<[[u8; 0]; 3] as FlatArrayLength>::FLAT_LENGTH, 0);
+    assert_eq!(<[[u8; 3]; 0] as FlatArrayLength>::FLAT_LENGTH, 0);
+    assert_eq!(<[[u8; 1]; 1] as FlatArrayLength>::FLAT_LENGTH, 1);
+    assert_eq!(<[[u8; 3]; 2] as FlatArrayLength>::FLAT_LENGTH, 6);
+    assert_eq!(<[[Str; 4]; 5] as FlatArrayLength>::FLAT_LENGTH, 20);
+
+    // flattens only one level of nesting
+    assert_eq!(<[[[u8; 2]; 3]; 4] as FlatArrayLength>::FLAT_LENGTH, 12);
+
+    const LEN: usize = <[[u16; 2]; 7] as FlatArrayLength>::FLAT_LENGTH;
+    let _: [u16; LEN] = [0; 14];
 }
 
-trait ConstVal<T> {
-    const C: T;
+#[test]
+fn concat_struct_test() {
+    use crate::concat_struct;
+    use core::mem::size_of_val;
+
+    {
+        let s = concat_struct!();
+        assert_eq!(size_of_val(&s), 0);
+    }
+    {
+        let s = concat_struct!([1u8, 2], [3u16]);
+        assert_eq!({ s.0 }, [1, 2]);
+        assert_eq!({ s.1 }, [3]);
+        assert_eq!(size_of_val(&s), 4);
+    }
+    {
+        const S: [u32; 2] = [8, 13];
+        let var = [(); 3];
+        let s = concat_struct!([3u16; 3], S, var, [1u8], [Str("foo")],);
+        assert_eq!({ s.0 }, [3, 3, 3]);
+        assert_eq!({ s.1 }, [8, 13]);
+        assert_eq!({ s.2 }, [(); 3]);
+        assert_eq!({ s.3 }, [1]);
+        assert_eq!(size_of_val(&s), 6 + 8 + 1 + core::mem::size_of::<Str>());
+    }
+    {
+        const BYTES: [u8; 7] = {
+            let s = concat_struct!([1u8], [0x0302u16], [0x0706_0504u32]);
+            unsafe { core::mem::transmute(s) }
+        };
+        asserteq(
+            BYTES,
+            concat_arrays!(
+                [1u8],
+                (0x0302u16.to_ne_bytes()),
+                (0x0706_0504u32.to_ne_bytes()),
+            ),
+        );
+    }
 }
 
-struct FooConst;
+#[test]
+fn array_index_of_extremum_test() {
+    use crate::{
+        array_index_of_max, array_index_of_max_by, array_index_of_min, array_index_of_min_by,
+    };
+    use core::cmp::Ordering;
 
-impl ConstVal<u8> for FooConst {
-    const C: u8 = 3;
+    const fn cmp_rev(l: &u8, r: &u8) -> Ordering {
+        if *l > *r {
+            Ordering::Less
+        } else if *l == *r {
+            Ordering::Equal
+        } else {
+            Ordering::Greater
+        }
+    }
+
+    const fn cmp_str(l: &Str, r: &Str) -> Ordering {
+        let (l, r) = (l.0.as_bytes(), r.0.as_bytes());
+        if l.len() < r.len() {
+            Ordering::Less
+        } else if l.len() == r.len() {
+            Ordering::Equal
+        } else {
+            Ordering::Greater
+        }
+    }
+
+    // single element
+    assert_eq!(array_index_of_max!([3u8]), 0);
+    assert_eq!(array_index_of_min!([3u8]), 0);
+    assert_eq!(array_index_of_max_by!([3u8], cmp_rev), 0);
+    assert_eq!(array_index_of_min_by!([3u8], cmp_rev), 0);
+
+    // distinct elements
+    {
+        const ARR: [u8; 5] = [3, 9, 1, 4, 0];
+        const MAX: usize = array_index_of_max!(ARR);
+        const MIN: usize = array_index_of_min!(ARR);
+        const MAX_BY: usize = array_index_of_max_by!(ARR, cmp_rev);
+        const MIN_BY: usize = array_index_of_min_by!(ARR, cmp_rev);
+        assert_eq!(MAX, 1);
+        assert_eq!(MIN, 4);
+        assert_eq!(MAX_BY, 4);
+        assert_eq!(MIN_BY, 1);
+    }
+
+    // ties, the first occurrence wins
+    {
+        let arr = [2u8, 7, 2, 7, 5];
+        assert_eq!(array_index_of_max!(arr), 1);
+        assert_eq!(array_index_of_min!(arr), 0);
+        assert_eq!(array_index_of_max_by!(arr, cmp_rev), 0);
+        assert_eq!(array_index_of_min_by!(arr, cmp_rev), 1);
+        assert_eq!(array_index_of_max!([4u8; 4]), 0);
+        assert_eq!(array_index_of_min!([4u8; 4]), 0);
+    }
+
+    // non-Copy elements aren't moved
+    {
+        let arr = [Str("ab"), Str("abc"), Str("a"), Str("abc"), Str("a")];
+        assert_eq!(array_index_of_max_by!(arr, cmp_str), 1);
+        assert_eq!(array_index_of_min_by!(arr, cmp_str), 2);
+        assert_eq!(arr[0], Str("ab"));
+    }
 }
 
-impl ConstVal<u16> for FooConst {
-    const C: u16 = 4;
+#[test]
+#[should_panic]
+fn array_index_of_max_empty() {
+    let _ = crate::array_index_of_max!([0u8; 0]);
 }
 
-impl ConstVal<u32> for FooConst {
-    const C: u32 = 5;
+#[test]
+#[should_panic]
+fn array_index_of_min_empty() {
+    let _ = crate::array_index_of_min!([0u8; 0]);
 }
 
-impl<T, const L: usize> ConstVal<[T; L]> for FooConst
-where
-    Self: ConstVal<T>,
-{
-    const C: [T; L] = [<FooConst as ConstVal<T>>::C; L];
+#[test]
+fn length_via_arg() {
+    {
+        trait L {
+            const LEN: usize;
+        }
+        let _: &[u8] = &concat_arrays!(length_via = L;);
+        assert_eq!(<() as L>::LEN, 0);
+    }
+    {
+        trait L {
+            const LEN: usize;
+        }
+        let _: &[u8] = &concat_arrays!(length_via = L; [1]);
+        assert_eq!(<() as L>::LEN, 1);
+    }
+    {
+        trait L {
+            const LEN: usize;
+        }
+        const C: [u8; 5] = [5, 8, 13, 21, 34];
+        const A: [u8; <u8 as L>::LEN] = concat_arrays!(length_via = <u8 as L>; [1], [2; 3], C);
+        asserteq(A, [1, 2, 2, 2, 5, 8, 13, 21, 34]);
+        assert_eq!(<u8 as L>::LEN, 9);
+    }
+    {
+        trait L {
+            const LEN: usize;
+        }
+        let arr = concat_arrays!(length_via = <Str as L>; [Str("foo")], [Str("bar")]);
+        let _: [Str; <Str as L>::LEN] = arr;
+        assert_eq!(<Str as L>::LEN, 2);
+    }
+    {
+        // the same trait can be implemented for multiple types
+        trait L {
+            const LEN: usize;
+        }
+        let _: [u8; 3] = concat_arrays!(length_via = <u8 as L>; [1, 2, 3]);
+        let _: [u8; 5] = concat_arrays!(length_via = <u16 as L>; [1, 2, 3], [4, 5]);
+        assert_eq!(<u8 as L>::LEN, 3);
+        assert_eq!(<u16 as L>::LEN, 5);
+    }
 }
 
-struct FixedLengthArray;
+#[test]
+fn checked_arg() {
+    asserteq(concat_arrays!(checked;), [0u8; 0]);
+    asserteq(concat_arrays!(checked; [3u8, 5]), [3, 5]);
+    asserteq(concat_arrays!(checked; [3u8, 5], [8; 2],), [3, 5, 8, 8]);
+    {
+        const C: [u8; 5] = [5, 8, 13, 21, 34];
+        const A: [u8; 9] = concat_arrays!(checked; [1], [2; 3], C);
+        asserteq(A, [1, 2, 2, 2, 5, 8, 13, 21, 34]);
+    }
+    {
+        let arr = concat_arrays!(checked; [(); usize::MAX - 3], [(); 3]);
+        assert_eq!(arr.len(), usize::MAX);
+    }
 
-impl<T> ConstVal<[T; 3]> for FixedLengthArray
-where
-    FooConst: ConstVal<T>,
-{
-    const C: [T; 3] = [<FooConst as ConstVal<T>>::C; 3];
+    // combined with the length headers
+    {
+        enum L {}
+        let arr = concat_arrays!(length_type = L; checked; [1u8], [2, 3]);
+        asserteq(arr, [1, 2, 3]);
+        assert_eq!(L::LEN, 3);
+    }
+    {
+        trait L {
+            const LEN: usize;
+        }
+        let arr = concat_arrays!(checked; length_via = L; [1u8], [2, 3]);
+        asserteq(arr, [1, 2, 3]);
+        assert_eq!(<() as L>::LEN, 3);
+    }
 }
 
-#[derive(Debug, PartialEq)]
-struct D(u32);
+#[test]
+fn nested_concat_arrays() {
+    // one level of nesting
+    asserteq(concat_arrays!(concat_arrays!([1], [2]), [3]), [1, 2, 3]);
+    asserteq(concat_arrays!([1], concat_arrays![[2], [3]]), [1, 2, 3]);
+    asserteq(concat_arrays!([1], concat_arrays! {[2], [3]},), [1, 2, 3]);
+    asserteq(concat_arrays!([1u8], concat_arrays!()), [1]);
 
-impl Drop for D {
-    fn drop(&mut self) {}
+    // `$crate::concat_arrays` invocations, from macros of this crate
+    {
+        macro_rules! prepend_one {
+            ($($args:tt)*) => {
+                $crate::concat_arrays!([1u8], $crate::concat_arrays!($($args)*))
+            };
+        }
+        let var = [2u8, 3];
+        asserteq(prepend_one!(var: [_; 2], [5]), [1, 2, 3, 5]);
+    }
+
+    // other paths are treated like any other macro invocation
+    {
+        let var = [2u8, 3];
+        asserteq(
+            concat_arrays!([1u8], crate::concat_arrays!(var: [_; 2]): [_; 2]),
+            [1, 2, 3],
+        );
+        asserteq(concat_arrays!([1u8], crate::concat_arrays!([2])), [1, 2]);
+        asserteq(concat_arrays!(self::concat_arrays!([1u8]), [2]), [1, 2]);
+    }
+
+    // nested calls with runtime values
+    {
+        let var = [Str("foo"), Str("bar")];
+        let arr = concat_arrays!(concat_arrays!(var: [_; 2], [Str("baz")]), [Str("qux")]);
+        asserteq(arr, [Str("foo"), Str("bar"), Str("baz"), Str("qux")]);
+    }
+
+    // nested calls with headers
+    {
+        enum L {}
+        const C: [u8; 2] = [5, 8];
+        let arr = concat_arrays!(
+            [1],
+            concat_arrays!(length_type = L; checked; [2, 3], C),
+            concat_arrays!(; [13]),
+        );
+        asserteq(arr, [1, 2, 3, 5, 8, 13]);
+        assert_eq!(L::LEN, 4);
+    }
+
+    // two levels of nesting
+    {
+        let var = [3u16, 5];
+        const ARR: [u16; 7] = concat_arrays!(
+            [1],
+            concat_arrays!(concat_arrays!([2], [3, 4]), [5]),
+            concat_arrays!([6], concat_arrays!([7])),
+        );
+        asserteq(ARR, [1, 2, 3, 4, 5, 6, 7]);
+
+        let arr = concat_arrays!(
+            concat_arrays!(concat_arrays!(var: [_; 2], [8]), var: [_; 2]),
+            [13],
+        );
+        asserteq(arr, [3, 5, 8, 3, 5, 13]);
+    }
+
+    // nested call with type annotation
+    {
+        let arr = concat_arrays!(concat_arrays!([1], [2]): [u8; 2], [3]);
+        asserteq(arr, [1, 2, 3]);
+        let arr = concat_arrays!(concat_arrays!([1], [2]): [u8; _], [3]);
+        asserteq(arr, [1, 2, 3]);
+    }
+}
+
+#[test]
+fn identity_perm_test() {
+    use crate::identity_perm;
+
+    {
+        const PERM: [usize; 0] = identity_perm!(0);
+        asserteq(PERM, [0usize; 0]);
+    }
+    asserteq(identity_perm!(1), [0usize]);
+    asserteq(identity_perm!(5), [0usize, 1, 2, 3, 4]);
+    asserteq(identity_perm!(3, u8), [0u8, 1, 2]);
+    asserteq(identity_perm!(4, i8,), [0i8, 1, 2, 3]);
+    asserteq(identity_perm!(2 + 2, u64), [0u64, 1, 2, 3]);
+
+    // the largest lengths that fit in the element type
+    {
+        let perm = identity_perm!(256, u8);
+        assert_eq!(perm.len(), 256);
+        assert_eq!(perm[255], 255);
+
+        let perm = identity_perm!(128, i8);
+        assert_eq!(perm.len(), 128);
+        assert_eq!(perm[127], 127);
+    }
+
+    // usable as the source of permutations in concatenations
+    asserteq(concat_arrays!((identity_perm!(3, u8)), [9]), [0, 1, 2, 9]);
+}
+
+#[test]
+fn apply_permutation_test() {
+    use crate::{apply_permutation, identity_perm};
+
+    {
+        const ARR: [u8; 0] = apply_permutation!([0u8; 0], []);
+        asserteq(ARR, [0u8; 0]);
+    }
+    {
+        const ARR: [u8; 5] = [3, 5, 8, 13, 21];
+        const REV: [u8; 5] = apply_permutation!(ARR, [4, 3, 2, 1, 0]);
+        const SAME: [u8; 5] = apply_permutation!(ARR, identity_perm!(5));
+        asserteq(REV, [21, 13, 8, 5, 3]);
+        asserteq(SAME, ARR);
+    }
+    {
+        let arr = ["foo", "bar", "baz"];
+        let perm = [1, 2, 0];
+        asserteq(apply_permutation!(arr, perm), ["bar", "baz", "foo"]);
+
+        // applying the reverse permutation twice is a no-op
+        let rev = [2, 1, 0];
+        asserteq(apply_permutation!(apply_permutation!(arr, rev), rev), arr);
+    }
+    {
+        // repeated indices are allowed
+        asserteq(apply_permutation!([3, 5, 8], [0, 0, 2]), [3, 3, 8]);
+    }
+}
+
+#[test]
+#[should_panic]
+fn apply_permutation_out_of_bounds() {
+    let perm = [0, 3, 1];
+    let _ = crate::apply_permutation!([3, 5, 8], perm);
+}
+
+#[test]
+fn gather_test() {
+    use crate::{gather, identity_perm};
+
+    const ARR: [u8; 5] = [3, 5, 8, 13, 21];
+
+    {
+        const EMPTY: [u8; 0] = gather!(ARR, []);
+        asserteq(EMPTY, [0u8; 0]);
+    }
+    {
+        const REORDERED: [u8; 3] = gather!(ARR, [4, 0, 2]);
+        const REPEATED: [u8; 7] = gather!(ARR, [1, 1, 4, 1, 0, 0, 1]);
+        const SAME: [u8; 5] = gather!(ARR, identity_perm!(5));
+        asserteq(REORDERED, [21, 3, 8]);
+        asserteq(REPEATED, [5, 5, 21, 5, 3, 3, 5]);
+        asserteq(SAME, ARR);
+    }
+
+    let arr = ["foo", "bar"];
+    let indices = [1, 0, 1];
+    asserteq(gather!(arr, indices), ["bar", "foo", "bar"]);
+    asserteq(gather!(concat_arrays!(arr: [_; 2], ["baz"]), [2]), ["baz"]);
+}
+
+#[test]
+#[should_panic]
+fn gather_out_of_bounds() {
+    let indices = [0, 3];
+    let _ = crate::gather!([3, 5, 8], indices);
+}
+
+#[test]
+fn scatter_test() {
+    use crate::{gather, scatter};
+
+    const ARR: [u8; 3] = [3, 5, 8];
+
+    {
+        const EMPTY: [u8; 0] = scatter!(0, [0u8; 0], []);
+        const DEFAULTS: [u8; 2] = scatter!(9, [0u8; 0], []);
+        asserteq(EMPTY, [0u8; 0]);
+        asserteq(DEFAULTS, [9, 9]);
+    }
+    {
+        // every position is written to
+        const FULL: [u8; 3] = scatter!(0, ARR, [2, 0, 1]);
+        asserteq(FULL, [5, 8, 3]);
+
+        // scattering is the inverse of gathering with the same indices
+        asserteq(gather!(FULL, [2, 0, 1]), ARR);
+    }
+    {
+        // positions that aren't written to keep the default
+        const PARTIAL: [u8; 6] = scatter!(0, ARR, [5, 1, 3], 6);
+        asserteq(PARTIAL, [0, 5, 0, 8, 0, 3]);
+    }
+    {
+        // the last write to a repeated index wins
+        const REPEATED: [u8; 2] = scatter!(0, ARR, [1, 0, 1]);
+        asserteq(REPEATED, [5, 8]);
+    }
+
+    let arr = ["foo", "bar"];
+    let indices = [3, 1];
+    asserteq(scatter!("", arr, indices, 2 * 2), ["", "bar", "", "foo"]);
+}
+
+#[test]
+#[should_panic]
+fn scatter_out_of_bounds() {
+    let indices = [0, 3];
+    let _: [u8; 3] = crate::scatter!(0, [3, 5], indices);
+}
+
+#[test]
+fn maybe_uninit_args() {
+    use core::mem::MaybeUninit;
+
+    type MU = MaybeUninit<u8>;
+
+    fn assume_init<const N: usize>(arr: [MU; N]) -> [u8; N] {
+        unsafe { core::mem::transmute_copy(&arr) }
+    }
+
+    {
+        const A: [MU; 2] = [MU::new(3), MU::new(5)];
+        const B: [MU; 3] = [MU::uninit(); 3];
+        let var = [MU::new(13), MU::uninit()];
+
+        let mut arr = concat_arrays!(A, B, [MU::new(8)], var: [_; 2], [MU::uninit(); 2]);
+        let _: &[MU; 10] = &arr;
+        arr[2] = MU::new(4);
+        arr[3] = MU::new(4);
+        arr[4] = MU::new(4);
+        arr[7] = MU::new(21);
+        arr[8] = MU::new(34);
+        arr[9] = MU::new(55);
+        assert_eq!(assume_init(arr), [3, 5, 4, 4, 4, 8, 13, 21, 34, 55]);
+    }
+    {
+        // partially initialized buffers in const
+        const ARR: [MU; 4] = concat_arrays!([MU::new(3)], [MU::uninit(); 2], [MU::new(8)]);
+        let mut arr = ARR;
+        arr[1] = MU::new(5);
+        arr[2] = MU::new(5);
+        assert_eq!(assume_init(arr), [3, 5, 5, 8]);
+    }
+    {
+        // with inferred lengths
+        const A: [MaybeUninit<u16>; 2] = [MaybeUninit::new(3), MaybeUninit::new(5)];
+        let arr = concat_arrays!(A: [MaybeUninit<u16>; _], [MaybeUninit::new(8)]: [_; 1]);
+        let arr: [u16; 3] = unsafe { core::mem::transmute(arr) };
+        assert_eq!(arr, [3, 5, 8]);
+    }
+}
+
+#[test]
+fn concat_ref_test() {
+    use crate::concat_ref;
+
+    {
+        const ARR: [&str; 0] = concat_ref([], []);
+        asserteq(ARR, [""; 0]);
+    }
+    {
+        const A: [&str; 2] = ["foo", "bar"];
+        const B: [&str; 3] = ["baz", "qux", "hello"];
+        const C: [&str; 5] = concat_ref(A, B);
+        const D: [&str; 5] = concat_ref(B, A);
+        asserteq(C, ["foo", "bar", "baz", "qux", "hello"]);
+        asserteq(D, ["baz", "qux", "hello", "foo", "bar"]);
+        asserteq(concat_ref::<_, 2, 0, 2>(A, []), A);
+        asserteq(concat_ref::<_, 0, 2, 2>([], A), A);
+    }
+    {
+        let strs = [Str("foo"), Str("bar"), Str("baz")];
+        let arr: [&Str; 4] = concat_ref([&strs[2], &strs[0]], [&strs[1], &strs[2]]);
+        asserteq(arr, [&strs[2], &strs[0], &strs[1], &strs[2]]);
+        assert!(core::ptr::eq(arr[0], &strs[2]));
+        assert!(core::ptr::eq(arr[3], &strs[2]));
+    }
+    {
+        let slices: [&[u8]; 3] = concat_ref([&[3, 5][..]], [&[8][..], &[]]);
+        asserteq(slices, [&[3, 5][..], &[8][..], &[][..]]);
+    }
+}
+
+#[test]
+#[should_panic]
+fn concat_ref_wrong_length() {
+    let _: [&u8; 4] = crate::concat_ref([&3], [&5, &8]);
+}
+
+#[test]
+fn concat_pair_test() {
+    use crate::concat_pair;
+
+    const fn push3<const N: usize, const M: usize>(array: [u8; N]) -> [u8; M] {
+        concat_pair(array, [3, 5, 8])
+    }
+
+    // `M` must be `N + 1`, and `K` must be `N + 2`
+    fn surround<T, const N: usize, const M: usize, const K: usize>(
+        x: T,
+        array: [T; N],
+        y: T,
+    ) -> [T; K] {
+        concat_pair::<_, 1, M, K>([x], concat_pair::<_, N, 1, M>(array, [y]))
+    }
+
+    {
+        const ARR: [u8; 0] = concat_pair([], []);
+        asserteq(ARR, [0u8; 0]);
+    }
+    {
+        const ARR: [u8; 5] = push3([1, 2]);
+        asserteq(ARR, [1, 2, 3, 5, 8]);
+        asserteq(push3::<0, 3>([]), [3, 5, 8]);
+    }
+
+    let arr: [Str; 4] = surround::<_, 2, 3, 4>(Str("a"), [Str("b"), Str("c")], Str("d"));
+    asserteq(arr, [Str("a"), Str("b"), Str("c"), Str("d")]);
+}
+
+#[test]
+#[should_panic]
+fn concat_pair_wrong_length() {
+    let _: [u8; 4] = crate::concat_pair([3], [5, 8]);
+}
+
+#[cfg(feature = "rust_1_83")]
+#[test]
+fn select_test() {
+    use crate::select;
+
+    const A: [u8; 4] = [3, 5, 8, 13];
+    const B: [u8; 4] = [0, 1, 2, 3];
+
+    {
+        const ARR: [u8; 4] = select(A, B, [true; 4]);
+        assert_eq!(ARR, A);
+    }
+    {
+        const ARR: [u8; 4] = select(A, B, [false; 4]);
+        assert_eq!(ARR, B);
+    }
+    {
+        const ARR: [u8; 4] = select(A, B, [true, false, true, false]);
+        assert_eq!(ARR, [3, 1, 8, 3]);
+    }
+    assert_eq!(select(A, B, [false, true, false, true]), [0, 5, 2, 13]);
+
+    assert_eq!(select::<u8, 0>([], [], []), []);
+    assert_eq!(select(["foo"], ["bar"], [false]), ["bar"]);
+}
+
+#[test]
+fn concat_str_bytes_test() {
+    use crate::concat_str_bytes;
+
+    asserteq(concat_str_bytes!(), [0u8; 0]);
+    asserteq(concat_str_bytes!(""), [0u8; 0]);
+    asserteq(concat_str_bytes!("", []), [0u8; 0]);
+
+    {
+        const BYTES: [u8; 3] = concat_str_bytes!("ab", "c");
+        asserteq(BYTES, [b'a', b'b', b'c']);
+    }
+
+    // mixed string literals and byte arrays
+    {
+        const ARR: [u8; 2] = *b"AB";
+        let var = [b'x', b'y'];
+
+        const BYTES: [u8; 10] = concat_str_bytes!(ARR, "foo", [b'-'; 2], "", "ba", [b'r'],);
+        asserteq(BYTES, *b"ABfoo--bar");
+
+        let bytes = concat_str_bytes!("(", var: [_; 2], ")", (*b"[]"));
+        asserteq(bytes, *b"(xy)[]");
+    }
+
+    // multibyte UTF-8
+    {
+        const BYTES: [u8; 11] = concat_str_bytes!("ñ", [b'_'], "字", "🦀", "!");
+        assert_eq!(&BYTES, "ñ_字🦀!".as_bytes());
+        assert_eq!(core::str::from_utf8(&BYTES), Ok("ñ_字🦀!"));
+    }
+}
+
+#[test]
+fn bytes_of_test() {
+    use crate::bytes_of;
+
+    const WORD: u32 = 0x0102_0304;
+
+    // little-endian
+    {
+        const BYTES: [u8; 4] = bytes_of!(le; 0x1234u16, 0x5678u16);
+        asserteq(BYTES, [0x34, 0x12, 0x78, 0x56]);
+    }
+    {
+        const BYTES: [u8; 7] = bytes_of!(le; 0xABu8, WORD, 0xCDEFu16);
+        asserteq(BYTES, [0xAB, 4, 3, 2, 1, 0xEF, 0xCD]);
+    }
+    // big-endian
+    {
+        const BYTES: [u8; 7] = bytes_of!(be; 0xABu8, WORD, 0xCDEFu16,);
+        asserteq(BYTES, [0xAB, 1, 2, 3, 4, 0xCD, 0xEF]);
+    }
+    {
+        const BYTES: [u8; 9] = bytes_of!(be; (-2i64), (u8::MAX));
+        asserteq(
+            BYTES,
+            [0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFE, 0xFF],
+        );
+    }
+    // native-endian
+    {
+        const BYTES: [u8; 6] = bytes_of!(ne; WORD, 0x1234u16);
+        asserteq(
+            BYTES,
+            concat_arrays!((WORD.to_ne_bytes()), (0x1234u16.to_ne_bytes())),
+        );
+    }
+    {
+        const EMPTY: [u8; 0] = bytes_of!(le;);
+        asserteq(EMPTY, []);
+    }
+
+    // non-constant arguments
+    let (byte, word) = (0x7Fu8, 0xAABB_CCDDu32);
+    asserteq(
+        bytes_of!(le; byte: u8, word: u32, (word as u16): u16, 5u16),
+        [0x7F, 0xDD, 0xCC, 0xBB, 0xAA, 0xDD, 0xCC, 5, 0],
+    );
+    asserteq(
+        bytes_of!(be; word: u32, (-1i128)),
+        concat_arrays!([0xAA, 0xBB, 0xCC, 0xDD], [0xFF; 16]),
+    );
+}
+
+#[test]
+fn with_len_header_test() {
+    use crate::with_len_header;
+
+    const DATA: [u8; 3] = [7, 8, 9];
+
+    {
+        const FRAME: [u8; 7] = with_len_header!(u16; [0x01u8, 0x02], DATA);
+        asserteq(FRAME, [5, 0, 0x01, 0x02, 7, 8, 9]);
+    }
+    {
+        const FRAME: [u8; 7] = with_len_header!(u32; DATA);
+        asserteq(FRAME, [3, 0, 0, 0, 7, 8, 9]);
+    }
+    {
+        const FRAME: [u8; 256] = with_len_header!(u8; [0xAAu8; 255]);
+        asserteq(FRAME, concat_arrays!([255], [0xAA; 255]));
+    }
+    {
+        const FRAME: [u8; 302] = with_len_header!(u16; [1u8; 100], [2u8; 200]);
+        asserteq(FRAME, concat_arrays!([44, 1], [1; 100], [2; 200]));
+    }
+    {
+        const EMPTY: [u8; 2] = with_len_header!(u16;);
+        asserteq(EMPTY, [0, 0]);
+    }
+    {
+        const EMPTY: [u8; 8] = with_len_header!(u64; [0u8; 0]);
+        asserteq(EMPTY, [0; 8]);
+    }
+
+    let data = [13u8, 21];
+    asserteq(
+        with_len_header!(u16; data: [_; 2], DATA),
+        [5, 0, 13, 21, 7, 8, 9],
+    );
+}
+
+#[test]
+fn aligned_concat_test() {
+    use crate::{aligned_concat, Aligned16, Aligned4096, Aligned64, Aligned8};
+    use core::mem::align_of_val;
+
+    const A: [u8; 2] = [1, 2];
+
+    {
+        const ARR: Aligned64<[u8; 5]> = aligned_concat!(align = 64; A, [3, 5, 8]);
+        assert_eq!(align_of_val(&ARR), 64);
+        asserteq(ARR.0, [1, 2, 3, 5, 8]);
+    }
+    {
+        static ARR: Aligned4096<[u8; 3]> = aligned_concat!(align = 4096; A, [3]);
+        assert_eq!(align_of_val(&ARR), 4096);
+        assert_eq!(ARR.as_ptr() as usize % 4096, 0);
+        asserteq(*ARR, [1, 2, 3]);
+    }
+    {
+        const ARR: Aligned8<[u8; 0]> = aligned_concat!(align = 8;);
+        assert_eq!(align_of_val(&ARR), 8);
+        asserteq(ARR.into_inner(), []);
+    }
+    {
+        // the alignment of the element type is used if it's larger
+        let arr = aligned_concat!(align = 2; [1u64], [2]);
+        assert_eq!(align_of_val(&arr), align_of_val(&0u64).max(2));
+        asserteq(arr.0, [1, 2]);
+    }
+
+    let runtime = [13u8, 21];
+    let mut arr: Aligned16<[u8; 4]> = aligned_concat!(align = 16; A, runtime: [_; 2]);
+    assert_eq!(align_of_val(&arr), 16);
+    arr[0] = 0;
+    asserteq(arr.into_inner(), [0, 2, 13, 21]);
+}
+
+#[test]
+fn include_bytes_args() {
+    const BYTES: [u8; 9] = concat_arrays!(
+        *include_bytes!("tests/fixtures/first.bin"),
+        *include_bytes!("tests/fixtures/second.bin"),
+    );
+    assert_eq!(&BYTES, b"arrcat\x00\xFF\n");
+
+    // macro invocations can be mixed with other arguments
+    let bytes = concat_arrays!([b'<'], *include_bytes!("tests/fixtures/second.bin"), [b'>']);
+    asserteq(bytes, *b"<\x00\xFF\n>");
+}
+
+#[test]
+fn newtype_elements() {
+    use core::num::{NonZeroU8, Wrapping};
+
+    const fn nz(n: u8) -> NonZeroU8 {
+        match NonZeroU8::new(n) {
+            Some(x) => x,
+            None => panic!("zero"),
+        }
+    }
+
+    {
+        const A: [Wrapping<u8>; 2] = [Wrapping(3), Wrapping(5)];
+        const ARR: [Wrapping<u8>; 5] = {
+            let b = [Wrapping(8), Wrapping(13)];
+            concat_arrays!(A, b: [_; 2], [Wrapping(255)])
+        };
+        asserteq(
+            ARR,
+            [
+                Wrapping(3),
+                Wrapping(5),
+                Wrapping(8),
+                Wrapping(13),
+                Wrapping(255),
+            ],
+        );
+        assert_eq!(ARR[4] + Wrapping(1), Wrapping(0));
+    }
+    {
+        const A: [NonZeroU8; 2] = [nz(3), nz(5)];
+        const ARR: [NonZeroU8; 5] = {
+            let b = [nz(8), nz(13)];
+            concat_arrays!(A, b: [_; 2], [nz(21)]: [NonZeroU8; _])
+        };
+        asserteq(ARR, [nz(3), nz(5), nz(8), nz(13), nz(21)]);
+    }
+    {
+        let b = [Some(nz(8)), None];
+        let arr = concat_arrays!([Some(nz(3))], b: [_; 2]);
+        asserteq(arr, [Some(nz(3)), Some(nz(8)), None]);
+    }
+}
+
+#[test]
+fn transparent_newtype_elements() {
+    #[derive(Debug, PartialEq, Copy, Clone)]
+    #[repr(transparent)]
+    struct Id(u32);
+
+    const IDS: [Id; 2] = [Id(3), Id(5)];
+
+    {
+        const ARR: [Id; 5] = {
+            let runtime = [Id(8), Id(13)];
+            concat_arrays!(IDS, runtime: [Id; 2], [Id(21)])
+        };
+        asserteq(ARR, [Id(3), Id(5), Id(8), Id(13), Id(21)]);
+    }
+    {
+        let runtime = [Id(8)];
+        let arr = concat_arrays!(
+            [Id(1)]: [Id; _],
+            IDS: [_; 2],
+            runtime: [Id; 1],
+            (IDS): [Id; 2],
+        );
+        asserteq(arr, [Id(1), Id(3), Id(5), Id(8), Id(3), Id(5)]);
+    }
+}
+
+#[test]
+fn interior_mutable_elements() {
+    use core::cell::{Cell, UnsafeCell};
+
+    const fn pair(a: u8, b: u8) -> [Cell<u8>; 2] {
+        [Cell::new(a), Cell::new(b)]
+    }
+
+    const fn cells() -> [Cell<u8>; 7] {
+        let runtime = [Cell::new(8), Cell::new(13)];
+        concat_arrays!(
+            [Cell::new(3)]: [Cell<u8>; _],
+            [Cell::new(5)],
+            runtime: [_; 2],
+            [Cell::new(21)],
+            pair(34, 55),
+        )
+    }
+
+    {
+        let arr = cells();
+        arr[1].set(50);
+        arr[4].set(arr[4].get() + 1);
+        asserteq(arr.map(Cell::into_inner), [3, 50, 8, 13, 22, 34, 55]);
+    }
+    {
+        let a = [Cell::new(1u32), Cell::new(2)];
+        let arr = concat_arrays!((a.clone()): [Cell<u32>; 2], [Cell::new(3)], (a.clone()): [_; 2]);
+        arr[0].set(10);
+        arr[3].set(20);
+        asserteq(arr.map(Cell::into_inner), [10, 2, 3, 20, 2]);
+        // the returned array doesn't share its cells with the arguments
+        asserteq(a.map(Cell::into_inner), [1, 2]);
+    }
+    {
+        let arr = concat_arrays!(
+            [UnsafeCell::new("foo")]: [UnsafeCell<&str>; _],
+            [UnsafeCell::new("bar")]
+        );
+        // SAFETY: there are no other references to the contents of the cell
+        unsafe { *arr[1].get() = "baz" };
+        asserteq(arr.map(UnsafeCell::into_inner), ["foo", "baz"]);
+    }
+}
+
+#[test]
+fn repr_c_struct_elements() {
+    use crate::__::{size_of, ArrayLength, GetTypeParam};
+
+    #[derive(Debug, PartialEq, Copy, Clone)]
+    #[repr(C)]
+    struct Pixel {
+        r: u8,
+        g: u8,
+        b: u8,
+    }
+
+    #[derive(Debug, PartialEq, Copy, Clone)]
+    #[repr(C)]
+    struct Lanes<const N: usize> {
+        tag: u16,
+        lanes: [u8; N],
+    }
+
+    const fn px(r: u8, g: u8, b: u8) -> Pixel {
+        Pixel { r, g, b }
+    }
+
+    type Pair = [Pixel; 2];
+
+    assert_eq!(size_of::<Pixel>(), 3);
+    assert_eq!(size_of::<[Pixel; 5]>(), 15);
+    assert_eq!(<Pair as ArrayLength>::LENGTH, 2);
+    assert_eq!(size_of::<<Pair as GetTypeParam>::T>(), size_of::<Pixel>(),);
+    // the struct has a trailing padding byte
+    assert_eq!(size_of::<Lanes<3>>(), 6);
+
+    const PAIR: Pair = [px(1, 2, 3), px(4, 5, 6)];
+
+    {
+        const ARR: [Pixel; 5] = {
+            let runtime = [px(7, 8, 9), px(10, 11, 12)];
+            concat_arrays!(PAIR: [Pixel; _], runtime: [Pixel; 2], [px(13, 14, 15)])
+        };
+        asserteq(
+            ARR,
+            [
+                px(1, 2, 3),
+                px(4, 5, 6),
+                px(7, 8, 9),
+                px(10, 11, 12),
+                px(13, 14, 15),
+            ],
+        );
+    }
+    {
+        let runtime = [px(0, 0, 0)];
+        let arr = concat_arrays!(
+            [px(9, 9, 9)]: [Pixel; _],
+            runtime: [Pixel; 1],
+            (PAIR): len = Pair,
+            [],
+        );
+        asserteq(arr, [px(9, 9, 9), px(0, 0, 0), px(1, 2, 3), px(4, 5, 6)]);
+    }
+    {
+        const fn lanes(tag: u16) -> Lanes<3> {
+            Lanes {
+                tag,
+                lanes: [tag as u8, 0, !(tag as u8)],
+            }
+        }
+
+        const ARR: [Lanes<3>; 3] = {
+            let runtime = [lanes(5), lanes(8)];
+            concat_arrays!([lanes(3)]: [Lanes<3>; _], runtime: [_; 2])
+        };
+        asserteq(ARR, [lanes(3), lanes(5), lanes(8)]);
+    }
+}
+
+#[test]
+fn large_static() {
+    static BIG: [u8; 65536 + 3] = concat_arrays!([0xAB; 32768], [3, 5, 8], [0xCD; 32768]);
+
+    assert_eq!(BIG.len(), 65539);
+    assert!(BIG[..32768].iter().all(|&b| b == 0xAB));
+    assert_eq!(BIG[32768..32771], [3, 5, 8]);
+    assert!(BIG[32771..].iter().all(|&b| b == 0xCD));
+
+    static WORDS: [u32; 8192] = concat_arrays!([1u32; 4096], [2u32; 4096]);
+    assert!(WORDS[..4096].iter().all(|&w| w == 1));
+    assert!(WORDS[4096..].iter().all(|&w| w == 2));
+}
+
+#[test]
+fn concat_map_arrays_test() {
+    use crate::concat_map_arrays;
+
+    const fn inc(x: u8) -> u16 {
+        x as u16 + 1
+    }
+
+    {
+        const ARR: [u16; 0] = concat_map_arrays!(inc;);
+        asserteq(ARR, [0u16; 0]);
+    }
+    {
+        const C: [u8; 2] = [21, 34];
+        const ARR: [u16; 7] = {
+            let runtime = [8, 13];
+            concat_map_arrays!(inc; [3, 5], runtime: [_; 2], C, [255])
+        };
+        asserteq(ARR, [4, 6, 9, 14, 22, 35, 256]);
+    }
+
+    let runtime = [Some(3u8), None];
+    let arr = concat_map_arrays!(
+        (|x: Option<u8>| x.map_or(0, |x| x * 2));
+        runtime: [_; 2],
+        concat_arrays!([Some(5)], [None, Some(8)]),
+    );
+    asserteq(arr, [6, 0, 10, 0, 16]);
+
+    // mapping to a type with drop glue
+    let arr = concat_map_arrays!(Str; ["foo"], ["bar", "baz"]);
+    asserteq(arr, [Str("foo"), Str("bar"), Str("baz")]);
+}
+
+#[cfg(feature = "rust_1_83")]
+#[test]
+fn concat_map_non_copy_test() {
+    use crate::{concat_map_arrays, concat_map_index};
+
+    let dropped = Cell::new(0u64);
+    let new = |id: u32| Counted {
+        id,
+        dropped: &dropped,
+    };
+
+    {
+        let runtime = [new(2), new(3)];
+        let ids = concat_map_arrays!(
+            (|c: Counted<'_>| c.id * 10);
+            [new(0), new(1)],
+            runtime: [_; 2],
+            concat_arrays!([new(4)], [new(5)]),
+        );
+        asserteq(ids, [0, 10, 20, 30, 40, 50]);
+
+        // every element was dropped by the mapper, exactly once
+        assert_eq!(dropped.get(), 0b11_1111);
+    }
+
+    dropped.set(0);
+    {
+        let runtime = [new(1), new(2)];
+        let arr = concat_map_index!(
+            (|i: usize, c| (i, c));
+            [new(0)],
+            runtime: [_; 2],
+            [new(3)],
+        );
+
+        // nothing is dropped while moving the elements into the returned array
+        assert_eq!(dropped.get(), 0);
+
+        for (i, (index, elem)) in arr.iter().enumerate() {
+            assert_eq!(*index, i);
+            assert_eq!(elem.id, i as u32);
+        }
+    }
+    assert_eq!(dropped.get(), 0b1111);
+}
+
+#[test]
+fn concat_map_index_test() {
+    use crate::concat_map_index;
+
+    const fn add_index(i: usize, x: u32) -> u32 {
+        x + i as u32
+    }
+
+    {
+        const ARR: [u32; 0] = concat_map_index!(add_index;);
+        asserteq(ARR, [0u32; 0]);
+    }
+    {
+        const ARR: [u32; 3] = concat_map_index!(add_index; [10, 20], [30]);
+        asserteq(ARR, [10, 21, 32]);
+    }
+    {
+        const C: [u32; 2] = [0; 2];
+        const ARR: [u32; 7] = {
+            let runtime = [100, 100];
+            concat_map_index!(add_index; [0, 0], runtime: [_; 2], C * 1, [0])
+        };
+        asserteq(ARR, [0, 1, 102, 103, 4, 5, 6]);
+    }
+
+    let runtime = ['a', 'b'];
+    let arr = concat_map_index!(
+        (|i, c| (i, c));
+        runtime: [_; 2],
+        concat_arrays!(['c'], runtime: [_; 2]),
+    );
+    asserteq(arr, [(0, 'a'), (1, 'b'), (2, 'c'), (3, 'a'), (4, 'b')]);
+
+    // mapping to a type with drop glue
+    let arr = concat_map_index!((|i, _| D(i as u32)); ["foo"], ["bar", "baz"]);
+    asserteq(arr, [D(0), D(1), D(2)]);
+}
+
+#[test]
+fn for_each_concat_test() {
+    use crate::for_each_concat;
+
+    {
+        const SUM: u32 = {
+            const C: [u32; 2] = [21, 34];
+            let runtime = [8, 13];
+            let mut sum = 0;
+            for_each_concat!(|x| sum += x; [3, 5], runtime: [_; 2], C, concat_arrays!([55], [89]));
+            sum
+        };
+        assert_eq!(SUM, 228);
+    }
+    {
+        const SUM: u32 = {
+            let mut sum = 0;
+            for_each_concat!(|x: u32| sum += x; );
+            sum
+        };
+        assert_eq!(SUM, 0);
+    }
+
+    // patterns are allowed
+    let mut sum = 0;
+    for_each_concat!(|(a, b)| sum += a * b; [(1, 2), (3, 4)], [(5, 6)]);
+    assert_eq!(sum, 44);
+
+    // skipping elements with `continue`
+    let mut found = [""; 4];
+    let mut len = 0;
+    let runtime = ["bar", "", "baz"];
+    for_each_concat!(
+        |x| {
+            if x.is_empty() {
+                continue;
+            }
+            found[len] = x;
+            len += 1;
+        };
+        ["foo"],
+        runtime: [_; 3],
+    );
+    assert_eq!(found[..len], ["foo", "bar", "baz"]);
+
+    // passing a function
+    let mut found = [0u16; 4];
+    let mut len = 0;
+    let mut push = |x: u8| {
+        found[len] = x as u16 * 2;
+        len += 1;
+    };
+    for_each_concat!(push; [3, 5], [8, 13]);
+    asserteq(found, [6, 10, 16, 26]);
+}
+
+#[test]
+fn length_assertions() {
+    use crate::{assert_array_len, assert_same_len};
+
+    const A: [u8; 3] = [3, 5, 8];
+    const B: [&str; 3] = ["foo", "bar", "baz"];
+
+    const _: () = assert_array_len!(A, 3);
+    const _: () = assert_array_len!([0u8; 0], 0);
+    const _: () = assert_array_len!(concat_arrays!(A, [13]), 4);
+    const _: () = assert_same_len!(A, B);
+    const _: () = assert_same_len!(concat_arrays!(A, A), [(); 6]);
+
+    // the arguments aren't evaluated
+    let mut evaluated = false;
+    assert_array_len!(
+        {
+            evaluated = true;
+            [Str("foo")]
+        },
+        1
+    );
+    assert_same_len!(
+        {
+            evaluated = true;
+            A
+        },
+        [Str("foo"), Str("bar"), Str("baz")],
+    );
+    assert!(!evaluated);
+
+    let runtime = [Str("bar"), Str("baz")];
+    assert_array_len!(runtime, 2);
+    assert_same_len!(runtime, [0u64; 2]);
+    asserteq(runtime, [Str("bar"), Str("baz")]);
+}
+
+#[test]
+fn generic_element_type() {
+    fn copy_bounded<T: Copy>(a: [T; 2], b: [T; 1]) -> [T; 3] {
+        concat_arrays!(a: [T; 2], b: [T; 1])
+    }
+
+    fn inferred_elem<T: Copy>(a: [T; 2], b: [T; 1]) -> [T; 4] {
+        concat_arrays!(a: [_; 2], b: [_; 1], [b[0]])
+    }
+
+    fn where_bounded<T>(a: [T; 2], b: [T; 1]) -> [T; 3]
+    where
+        T: Copy + PartialEq,
+    {
+        let arr = concat_arrays!(a: [T; 2], b: [T; 1]);
+        assert!(arr[2] == b[0]);
+        arr
+    }
+
+    // trait bounds in const fns require Rust 1.61.0
+    const fn const_generic<T>(a: [T; 2], b: [T; 1]) -> [T; 3] {
+        concat_arrays!(a: [T; 2], b: [T; 1])
+    }
+
+    const fn const_generic_inferred<T>(a: [T; 2], b: [T; 1]) -> [T; 3] {
+        concat_arrays!(a: [_; 2], b: [_; 1])
+    }
+
+    // no bounds at all
+    fn unbounded<T>(a: [T; 2], b: [T; 1]) -> [T; 3] {
+        concat_arrays!(a: [T; 2], b: [_; 1])
+    }
+
+    asserteq(copy_bounded([3u8, 5], [8]), [3, 5, 8]);
+    asserteq(copy_bounded(["foo", "bar"], ["baz"]), ["foo", "bar", "baz"]);
+    asserteq(inferred_elem([3u8, 5], [8]), [3, 5, 8, 8]);
+    asserteq(where_bounded([3.0f32, 5.0], [8.0]), [3.0, 5.0, 8.0]);
+    asserteq(
+        unbounded([Str("a"), Str("b")], [Str("c")]),
+        [Str("a"), Str("b"), Str("c")],
+    );
+
+    const ARR: [(u8, char); 3] = const_generic([(3, 'a'), (5, 'b')], [(8, 'c')]);
+    asserteq(ARR, [(3, 'a'), (5, 'b'), (8, 'c')]);
+
+    asserteq(
+        const_generic_inferred([Str("a"), Str("b")], [Str("c")]),
+        [Str("a"), Str("b"), Str("c")],
+    );
+}
+
+#[test]
+fn repeated_args() {
+    {
+        const C: [u8; 2] = [21, 34];
+        const ARR: [u8; 14] = {
+            let var = [3, 5];
+            concat_arrays!(var * 3: [_; 2], [9], C * 2, [1, 2] * 1, [7] * 0, [55])
+        };
+        asserteq(ARR, [3, 5, 3, 5, 3, 5, 9, 21, 34, 21, 34, 1, 2, 55]);
+    }
+    {
+        const N: usize = 2;
+        let var = [Some(3u16)];
+        let arr = concat_arrays!(
+            var * (N + 1): [Option<u16>; 1],
+            [None] * N: [Option<u16>; _],
+        );
+        asserteq(arr, [Some(3), Some(3), Some(3), None, None]);
+    }
+    {
+        let var = ['a', 'b'];
+        let arr = concat_arrays!(length_type = L; checked; var * 2: [char; 2], ['c'] * 3);
+        asserteq(arr, ['a', 'b', 'a', 'b', 'c', 'c', 'c']);
+        assert_eq!(L::LEN, 7);
+        enum L {}
+    }
+}
+
+#[test]
+fn arr_concat_test() {
+    use crate::arr_concat;
+
+    {
+        const ARR: [u8; 0] = arr_concat!();
+        asserteq(ARR, [0u8; 0]);
+    }
+    {
+        const FOO: [u8; 2] = [8, 13];
+        const ARR: [u8; 9] = {
+            let var = [21, 34];
+            arr_concat!([3, 5] ++ FOO ++ var: [_; 2] ++ [55] ++ (FOO): [u8; _] ++ [])
+        };
+        asserteq(ARR, concat_arrays!([3, 5], FOO, [21, 34], [55], FOO));
+    }
+    {
+        let var = [Str("b")];
+        let arr = arr_concat!(
+            length_type = L;
+            [Str("a")] ++ var: [_; 1] ++ concat_arrays!([Str("c")], [Str("d")])
+        );
+        asserteq(arr, [Str("a"), Str("b"), Str("c"), Str("d")]);
+        assert_eq!(L::LEN, 4);
+        enum L {}
+    }
+    {
+        let var = [3u8];
+        let arr = arr_concat!(var * 2: [_; 1] ++ [5, 8] * 2 ++ [(1 + 2) * 3]);
+        asserteq(arr, [3, 3, 5, 8, 5, 8, 9]);
+    }
+}
+
+#[test]
+fn trim_trailing_test() {
+    use crate::trim_trailing;
+
+    {
+        const TRIMMED: ([u8; 6], usize) = trim_trailing!([3, 0, 5, 0, 0, 0], 0);
+        assert_eq!(TRIMMED, ([3, 0, 5, 0, 0, 0], 3));
+    }
+    {
+        // no trailing matches
+        const TRIMMED: ([u8; 3], usize) = trim_trailing!([0, 5, 8], 0);
+        assert_eq!(TRIMMED, ([0, 5, 8], 3));
+    }
+    {
+        // every element matches
+        const TRIMMED: ([u16; 4], usize) = trim_trailing!([7; 4], 7);
+        assert_eq!(TRIMMED, ([7; 4], 0));
+    }
+    assert_eq!(trim_trailing!([0u8; 0], 0), ([], 0));
+
+    let (arr, len) = trim_trailing!(concat_arrays!(["foo", "bar"], [""; 3]), "");
+    assert_eq!(arr[..len], ["foo", "bar"]);
+}
+
+#[test]
+fn lens_header() {
+    {
+        const ARR: [u8; 5] = {
+            let a = [3, 5];
+            let b = [8, 13, 21];
+            concat_arrays!(lens = [2, 3]; a, b)
+        };
+        asserteq(ARR, [3, 5, 8, 13, 21]);
+    }
+    {
+        const N: usize = 2;
+        let a = [Str("a"), Str("b")];
+        let b = [Str("c")];
+        let arr = concat_arrays!(
+            length_type = L;
+            lens = [N, N - 1, 0,];
+            checked;
+            a,
+            (b),
+            [],
+        );
+        asserteq(arr, [Str("a"), Str("b"), Str("c")]);
+        assert_eq!(L::LEN, 3);
+        enum L {}
+    }
+    {
+        let arr: [u8; 0] = concat_arrays!(lens = [];);
+        asserteq(arr, [0u8; 0]);
+    }
+}
+
+#[test]
+fn array_fold_test() {
+    use crate::array_fold;
+
+    const fn add(acc: u32, x: u32) -> u32 {
+        acc + x
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct MinMax {
+        min: u8,
+        max: u8,
+    }
+
+    const fn min_max(acc: MinMax, x: u8) -> MinMax {
+        MinMax {
+            min: if x < acc.min { x } else { acc.min },
+            max: if x > acc.max { x } else { acc.max },
+        }
+    }
+
+    {
+        const SUM: u32 = array_fold!(0, add, concat_arrays!([3, 5], [8, 13]));
+        assert_eq!(SUM, 29);
+    }
+    {
+        const SUM: u32 = array_fold!(7, add, [0u32; 0]);
+        assert_eq!(SUM, 7);
+    }
+    {
+        const MIN_MAX: MinMax = array_fold!(
+            MinMax {
+                min: u8::MAX,
+                max: 0
+            },
+            min_max,
+            [13, 3, 34, 8],
+        );
+        assert_eq!(MIN_MAX, MinMax { min: 3, max: 34 });
+    }
+
+    // order of the calls
+    let mut order = [0u8; 4];
+    let last = array_fold!(
+        0usize,
+        |i: usize, x: u8| {
+            order[i] = x;
+            i + 1
+        },
+        [3, 5, 8, 13],
+    );
+    assert_eq!(last, 4);
+    assert_eq!(order, [3, 5, 8, 13]);
+}
+
+#[test]
+fn find_subarray_test() {
+    use crate::find_subarray;
+
+    const HAYSTACK: [u8; 6] = [3, 5, 8, 13, 8, 13];
+
+    // found at the start
+    {
+        const FOUND: Option<usize> = find_subarray!(HAYSTACK, [3, 5]);
+        assert_eq!(FOUND, Some(0));
+    }
+    assert_eq!(find_subarray!(HAYSTACK, HAYSTACK), Some(0));
+    assert_eq!(find_subarray!(HAYSTACK, [0u8; 0]), Some(0));
+
+    // found in the middle, the first occurrence wins
+    {
+        const FOUND: Option<usize> = find_subarray!(HAYSTACK, [8, 13]);
+        assert_eq!(FOUND, Some(2));
+    }
+    assert_eq!(find_subarray!(&HAYSTACK, &[5, 8, 13]), Some(1));
+    assert_eq!(find_subarray!(HAYSTACK, [13, 8, 13]), Some(3));
+
+    // not found
+    {
+        const FOUND: Option<usize> = find_subarray!(HAYSTACK, [13, 3]);
+        assert_eq!(FOUND, None);
+    }
+    assert_eq!(find_subarray!(HAYSTACK, [8, 13, 21]), None);
+    assert_eq!(find_subarray!([0u8; 0], [3]), None);
+
+    // needle longer than haystack
+    {
+        const FOUND: Option<usize> = find_subarray!([3u8, 5], [3, 5, 8]);
+        assert_eq!(FOUND, None);
+    }
+    assert_eq!(find_subarray!([3u8], HAYSTACK), None);
+}
+
+#[test]
+fn array_all_any_test() {
+    use crate::{array_all, array_any};
+
+    use core::cell::Cell;
+
+    const fn is_nonzero(x: &u8) -> bool {
+        *x != 0
+    }
+
+    // all true
+    {
+        const ALL: bool = array_all!(is_nonzero, concat_arrays!([3, 5], [8]));
+        const ANY: bool = array_any!(is_nonzero, [3u8, 5, 8]);
+        assert_eq!([ALL, ANY], [true, true]);
+    }
+
+    // some false
+    {
+        const ALL: bool = array_all!(is_nonzero, [3u8, 0, 8]);
+        const ANY: bool = array_any!(is_nonzero, [0u8, 0, 8]);
+        assert_eq!([ALL, ANY], [false, true]);
+    }
+    assert!(!array_all!(is_nonzero, [0u8, 0]));
+    assert!(!array_any!(is_nonzero, [0u8, 0]));
+
+    // empty arrays
+    {
+        const ALL: bool = array_all!(is_nonzero, [0u8; 0]);
+        const ANY: bool = array_any!(is_nonzero, [0u8; 0]);
+        assert_eq!([ALL, ANY], [true, false]);
+    }
+
+    // non-Copy elements, passed by reference
+    {
+        let arr = [Str("foo"), Str(""), Str("bar")];
+        assert!(!array_all!(|s: &Str| !s.0.is_empty(), &arr));
+        assert!(array_any!(|s: &Str| s.0 == "bar", arr));
+    }
+
+    // short-circuiting
+    {
+        let calls = Cell::new(0);
+        let counted = |x: &u8| {
+            calls.set(calls.get() + 1);
+            *x != 0
+        };
+
+        assert!(!array_all!(counted, [3, 0, 5, 8]));
+        assert_eq!(calls.replace(0), 2);
+
+        assert!(array_any!(counted, [0, 3, 0, 5]));
+        assert_eq!(calls.replace(0), 2);
+    }
+}
+
+#[test]
+fn infer_elem_type_and_length_test() {
+    const ARR: [u8; 2] = [3, 5];
+
+    struct Pair {
+        first: [u16; 2],
+        second: [u16; 1],
+    }
+
+    const PAIR: Pair = Pair {
+        first: [8, 13],
+        second: [21],
+    };
+
+    const fn make_array() -> [u32; 3] {
+        [34, 55, 89]
+    }
+
+    // constants
+    asserteq(concat_arrays!(ARR: [_; _], [8]), [3, 5, 8]);
+    asserteq(concat_arrays!(ARR: [_; _], ARR: [_; _]), [3, 5, 3, 5]);
+    asserteq(concat_arrays!((crate::tests::INFER_CONST): [_; _]), [0, 1]);
+
+    // field accesses
+    asserteq(
+        concat_arrays!((PAIR.first): [_; _], (PAIR.second): [_; _]),
+        [8, 13, 21],
+    );
+
+    // const fn calls
+    asserteq(
+        concat_arrays!((make_array()): [_; _], [144]),
+        [34, 55, 89, 144],
+    );
+    asserteq(concat_arrays!({ make_array() }: [_; _]), [34, 55, 89]);
+
+    // array literals, repeated arguments, and nested invocations
+    asserteq(concat_arrays!([1u8, 2]: [_; _], ARR), [1, 2, 3, 5]);
+    asserteq(
+        concat_arrays!(ARR * 2: [_; _], [8] * 2: [_; _]),
+        [3, 5, 3, 5, 8, 8],
+    );
+    asserteq(
+        concat_arrays!(concat_arrays!(ARR, [8]): [_; _], [13]),
+        [3, 5, 8, 13],
+    );
+
+    // in const contexts
+    {
+        const CONCAT: [u32; 5] = concat_arrays!((make_array()): [_; _], [0, 1]);
+        asserteq(CONCAT, [34, 55, 89, 0, 1]);
+    }
+    {
+        const fn in_const_fn() -> [u16; 5] {
+            concat_arrays!((PAIR.first): [_; _], (PAIR.second): [_; _], [0, 1])
+        }
+        asserteq(in_const_fn(), [8, 13, 21, 0, 1]);
+    }
+
+    // variables need their length specified
+    {
+        let var = [3u8, 5];
+        asserteq(concat_arrays!(var: [_; 2], ARR: [_; _]), [3, 5, 3, 5]);
+    }
+}
+
+const INFER_CONST: [i8; 2] = [0, 1];
+
+#[test]
+fn pack_bits_test() {
+    use crate::{pack_bits, pack_bits_msb};
+
+    const T: bool = true;
+    const F: bool = false;
+
+    // empty
+    {
+        const BYTES: [u8; 0] = pack_bits!([F; 0]);
+        const INTEGER: u8 = pack_bits!(u8; [F; 0]);
+        asserteq(BYTES, [0u8; 0]);
+        assert_eq!(INTEGER, 0);
+    }
+    asserteq(pack_bits!(), [0u8; 0]);
+    asserteq(pack_bits_msb!(), [0u8; 0]);
+
+    // less than a byte, the unused bits are zeroed
+    {
+        const LSB: [u8; 1] = pack_bits!([T, F, T]);
+        const MSB: [u8; 1] = pack_bits_msb!([T, F, T]);
+        asserteq(LSB, [0b0000_0101]);
+        asserteq(MSB, [0b1010_0000]);
+    }
+
+    // exactly a byte
+    asserteq(pack_bits!([T, T, F, F, T, F, F, F]), [0b0001_0011]);
+    asserteq(pack_bits_msb!([T, T, F, F, T, F, F, F]), [0b1100_1000]);
+    asserteq(pack_bits!([T; 8]), [0xFF]);
+
+    // not a multiple of 8
+    {
+        const BITS: [bool; 11] = [T, F, F, F, F, F, F, T, F, T, T];
+        const LSB: [u8; 2] = pack_bits!(BITS);
+        const MSB: [u8; 2] = pack_bits_msb!(BITS);
+        asserteq(LSB, [0b1000_0001, 0b0000_0110]);
+        asserteq(MSB, [0b1000_0001, 0b0110_0000]);
+    }
+    asserteq(pack_bits!([T; 17]), [0xFF, 0xFF, 0b1]);
+    asserteq(pack_bits_msb!([T; 17]), [0xFF, 0xFF, 0b1000_0000]);
+
+    // concatenated arguments
+    {
+        let var = [T, T];
+        asserteq(
+            pack_bits!([F; 7], var: [_; 2], [T]),
+            [0b1000_0000, 0b0000_0011],
+        );
+    }
+
+    // integers
+    {
+        const LSB: u16 = pack_bits!(u16; [T, F, F, T, T]);
+        const MSB: u16 = pack_bits_msb!(u16; [T, F, F, T, T]);
+        assert_eq!(LSB, 0b1_1001);
+        assert_eq!(MSB, 0b1_0011);
+    }
+    assert_eq!(pack_bits!(u8; [T; 8]), u8::MAX);
+    assert_eq!(pack_bits_msb!(u8; [T; 8]), u8::MAX);
+    assert_eq!(pack_bits!(u64; [F; 63], [T]), 1 << 63);
+    assert_eq!(pack_bits_msb!(u64; [T], [F; 63]), 1 << 63);
+    assert_eq!(pack_bits!(u128; [T; 128]), u128::MAX);
+    assert_eq!(pack_bits_msb!(usize; [T, F]), 0b10);
+}
+
+#[test]
+fn cfg_argument_test() {
+    const ARR: [u8; 2] = [3, 5];
+
+    {
+        const CONCAT: [u8; CONCAT_LEN] = concat_arrays!(
+            [0],
+            #[cfg(feature = "rust_1_83")]
+            ARR,
+            #[cfg(not(feature = "rust_1_83"))]
+            [8],
+            [13],
+        );
+
+        #[cfg(feature = "rust_1_83")]
+        const CONCAT_LEN: usize = 4;
+        #[cfg(not(feature = "rust_1_83"))]
+        const CONCAT_LEN: usize = 3;
+
+        #[cfg(feature = "rust_1_83")]
+        asserteq(CONCAT, [0, 3, 5, 13]);
+        #[cfg(not(feature = "rust_1_83"))]
+        asserteq(CONCAT, [0, 8, 13]);
+    }
+
+    // always enabled and always disabled arguments
+    asserteq(
+        concat_arrays!(
+            #[cfg(all())]
+            ARR,
+            [8]
+        ),
+        [3, 5, 8],
+    );
+    asserteq(
+        concat_arrays!(
+            #[cfg(any())]
+            ARR,
+            [8]
+        ),
+        [8],
+    );
+    asserteq(
+        concat_arrays!(
+            [8],
+            #[cfg(any())]
+            ARR
+        ),
+        [8],
+    );
+    asserteq(
+        concat_arrays!(
+            [8],
+            #[cfg(any())]
+            ARR,
+        ),
+        [8],
+    );
+    asserteq(
+        concat_arrays!(
+            #[cfg(any())]
+            [3u8]
+        ),
+        [0u8; 0],
+    );
+
+    // annotated, repeated, and nested arguments
+    {
+        let var = [8u8, 13];
+        asserteq(
+            concat_arrays!(
+                #[cfg(all())]
+                var: [_; 2],
+                #[cfg(all())]
+                ARR * 2,
+                #[cfg(any())]
+                var: [_; 2],
+                #[cfg(all())]
+                concat_arrays!([21], #[cfg(any())] ARR),
+            ),
+            [8, 13, 3, 5, 3, 5, 21],
+        );
+    }
+
+    // along with headers
+    {
+        enum Len {}
+        const CONCAT: [u8; Len::LEN] = concat_arrays!(
+            length_type = Len;
+            lens = [2, 1];
+            ARR,
+            #[cfg(all())] [8],
+        );
+        asserteq(CONCAT, [3, 5, 8]);
+    }
+    {
+        // removing the trailing arguments
+        enum Len {}
+        const CONCAT: [u8; Len::LEN] = concat_arrays!(
+            length_type = Len;
+            checked;
+            ARR,
+            [8],
+            #[cfg(any())] [13],
+            #[cfg(any())] [21],
+        );
+        asserteq(CONCAT, [3, 5, 8]);
+
+        const LEN: usize = crate::__concat_arrays_length_of!(
+            ARR,
+            #[cfg(any())]
+            [13]
+        );
+        assert_eq!(LEN, 2);
+    }
+}
+
+#[test]
+fn dedup_consecutive_test() {
+    use crate::dedup_consecutive;
+
+    // runs
+    {
+        const DEDUPED: ([u8; 4], usize) = dedup_consecutive!([3, 3, 5, 8, 8, 8, 3], 4);
+        asserteq(DEDUPED.0, [3, 5, 8, 3]);
+        assert_eq!(DEDUPED.1, 4);
+    }
+    assert_eq!(dedup_consecutive!([7u8; 5], 1), ([7], 1));
+    assert_eq!(dedup_consecutive!([7u8; 5], 3), ([7, 7, 7], 1));
+    assert_eq!(
+        dedup_consecutive!(["foo", "foo", "bar", "foo"], 4),
+        (["foo", "bar", "foo", "foo"], 3),
+    );
+
+    // no runs
+    {
+        const DEDUPED: ([u16; 5], usize) = dedup_consecutive!([3, 5, 8, 13], 5);
+        asserteq(DEDUPED.0, [3, 5, 8, 13, 13]);
+        assert_eq!(DEDUPED.1, 4);
+    }
+    assert_eq!(dedup_consecutive!([3u8, 5, 3, 5], 4), ([3, 5, 3, 5], 4));
+
+    // empty arrays
+    assert_eq!(dedup_consecutive!([0u8; 0], 0), ([], 0));
+}
+
+#[test]
+fn const_crc32_test() {
+    use crate::const_crc32;
+
+    {
+        const CRC: u32 = const_crc32!();
+        assert_eq!(CRC, 0);
+    }
+    {
+        const CRC: u32 = const_crc32!([b'a']);
+        assert_eq!(CRC, 0xE8B7_BE43);
+    }
+    {
+        const CRC: u32 = const_crc32!((*b"123456789"));
+        assert_eq!(CRC, 0xCBF4_3926);
+    }
+    {
+        const FOX: [u8; 43] = *b"The quick brown fox jumps over the lazy dog";
+        const CRC: u32 = const_crc32!(FOX);
+        assert_eq!(CRC, 0x414F_A339);
+    }
+
+    // the checksum is of the concatenated bytes
+    {
+        const DATA: [u8; 7] = *b"3456789";
+        const CRC: u32 = const_crc32!([0x31u8, 0x32], DATA);
+        assert_eq!(CRC, 0xCBF4_3926);
+    }
+    assert_eq!(const_crc32!([0u8; 4]), 0x2144_DF1C);
+    assert_eq!(const_crc32!([0xFFu8; 4]), 0xFFFF_FFFF);
+
+    let data = [0x31u8, 0x32, 0x33];
+    assert_eq!(const_crc32!(data: [_; 3], (*b"456789")), 0xCBF4_3926);
+}
+
+#[test]
+fn concat_checksummed_test() {
+    use crate::concat_checksummed;
+
+    const TABLE: [u8; 4] = [8, 13, 21, 255];
+
+    {
+        const SUMMED: ([u8; 6], u8) = concat_checksummed!([3u8, 5], TABLE);
+        asserteq(SUMMED.0, [3, 5, 8, 13, 21, 255]);
+        assert_eq!(
+            SUMMED.1,
+            SUMMED.0.iter().fold(0u8, |a, &x| a.wrapping_add(x))
+        );
+        assert_eq!(SUMMED, concat_checksummed!(checksum = sum; [3u8, 5], TABLE));
+    }
+    {
+        const XORED: ([u8; 6], u8) = concat_checksummed!(checksum = xor; [3u8, 5], TABLE);
+        asserteq(XORED.0, [3, 5, 8, 13, 21, 255]);
+        assert_eq!(XORED.1, XORED.0.iter().fold(0, |a, &x| a ^ x));
+    }
+    {
+        const SIGNED: ([i16; 4], i16) = concat_checksummed!([i16::MAX, 1], [-5, 3]);
+        assert_eq!(SIGNED, ([i16::MAX, 1, -5, 3], i16::MAX - 1));
+    }
+    {
+        const WIDE: ([u64; 3], u64) = concat_checksummed!(checksum = xor; [u64::MAX], [1, 2]);
+        assert_eq!(WIDE, ([u64::MAX, 1, 2], !3));
+    }
+    {
+        const EMPTY: ([u32; 0], u32) = concat_checksummed!(checksum = xor; [0u32; 0]);
+        assert_eq!(EMPTY, ([], 0));
+    }
+
+    let runtime = [100u8, 200];
+    let (concat, checksum) = concat_checksummed!(runtime: [_; 2], [50, 6]);
+    asserteq(concat, [100, 200, 50, 6]);
+    assert_eq!(
+        checksum,
+        100u8.wrapping_add(200).wrapping_add(50).wrapping_add(6)
+    );
+    assert_eq!(
+        concat_checksummed!(checksum = xor; runtime: [_; 2]).1,
+        100 ^ 200
+    );
+}
+
+#[test]
+fn concat_tuple_elements_test() {
+    const A: [(u8, u16); 2] = [(3, 0x0305), (5, 0x0508)];
+    const B: [(u8, u16); 1] = [(8, 0x0813)];
+
+    {
+        const CONCAT: [(u8, u16); 5] = concat_arrays!(A, B, [(13, 0xFFFF), (0, 0)]);
+        asserteq(
+            CONCAT,
+            [(3, 0x0305), (5, 0x0508), (8, 0x0813), (13, 0xFFFF), (0, 0)],
+        );
+    }
+    {
+        const CONCAT: [(u8, u16); 6] = concat_arrays!(A * 2, B * 2);
+        asserteq(
+            CONCAT,
+            [
+                (3, 0x0305),
+                (5, 0x0508),
+                (3, 0x0305),
+                (5, 0x0508),
+                (8, 0x0813),
+                (8, 0x0813),
+            ],
+        );
+    }
+
+    // tuples with a different field order
+    asserteq(
+        concat_arrays!([(0x0305u16, 3u8)], [(0x0508, 5)]),
+        [(0x0305, 3), (0x0508, 5)],
+    );
+
+    // padded and non-Copy tuples
+    {
+        let arr = [(Str("foo"), 3u64, 5u8)];
+        asserteq(
+            concat_arrays!(arr: [_; 1], [(Str("bar"), u64::MAX, 8)]),
+            [(Str("foo"), 3, 5), (Str("bar"), u64::MAX, 8)],
+        );
+    }
+}
+
+#[test]
+fn extend_array_test() {
+    use crate::extend_array;
+
+    // sign extension
+    {
+        const WIDE: [i32; 4] = extend_array!(widen = i32; [1i8, -2, i8::MIN, i8::MAX]);
+        asserteq(WIDE, [1, -2, -128, 127]);
+    }
+    asserteq(extend_array!(widen = i16; [-1i8]), [-1]);
+    asserteq(
+        extend_array!(widen = i128; [i64::MIN, -1]),
+        [i64::MIN as i128, -1],
+    );
+    asserteq(extend_array!(widen = isize; [-3i16, 5]), [-3, 5]);
+
+    // zero extension
+    {
+        const WIDE: [u32; 4] = extend_array!(widen = u32; [0u8, 0x7F, 0x80, 0xFF]);
+        asserteq(WIDE, [0, 0x7F, 0x80, 0xFF]);
+    }
+    asserteq(extend_array!(widen = i16; [0xFFu8]), [0xFF]);
+    asserteq(extend_array!(widen = u128; [u64::MAX]), [u64::MAX as u128]);
+    asserteq(extend_array!(widen = usize; [u16::MAX]), [0xFFFF]);
+
+    // concatenated arrays
+    {
+        const WIDE: [i64; 3] = extend_array!(widen = i64; concat_arrays!([-3i32], [5, -8]));
+        asserteq(WIDE, [-3, 5, -8]);
+    }
+    asserteq(extend_array!(widen = u16; [0u8; 0]), [0u16; 0]);
+}
+
+#[test]
+fn concat_sat_test() {
+    use crate::concat_sat;
+
+    // saturating high
+    {
+        const SAT: [u8; 3] = concat_sat!(into = u8; [300i16, 5], [10u8]);
+        asserteq(SAT, [255, 5, 10]);
+    }
+    asserteq(concat_sat!(into = i8; [128u8], [i128::MAX]), [127, 127]);
+    asserteq(
+        concat_sat!(into = u32; [u128::MAX, 1 << 32]),
+        [u32::MAX, u32::MAX],
+    );
+
+    // saturating low
+    {
+        const SAT: [u16; 4] = concat_sat!(into = u16; [-1i8, i8::MIN], [-300i64, i64::MIN]);
+        asserteq(SAT, [0; 4]);
+    }
+    asserteq(
+        concat_sat!(into = i16; [-40000i32], [i128::MIN]),
+        [i16::MIN, i16::MIN],
+    );
+
+    // fitting exactly
+    {
+        const FIT: [i8; 5] = concat_sat!(into = i8; [-128i64, 127], [0u8, 127], [-128i8]);
+        asserteq(FIT, [-128, 127, 0, 127, -128]);
+    }
+    asserteq(
+        concat_sat!(into = u8; [255u16, 0], [255u128]),
+        [255, 0, 255],
+    );
+    asserteq(
+        concat_sat!(into = u128; [u128::MAX], [i128::MAX, -1]),
+        [u128::MAX, i128::MAX as u128, 0],
+    );
+    asserteq(
+        concat_sat!(into = i128; [u128::MAX, 3], [i128::MIN]),
+        [i128::MAX, 3, i128::MIN],
+    );
+    asserteq(concat_sat!(into = isize; [5usize], [-5i8]), [5, -5]);
+
+    // non-constant arguments
+    let runtime = [1000i32, -1000];
+    asserteq(
+        concat_sat!(into = i8; runtime: [_; 2], (runtime): [i32; 2], [3u8]),
+        [127, -128, 127, -128, 3],
+    );
+    asserteq(concat_sat!(into = u8; [0u64; 0], [7u64]), [7]);
+}
+
+#[test]
+fn const_positions_test() {
+    const ARR: [u8; 2] = [3, 5];
+
+    static STATIC: [u8; 3] = concat_arrays!(ARR, [8]);
+    asserteq(STATIC, [3, 5, 8]);
+
+    // array lengths
+    type Array = [u8; concat_arrays!(ARR, [2])[2] as usize];
+    let array: Array = [13; 2];
+    asserteq(array, [13, 13]);
+    asserteq([21u8; concat_arrays!([1u8], ARR).len()], [21, 21, 21]);
+
+    // enum discriminants
+    #[repr(u8)]
+    enum Enum {
+        First = concat_arrays!([8u8], ARR)[2],
+        Second,
+    }
+    assert_eq!(Enum::First as u8, 5);
+    assert_eq!(Enum::Second as u8, 6);
+
+    // const generic arguments
+    const fn get<const N: usize>() -> usize {
+        N
+    }
+    assert_eq!(get::<{ concat_arrays!(ARR, [8u8])[2] as usize }>(), 8);
+    assert_eq!(get::<{ concat_arrays!(ARR, ARR).len() }>(), 4);
+
+    // associated constants
+    trait Table {
+        const TABLE: [u8; 3];
+    }
+    impl Table for () {
+        const TABLE: [u8; 3] = concat_arrays!([0], ARR);
+    }
+    asserteq(<() as Table>::TABLE, [0, 3, 5]);
+
+    // nested in other constant expressions
+    const SUM: u8 = concat_arrays!(ARR, [8])[0] + concat_arrays!([13], ARR)[0];
+    assert_eq!(SUM, 16);
+}
+
+#[test]
+fn concat_from_fn_test() {
+    use crate::concat_from_fn;
+
+    const fn make(i: usize) -> [u8; 2] {
+        [i as u8; 2]
+    }
+
+    const fn make_generic<const N: usize>(i: usize) -> [u8; N] {
+        [i as u8; N]
+    }
+
+    {
+        const BLOCKS: [u8; 8] = concat_from_fn!(make, 4);
+        asserteq(BLOCKS, [0, 0, 1, 1, 2, 2, 3, 3]);
+    }
+    {
+        const BLOCKS: [u8; 9] = concat_from_fn!(make_generic::<3>, 3);
+        asserteq(BLOCKS, [0, 0, 0, 1, 1, 1, 2, 2, 2]);
+    }
+    asserteq(concat_from_fn!(make_generic::<1>, 5), [0, 1, 2, 3, 4]);
+    asserteq(concat_from_fn!(make, 1), [0, 0]);
+    asserteq(concat_from_fn!(make, 0), [0u8; 0]);
+    asserteq(concat_from_fn!(make_generic::<0>, 3), [0u8; 0]);
+
+    // non-Copy elements
+    const fn strs(i: usize) -> [Str; 1] {
+        [Str(["foo", "bar"][i])]
+    }
+    asserteq(concat_from_fn!(strs, 2), [Str("foo"), Str("bar")]);
+}
+
+#[test]
+fn array_from_fn_test() {
+    use crate::array_from_fn;
+
+    const fn square(i: usize) -> u32 {
+        (i * i) as u32
+    }
+
+    {
+        const SQUARES: [u32; 4] = array_from_fn!(square, 4);
+        asserteq(SQUARES, [0, 1, 4, 9]);
+    }
+    {
+        const ARR: [u8; 4] = concat_arrays!(array_from_fn!(|i| i as u8, 3), [9]);
+        asserteq(ARR, [0, 1, 2, 9]);
+    }
+    {
+        const ARR: [u32; 6] = concat_arrays!(
+            [100],
+            array_from_fn!(square, 2),
+            (crate::array_from_fn!(|i| i as u32 + 10, 3)),
+        );
+        asserteq(ARR, [100, 0, 1, 10, 11, 12]);
+    }
+    {
+        const EMPTY: [u8; 0] = array_from_fn!(|_| 0, 0);
+        asserteq(EMPTY, []);
+    }
+
+    // closures that capture variables need the length of the generated argument
+    let offset = 100u16;
+    asserteq(
+        concat_arrays!(array_from_fn!(|i| i as u16 + offset, 3): [_; 3], [9]),
+        [100, 101, 102, 9],
+    );
+    asserteq(
+        array_from_fn!(|i| Str(["foo", "bar"][i]), 2),
+        [Str("foo"), Str("bar")],
+    );
+}
+
+#[test]
+fn count_true_test() {
+    use crate::count_true;
+
+    const FLAGS: [bool; 4] = [true, false, false, true];
+
+    {
+        const COUNT: usize = count_true!([true, false], FLAGS);
+        assert_eq!(COUNT, 3);
+    }
+    {
+        const COUNT: usize = count_true!(FLAGS * 3, [true]);
+        assert_eq!(COUNT, 7);
+    }
+
+    // all false
+    assert_eq!(count_true!([false; 5], [false; 3]), 0);
+    assert_eq!(count_true!(), 0);
+
+    // all true
+    assert_eq!(count_true!([true; 5], [true; 3]), 8);
+
+    let var = [false, true];
+    assert_eq!(count_true!(var: [_; 2], FLAGS), 3);
+}
+
+#[test]
+fn fill_concat_test() {
+    use crate::fill_concat;
+
+    const HEADER: [u8; 2] = [3, 5];
+
+    {
+        let mut buffer = [0u8; 6];
+        let part = [13, 21];
+        fill_concat!(&mut buffer, HEADER, [8], part, &[34]);
+        asserteq(buffer, [3, 5, 8, 13, 21, 34]);
+
+        // overwrites the previous contents
+        fill_concat!(&mut buffer, [0; 3], HEADER, [1]);
+        asserteq(buffer, [0, 0, 0, 3, 5, 1]);
+    }
+    {
+        let mut buffer: [&str; 3] = [""; 3];
+        let out = &mut buffer;
+        fill_concat!(out, ["foo"], ["bar", "baz"],);
+        asserteq(buffer, ["foo", "bar", "baz"]);
+    }
+    {
+        let mut buffer = [0u8; 0];
+        fill_concat!(&mut buffer);
+        fill_concat!(&mut buffer, [0u8; 0]);
+        asserteq(buffer, [0u8; 0]);
+    }
+}
+
+#[test]
+#[should_panic]
+fn fill_concat_too_long_test() {
+    let mut buffer = [0u8; 3];
+    crate::fill_concat!(&mut buffer, [3, 5], [8, 13]);
+}
+
+#[test]
+#[should_panic]
+fn fill_concat_too_short_test() {
+    let mut buffer = [0u8; 3];
+    crate::fill_concat!(&mut buffer, [3, 5]);
+}
+
+#[test]
+fn enum_array_test() {
+    use crate::enum_array;
+
+    #[derive(Debug, PartialEq, Copy, Clone)]
+    enum Level {
+        Low = 3,
+        Mid = 5,
+        High = 8,
+    }
+
+    const fn weight(level: Level) -> u16 {
+        match level {
+            Level::Low => 100,
+            Level::Mid => 200,
+            Level::High => 300,
+        }
+    }
+
+    {
+        const DISCRIMINANTS: [u8; 3] = enum_array!([Low, Mid, High]: Level; |v| v as u8);
+        asserteq(DISCRIMINANTS, [3, 5, 8]);
+    }
+    {
+        const WEIGHTS: [u16; 3] = enum_array!([Low, Mid, High]: Level; weight);
+        asserteq(WEIGHTS, [100, 200, 300]);
+    }
+
+    // in the listed order, including repeated variants
+    asserteq(
+        enum_array!([High, Low, High,]: Level; |v| v),
+        [Level::High, Level::Low, Level::High],
+    );
+    asserteq(enum_array!([]: Level; |v| v as u8), [0u8; 0]);
+
+    // patterns and non-const closures
+    asserteq(
+        enum_array!([Low, High]: Level; |_| "level"),
+        ["level", "level"],
+    );
+    let offset = 10;
+    asserteq(
+        enum_array!([Mid, High]: Level; |v: Level| v as u32 + offset),
+        [15, 18],
+    );
+
+    // concatenated with other arrays
+    {
+        const TABLE: [u8; 5] = concat_arrays!(
+            [0],
+            enum_array!([Low, Mid, High]: Level; |v| v as u8): [_; 3],
+            [0xFF],
+        );
+        asserteq(TABLE, [0, 3, 5, 8, 0xFF]);
+    }
+}
+
+#[test]
+fn concat_sorted_test() {
+    use crate::{concat_sorted, concat_sorted_by};
+    use core::cmp::Ordering;
+
+    const fn is_sorted<const N: usize>(arr: &[i32; N]) -> bool {
+        let mut i = 1;
+        while i < N {
+            if arr[i - 1] > arr[i] {
+                return false;
+            }
+            i += 1;
+        }
+        true
+    }
+
+    const fn cmp_first(l: &(u8, char), r: &(u8, char)) -> Ordering {
+        if l.0 < r.0 {
+            Ordering::Less
+        } else if l.0 == r.0 {
+            Ordering::Equal
+        } else {
+            Ordering::Greater
+        }
+    }
+
+    const fn cmp_rev(l: &i32, r: &i32) -> Ordering {
+        if *l > *r {
+            Ordering::Less
+        } else if *l == *r {
+            Ordering::Equal
+        } else {
+            Ordering::Greater
+        }
+    }
+
+    {
+        const SORTED: [i32; 4] = concat_sorted!([3, 1], [2, 5]);
+        asserteq(SORTED, [1, 2, 3, 5]);
+    }
+    {
+        const ARR: [i32; 6] = [8, -3, 0, 8, 13, -21];
+        const SORTED: [i32; 9] = concat_sorted!(ARR, [5, 3, 0]);
+        assert!(is_sorted(&SORTED));
+        asserteq(SORTED, [-21, -3, 0, 0, 3, 5, 8, 8, 13]);
+    }
+    asserteq(concat_sorted!([5; 3], [1] * 2), [1, 1, 5, 5, 5]);
+    asserteq(concat_sorted!([3u8]), [3]);
+    asserteq(concat_sorted!([0u8; 0]), [0u8; 0]);
+
+    // comparator functions
+    {
+        const SORTED: [i32; 5] = concat_sorted_by!(cmp_rev; [3, 1], [2, 5, 4]);
+        asserteq(SORTED, [5, 4, 3, 2, 1]);
+    }
+
+    // stability
+    {
+        const SORTED: [(u8, char); 6] = concat_sorted_by!(
+            cmp_first;
+            [(2, 'a'), (1, 'b'), (2, 'c')],
+            [(1, 'd'), (0, 'e'), (2, 'f')],
+        );
+        asserteq(
+            SORTED,
+            [(0, 'e'), (1, 'b'), (1, 'd'), (2, 'a'), (2, 'c'), (2, 'f')],
+        );
+    }
+    asserteq(
+        concat_sorted_by!(|l: &(u8, char), r: &(u8, char)| r.0.cmp(&l.0); [(1, 'a'), (2, 'b')], [(1, 'c')]),
+        [(2, 'b'), (1, 'a'), (1, 'c')],
+    );
+}
+
+#[test]
+fn function_call_argument_test() {
+    const fn make() -> [u8; 4] {
+        [3, 5, 8, 13]
+    }
+
+    mod inner {
+        pub const fn pair(x: u8) -> [u8; 2] {
+            [x, x + 1]
+        }
+    }
+
+    // without type annotations
+    {
+        const CONCAT: [u8; 5] = concat_arrays!(make(), [9]);
+        asserteq(CONCAT, [3, 5, 8, 13, 9]);
+    }
+    asserteq(
+        concat_arrays!(make(), inner::pair(21)),
+        [3, 5, 8, 13, 21, 22],
+    );
+    asserteq(
+        concat_arrays!(inner::pair(0), ::core::convert::identity([1u8])),
+        [0, 1, 1],
+    );
+
+    // with partial type annotations
+    asserteq(concat_arrays!(make(): [u8; _], [9]), [3, 5, 8, 13, 9]);
+    asserteq(
+        concat_arrays!(make(): [_; _], inner::pair(1): [_; 2]),
+        [3, 5, 8, 13, 1, 2],
+    );
+
+    // repeated
+    asserteq(concat_arrays!(inner::pair(1) * 2, [0]), [1, 2, 1, 2, 0]);
+
+    // runtime arguments require the length
+    {
+        let x = 34;
+        asserteq(
+            concat_arrays!(inner::pair(x): [_; 2], make()),
+            [34, 35, 3, 5, 8, 13],
+        );
+    }
+}
+
+#[test]
+fn mask_indices_test() {
+    use crate::mask_indices;
+
+    {
+        const INDICES: ([usize; 3], usize) = mask_indices!([true, false, true]);
+        assert_eq!(INDICES, ([0, 2, 0], 2));
+    }
+    {
+        const MASK: [bool; 4] = [false, false, true, true];
+        const INDICES: ([usize; 7], usize) = mask_indices!(MASK, [false, true, false]);
+        assert_eq!(INDICES, ([2, 3, 5, 0, 0, 0, 0], 3));
+    }
+
+    // all false
+    assert_eq!(mask_indices!([false; 4]), ([0; 4], 0));
+
+    // all true
+    assert_eq!(mask_indices!([true; 4]), ([0, 1, 2, 3], 4));
+
+    // empty
+    assert_eq!(mask_indices!(), ([0; 0], 0));
+}
+
+#[test]
+fn zero_sized_marker_test() {
+    use core::marker::PhantomData;
+
+    #[derive(Debug, PartialEq, Copy, Clone)]
+    struct Marker<T>(PhantomData<T>);
+
+    const M: Marker<u64> = Marker(PhantomData);
+    const PAIR: [Marker<u64>; 2] = [M; 2];
+
+    {
+        const CONCAT: [Marker<u64>; 5] = concat_arrays!(PAIR, [M], PAIR);
+        asserteq(CONCAT, [M; 5]);
+    }
+    {
+        const CONCAT: [Marker<u64>; 6] = concat_arrays!(PAIR * 2, [M; 2]);
+        asserteq(CONCAT, [M; 6]);
+    }
+
+    // inferring the element type, or the length, from annotations
+    {
+        let var = [Marker::<u64>(PhantomData); 3];
+        let concat = concat_arrays!(var: [_; 3], [Marker(PhantomData)]: [Marker<u64>; _]);
+        assert_eq!(concat.len(), 4);
+        asserteq(concat, [M; 4]);
+    }
+    asserteq(
+        concat_arrays!([Marker(PhantomData); 2]: [Marker<u64>; _], PAIR: [_; _]),
+        [M; 4],
+    );
+
+    // the length is computed from the arguments, not from their size
+    {
+        enum Len {}
+        const CONCAT: [Marker<u64>; Len::LEN] = concat_arrays!(
+            length_type = Len;
+            PAIR, [M; 3], PAIR * 3
+        );
+        assert_eq!(Len::LEN, 11);
+        assert_eq!(CONCAT.len(), 11);
+    }
+    assert_eq!(crate::__concat_arrays_length_of!(PAIR, [M; 7]), 9);
+}
+
+#[test]
+fn concat_rows_test() {
+    use crate::concat_rows;
+
+    const ROW: [u8; 3] = [3, 5, 8];
+
+    {
+        const MATRIX: [u8; 9] = concat_rows!([1, 0, 0], ROW, [0, 0, 1]);
+        asserteq(MATRIX, [1, 0, 0, 3, 5, 8, 0, 0, 1]);
+    }
+    {
+        const MATRIX: [u8; 3] = concat_rows!(ROW,);
+        asserteq(MATRIX, [3, 5, 8]);
+    }
+    asserteq(concat_rows!([0u8; 0], [0u8; 0]), [0u8; 0]);
+    asserteq(concat_rows!(), [0u8; 0]);
+
+    {
+        let row = [13u8, 21, 34];
+        asserteq(
+            concat_rows!(row: [_; 3], ROW, (row): [u8; 3]),
+            [13, 21, 34, 3, 5, 8, 13, 21, 34],
+        );
+    }
+    asserteq(
+        concat_rows!([Str("foo"), Str("bar")], [Str("baz"), Str("qux")]),
+        [Str("foo"), Str("bar"), Str("baz"), Str("qux")],
+    );
+}
+
+#[test]
+fn destructure_test() {
+    const ARR: [u8; 2] = [2, 3];
+
+    let [x, y, z] = concat_arrays!([1u8], [2, 3]);
+    assert_eq!((x, y, z), (1, 2, 3));
+
+    let [first, middle @ .., last] = concat_arrays!([1u8], ARR, [4]);
+    assert_eq!((first, middle, last), (1, [2, 3], 4));
+
+    {
+        let var = 5u8;
+        let [a, b] = concat_arrays!([var], [var + 1]);
+        assert_eq!((a, b), (5, 6));
+    }
+
+    match concat_arrays!([1u8], ARR) {
+        [1, rest @ ..] => assert_eq!(rest, [2, 3]),
+        _ => unreachable!(),
+    }
+
+    let [Str(foo), Str(bar)] = concat_arrays!([Str("foo")], [Str("bar")]);
+    assert_eq!((foo, bar), ("foo", "bar"));
+
+    {
+        const SUM: u8 = {
+            let [a, b, c] = concat_arrays!(ARR, [5]);
+            a + b + c
+        };
+        assert_eq!(SUM, 10);
+    }
+}
+
+#[test]
+fn prefix_sums_test() {
+    use crate::prefix_sums;
+
+    {
+        const SUMS: ([usize; 3], [usize; 4]) = prefix_sums!([2usize, 3, 1]);
+        assert_eq!(SUMS, ([2, 3, 1], [0, 2, 5, 6]));
+    }
+    {
+        const LENS: [u8; 2] = [4, 0];
+        const SUMS: ([u8; 4], [u8; 5]) = prefix_sums!(LENS, [7, 1]);
+        assert_eq!(SUMS.0, [4, 0, 7, 1]);
+        assert_eq!(SUMS.1, [0, 4, 4, 11, 12]);
+        assert_eq!(SUMS.1[0], 0);
+        assert_eq!(SUMS.1[4], 4 + 7 + 1);
+    }
+
+    assert_eq!(prefix_sums!([5i32, -8, 3]), ([5, -8, 3], [0, 5, -3, 0]));
+    assert_eq!(prefix_sums!([3u64]), ([3], [0, 3]));
+    assert_eq!(prefix_sums!([0usize; 0]), ([], [0]));
+}
+
+#[test]
+fn drop_exactly_once_test() {
+    let dropped = Cell::new(0u64);
+    let new = |id: u32| Counted {
+        id,
+        dropped: &dropped,
+    };
+
+    {
+        let first = [new(0), new(1)];
+        let second = [new(4)];
+
+        let concat = concat_arrays!(
+            first: [_; 2],
+            [new(2), new(3)],
+            second: [_; 1],
+            concat_arrays!([new(5)], [new(6), new(7)]),
+            [],
+        );
+
+        // nothing is dropped while concatenating
+        assert_eq!(dropped.get(), 0);
+
+        for (i, elem) in concat.iter().enumerate() {
+            assert_eq!(elem.id, i as u32);
+        }
+    }
+
+    // every element is dropped exactly once
+    assert_eq!(dropped.get(), 0xFF);
+
+    // dropping a partially moved-out-of concatenation
+    dropped.set(0);
+    {
+        let [a, b, rest @ ..] = concat_arrays!([new(0), new(1)], [new(2), new(3)]);
+        assert_eq!(dropped.get(), 0);
+        drop(b);
+        assert_eq!(dropped.get(), 0b10);
+        drop(rest);
+        assert_eq!(dropped.get(), 0b1110);
+        drop(a);
+    }
+    assert_eq!(dropped.get(), 0b1111);
+}
+
+#[test]
+fn concat_rev_test() {
+    use crate::{apply_permutation, concat_rev};
+
+    const ARR: [u8; 2] = [3, 4];
+
+    {
+        const REVERSED: [u8; 4] = concat_rev!([1, 2], ARR);
+        asserteq(REVERSED, [4, 3, 2, 1]);
+    }
+    {
+        const REVERSED: [u8; 5] = concat_rev!([1, 2], ARR, [5]);
+        const PERMUTED: [u8; 5] =
+            apply_permutation!(concat_arrays!([1, 2], ARR, [5]), [4, 3, 2, 1, 0],);
+        asserteq(REVERSED, PERMUTED);
+    }
+
+    {
+        let var = ["foo", "bar"];
+        asserteq(
+            concat_rev!(var: [_; 2], ["baz"]),
+            apply_permutation!(concat_arrays!(var: [_; 2], ["baz"]), [2, 1, 0]),
+        );
+    }
+    asserteq(concat_rev!(ARR * 2), [4, 3, 4, 3]);
+    asserteq(concat_rev!([3u8]), [3]);
+    asserteq(concat_rev!([0u8; 0]), [0u8; 0]);
+}
+
+#[test]
+fn length_type_annotation_test() {
+    use crate::ArrayLength;
+
+    struct PairLen;
+
+    impl ArrayLength for PairLen {
+        const LENGTH: usize = 2;
+    }
+
+    type TripleLen = [(); 3];
+
+    const fn with_pair(pair: [u8; 2]) -> [u8; 5] {
+        concat_arrays!(pair: len = PairLen, [8, 13], pair * 0: len = PairLen, [21])
+    }
+
+    const CONST: [u8; 5] = with_pair([3, 5]);
+    asserteq(CONST, [3, 5, 8, 13, 21]);
+
+    let triple = [1u16, 2, 3];
+    let pair = [4u16, 5];
+    asserteq(
+        concat_arrays!(triple: len = TripleLen, pair: len = PairLen),
+        [1, 2, 3, 4, 5],
+    );
+    asserteq(
+        concat_arrays!([0], (pair): len = PairLen, triple * 2: len = TripleLen),
+        [0, 4, 5, 1, 2, 3, 1, 2, 3],
+    );
+    asserteq(
+        concat_arrays!(["foo", "bar"]: len = PairLen, ["baz"]),
+        ["foo", "bar", "baz"],
+    );
+}
+
+#[test]
+fn concat_unique_test() {
+    use crate::concat_unique;
+
+    const TAIL: [u16; 2] = [8, 13];
+
+    {
+        const KEYS: [u16; 5] = concat_unique!([3, 5], TAIL, [21]);
+        asserteq(KEYS, [3, 5, 8, 13, 21]);
+    }
+    {
+        const KEYS: [char; 3] = concat_unique!(['f'], ['o', 'b']);
+        asserteq(KEYS, ['f', 'o', 'b']);
+    }
+
+    let var = ['a', 'b'];
+    asserteq(concat_unique!(var: [_; 2], ['c']), ['a', 'b', 'c']);
+    asserteq(concat_unique!([3u8]), [3]);
+    asserteq(concat_unique!([0u8; 0]), [0u8; 0]);
+}
+
+#[test]
+#[should_panic(expected = "duplicate elements")]
+fn concat_unique_duplicate_across_args_test() {
+    use crate::concat_unique;
+
+    let var = [3u8, 5];
+    let _ = concat_unique!([8, 13], var: [_; 2], [13]);
+}
+
+#[test]
+fn matrix_test() {
+    use crate::matrix;
+
+    const ROW: [u8; 3] = [7, 8, 9];
+
+    {
+        const MATRIX: [[u8; 3]; 3] = matrix!([1, 2, 3], concat_arrays!([4], [5, 6]), ROW);
+        asserteq(MATRIX, [[1, 2, 3], [4, 5, 6], [7, 8, 9]]);
+    }
+    {
+        const MATRIX: [[u8; 0]; 2] = matrix!([0u8; 0], [0u8; 0],);
+        asserteq(MATRIX, [[0u8; 0]; 2]);
+    }
+
+    let var = [Str("a"), Str("b")];
+    asserteq(
+        matrix!(var, [Str("c"), Str("d")]),
+        [[Str("a"), Str("b")], [Str("c"), Str("d")]],
+    );
+    asserteq(matrix!(ROW), [ROW]);
+
+    let empty: [[u8; 2]; 0] = matrix!();
+    asserteq(empty, []);
+}
+
+#[test]
+fn resize_array_test() {
+    use crate::resize_array;
+
+    const ARR: [u8; 3] = [3, 5, 8];
+
+    {
+        const GROWN: [u8; 5] = resize_array!(ARR, 5, 0);
+        asserteq(GROWN, [3, 5, 8, 0, 0]);
+    }
+    {
+        const SHRUNK: [u8; 2] = resize_array!(ARR, 2, 0);
+        asserteq(SHRUNK, [3, 5]);
+    }
+    {
+        const SAME: [u8; 3] = resize_array!(ARR, 3, 0);
+        asserteq(SAME, ARR);
+    }
+
+    let var = ["foo", "bar"];
+    asserteq(resize_array!(var, 4, "baz"), ["foo", "bar", "baz", "baz"]);
+    asserteq(resize_array!(var, 0, "baz"), [""; 0]);
+    asserteq(resize_array!([0u8; 0], 2, 13), [13, 13]);
+    asserteq(resize_array!(concat_arrays!(ARR, [13]), 3, 0), ARR);
+}
+
+#[test]
+fn concat_clamp_test() {
+    use crate::concat_clamp;
+
+    const A: [u8; 3] = [1, 2, 3];
+    const B: [u8; 3] = [4, 5, 6];
+
+    // over-length
+    {
+        const CLAMPED: [u8; 4] = concat_clamp!(max = 4; A, B);
+        asserteq(CLAMPED, [1, 2, 3, 4]);
+    }
+    {
+        const CLAMPED: [u8; 2] = concat_clamp!(max = 2; A, B, [7]);
+        asserteq(CLAMPED, [1, 2]);
+    }
+    {
+        const CLAMPED: [u8; 0] = concat_clamp!(max = 0; A);
+        asserteq(CLAMPED, []);
+    }
+    // exact fit
+    {
+        const EXACT: [u8; 6] = concat_clamp!(max = 6; A, B);
+        asserteq(EXACT, [1, 2, 3, 4, 5, 6]);
+    }
+    {
+        const EXACT: [u8; 0] = concat_clamp!(max = 0;);
+        asserteq(EXACT, []);
+    }
+
+    let var = ["foo", "bar"];
+    asserteq(
+        concat_clamp!(max = 3; var: [_; 2], ["baz", "qux"]),
+        ["foo", "bar", "baz"],
+    );
+    asserteq(concat_clamp!(max = A.len(); [0u8], A), [0, 1, 2]);
+}
+
+#[test]
+#[should_panic]
+fn concat_clamp_too_short() {
+    let _ = crate::concat_clamp!(max = 4; [1u8], [2, 3]);
+}
+
+#[test]
+fn tile_to_test() {
+    use crate::tile_to;
+
+    const PATTERN: [u8; 3] = [3, 5, 8];
+
+    // multiple of the pattern length
+    {
+        const TILED: [u8; 6] = tile_to!(PATTERN, 6);
+        asserteq(TILED, [3, 5, 8, 3, 5, 8]);
+    }
+    {
+        const TILED: [u8; 3] = tile_to!(PATTERN, 3);
+        asserteq(TILED, PATTERN);
+    }
+    // truncating mid-pattern
+    {
+        const TILED: [u8; 5] = tile_to!([0xAA, 0x55], 5);
+        asserteq(TILED, [0xAA, 0x55, 0xAA, 0x55, 0xAA]);
+    }
+    {
+        const TILED: [u8; 7] = tile_to!(concat_arrays!(PATTERN, [13]), 7);
+        asserteq(TILED, [3, 5, 8, 13, 3, 5, 8]);
+    }
+    // shorter than the pattern
+    {
+        const TILED: [u8; 2] = tile_to!(PATTERN, 2);
+        asserteq(TILED, [3, 5]);
+    }
+
+    let var = ["foo"];
+    asserteq(tile_to!(var, 3), ["foo"; 3]);
+    asserteq(tile_to!(var, 0), [""; 0]);
+    asserteq(tile_to!([0u8; 0], 0), [0u8; 0]);
+}
+
+#[test]
+#[should_panic]
+fn tile_to_empty_pattern() {
+    let _: [u8; 1] = crate::tile_to!([0u8; 0], 1);
+}
+
+#[test]
+fn concat_arrays_rev_arg_test() {
+    const ARR: [u8; 3] = [3, 5, 8];
+
+    const fn rev() -> [u8; 2] {
+        [13, 21]
+    }
+
+    {
+        const REVERSED: [u8; 5] = concat_arrays!(rev ARR, [13, 21]);
+        asserteq(REVERSED, [8, 5, 3, 13, 21]);
+    }
+    {
+        const REVERSED: [u8; 7] = concat_arrays!([1, 2], rev [3, 4, 5], ARR * 0, rev [6, 7]);
+        asserteq(REVERSED, [1, 2, 5, 4, 3, 7, 6]);
+    }
+    {
+        // `rev` is a function and a constant in these arguments, not a modifier
+        const REV: [u8; 1] = [34];
+        const ARGS: [u8; 5] = concat_arrays!(rev(), REV, rev(), ARR * 0);
+        asserteq(ARGS, [13, 21, 34, 13, 21]);
+    }
+
+    let var = ["foo", "bar"];
+    asserteq(
+        concat_arrays!(var: [_; 2], rev var: [_; 2], rev {var}: [_; 2]),
+        ["foo", "bar", "bar", "foo", "bar", "foo"],
+    );
+    asserteq(
+        concat_arrays!(rev var * 2: [_; 2], rev concat_arrays!(["baz"], var: [_; 2])),
+        ["bar", "foo", "bar", "foo", "bar", "foo", "baz"],
+    );
+    asserteq(concat_arrays!(rev [0u8; 0], rev [3u8]), [3]);
+    {
+        let rev = [1u8, 2];
+        asserteq(
+            concat_arrays!(rev: [_; 2], rev * 2: [_; 2]),
+            [1, 2, 1, 2, 1, 2],
+        );
+        asserteq(concat_arrays!(rev rev: [_; 2]), [2, 1]);
+    }
+
+    {
+        const LEN: usize = crate::__concat_arrays_length_of!(rev ARR, [0], rev ARR * 2);
+        assert_eq!(LEN, 10);
+    }
+}
+
+#[test]
+fn concat_pad_each_test() {
+    use crate::concat_pad_each;
+
+    const ARR: [u8; 3] = [3, 4, 5];
+
+    {
+        const PADDED: [u8; 8] = concat_pad_each!(width = 4, fill = 0; [1, 2], ARR);
+        asserteq(PADDED, [1, 2, 0, 0, 3, 4, 5, 0]);
+    }
+    {
+        const PADDED: [u8; 9] = concat_pad_each!(width = 3, fill = 9; ARR, [], [8],);
+        asserteq(PADDED, [3, 4, 5, 9, 9, 9, 8, 9, 9]);
+    }
+    {
+        const EMPTY: [u8; 0] = concat_pad_each!(width = 3, fill = 9;);
+        asserteq(EMPTY, []);
+    }
+
+    let var = ["foo", "bar"];
+    asserteq(
+        concat_pad_each!(width = 3, fill = ""; var: [_; 2], ["baz"], (var): [&str; 2]),
+        ["foo", "bar", "", "baz", "", "", "foo", "bar", ""],
+    );
+    asserteq(concat_pad_each!(width = 0, fill = 0u8; [], []), []);
+}
+
+#[test]
+fn elem_type_header_test() {
+    use crate::ElemType;
+
+    enum Pairs {}
+    enum Names {}
+    enum Bytes {}
+
+    const PAIRS: [<Pairs as ElemType>::Elem; 3] = concat_arrays!(
+        elem_type = Pairs;
+        [(3, 'a')],
+        [(5, 'b')]: [(u16, char); _],
+        [(8, 'c')]: [(u16, char); 1],
+    );
+    asserteq(PAIRS, [(3u16, 'a'), (5, 'b'), (8, 'c')]);
+
+    fn first<T: ElemType>(pairs: &[T::Elem]) -> &T::Elem {
+        &pairs[0]
+    }
+    assert_eq!(first::<Pairs>(&PAIRS), &(3, 'a'));
+
+    let names = ["foo", "bar"];
+    let found: [<Names as ElemType>::Elem; 3] = concat_arrays!(
+        length_type = Names;
+        elem_type = Names;
+        checked;
+        names: [&'static str; 2],
+        ["baz"],
+    );
+    asserteq(found, ["foo", "bar", "baz"]);
+    assert_eq!(Names::LEN, 3);
+
+    // repeated arguments are skipped when getting the element type
+    const BYTES: [<Bytes as ElemType>::Elem; 5] =
+        concat_arrays!(elem_type = Bytes; [3, 5] * 2: [u8; 2], [8]: [u8; 1]);
+    asserteq(BYTES, [3u8, 5, 3, 5, 8]);
+}
+
+#[test]
+fn rotate_test() {
+    use crate::{matrix, rotate180, rotate270, rotate90};
+
+    const MATRIX: [[u8; 3]; 3] = matrix!([1, 2, 3], [4, 5, 6], [7, 8, 9]);
+
+    {
+        const ROT90: [[u8; 3]; 3] = rotate90!(MATRIX);
+        const ROT180: [[u8; 3]; 3] = rotate180!(MATRIX);
+        const ROT270: [[u8; 3]; 3] = rotate270!(MATRIX);
+
+        asserteq(ROT90, [[7, 4, 1], [8, 5, 2], [9, 6, 3]]);
+        asserteq(ROT180, [[9, 8, 7], [6, 5, 4], [3, 2, 1]]);
+        asserteq(ROT270, [[3, 6, 9], [2, 5, 8], [1, 4, 7]]);
+
+        asserteq(rotate90!(ROT90), ROT180);
+        asserteq(rotate90!(ROT180), ROT270);
+        asserteq(rotate90!(ROT270), MATRIX);
+        asserteq(rotate180!(ROT180), MATRIX);
+        asserteq(rotate270!(ROT90), MATRIX);
+        asserteq(rotate90!(rotate90!(rotate90!(rotate90!(MATRIX)))), MATRIX);
+    }
+
+    let strs = [["a", "b"], ["c", "d"]];
+    asserteq(rotate90!(strs), [["c", "a"], ["d", "b"]]);
+    asserteq(rotate180!(strs), [["d", "c"], ["b", "a"]]);
+    asserteq(rotate270!(strs), [["b", "d"], ["a", "c"]]);
+
+    asserteq(rotate90!([[3u8]]), [[3]]);
+    asserteq(rotate90!([[0u8; 0]; 0]), [[0u8; 0]; 0]);
+}
+
+#[test]
+fn max_len_header_test() {
+    const MAX: usize = 4;
+
+    {
+        const ARR: [u8; 4] = concat_arrays!(max_len = MAX; [3, 5], [8, 13]);
+        asserteq(ARR, [3, 5, 8, 13]);
+    }
+    {
+        const ARR: [u8; 3] = concat_arrays!(max_len = MAX + 1; [3, 5] * 1, [8],);
+        asserteq(ARR, [3, 5, 8]);
+    }
+    {
+        const ARR: [u8; 0] = concat_arrays!(max_len = 0; [0u8; 0]);
+        asserteq(ARR, []);
+    }
+
+    let var = ["foo", "bar"];
+    let found = concat_arrays!(
+        checked;
+        max_len = 3;
+        length_type = Foo;
+        var: [_; 2],
+        ["baz"],
+    );
+    asserteq(found, ["foo", "bar", "baz"]);
+    assert_eq!(Foo::LEN, 3);
+
+    struct Foo;
+}
+
+#[test]
+fn concat_arrays_slice_arg_test() {
+    const SLICE: &[u8] = &[1, 2, 3];
+    const EMPTY: &[u8] = &[];
+
+    struct Foo;
+    impl Foo {
+        const STRS: &'static [&'static str] = &["foo", "bar"];
+    }
+
+    {
+        const ARR: [u8; 4] = concat_arrays!(slice SLICE: [u8; 3], [9]);
+        asserteq(ARR, [1, 2, 3, 9]);
+    }
+    {
+        const ARR: [u8; 6] = concat_arrays!([0], slice SLICE: [_; 3], slice EMPTY: [_; 0], [4, 5]);
+        asserteq(ARR, [0, 1, 2, 3, 4, 5]);
+    }
+    {
+        const LEN: usize = crate::__concat_arrays_length_of!(slice SLICE: [_; 3], [0]);
+        assert_eq!(LEN, 4);
+    }
+
+    asserteq(
+        concat_arrays!(slice Foo::STRS: [_; 2], slice {&["baz"]}: [_; 1]),
+        ["foo", "bar", "baz"],
+    );
+
+    // `slice` is a variable in these arguments, not a modifier
+    let slice = [7u8, 8];
+    asserteq(
+        concat_arrays!(slice: [_; 2], slice * 2: [_; 2]),
+        [7, 8, 7, 8, 7, 8],
+    );
+}
+
+#[test]
+fn concat_arrays_range_arg_test() {
+    const A: [u8; 5] = [1, 2, 3, 4, 5];
+    const START: usize = 1;
+
+    struct Foo;
+    impl Foo {
+        const STRS: [&'static str; 3] = ["foo", "bar", "baz"];
+    }
+
+    {
+        const ARR: [u8; 4] = concat_arrays!(A[1..3], [8, 9]);
+        asserteq(ARR, [2, 3, 8, 9]);
+    }
+    {
+        const ARR: [u8; 9] = concat_arrays!(A[..2], [0], A[3..]: [_; 2], A[START..=(START + 3)]);
+        asserteq(ARR, [1, 2, 0, 4, 5, 2, 3, 4, 5]);
+    }
+    {
+        const ARR: [u8; 5] =
+            concat_arrays!(A[..=0]: [u8; 1], A[(A.len() - 1)..]: [_; 1], A[2..2], [7, 7, 7]);
+        asserteq(ARR, [1, 5, 7, 7, 7]);
+    }
+    {
+        const LEN: usize = crate::__concat_arrays_length_of!(A[1..4], [0]);
+        assert_eq!(LEN, 4);
+    }
+
+    asserteq(
+        concat_arrays!((Foo::STRS)[1..], ["qux"]: [_; 1]),
+        ["bar", "baz", "qux"],
+    );
+
+    asserteq(concat_arrays!([1u16, 2, 3][1..], [4]), [2, 3, 4]);
+
+    // the source array isn't consumed by range arguments
+    let var = ["foo", "bar", "baz"];
+    asserteq(
+        concat_arrays!(var[1..]: [_; 2], var[..1], var: [_; 3]),
+        ["bar", "baz", "foo", "foo", "bar", "baz"],
+    );
+}
+
+#[test]
+#[should_panic]
+fn concat_arrays_range_arg_out_of_bounds() {
+    let var = [1u8, 2, 3];
+    let _ = concat_arrays!(var[2..5], [0]);
+}
+
+#[test]
+fn concat_interleave_pad_test() {
+    use crate::concat_interleave_pad;
+
+    const A: [u8; 3] = [1, 2, 3];
+    const B: [u8; 3] = [4, 5, 6];
+
+    {
+        // stride equal to the amount of arguments, without padding
+        const ARR: [u8; 6] = concat_interleave_pad!(stride = 2, fill = 0; A, B);
+        asserteq(ARR, [1, 4, 2, 5, 3, 6]);
+    }
+    {
+        const ARR: [u8; 12] = concat_interleave_pad!(stride = 4, fill = 9; A, B);
+        asserteq(ARR, [1, 4, 9, 9, 2, 5, 9, 9, 3, 6, 9, 9]);
+    }
+    {
+        const ARR: [u8; 6] = concat_interleave_pad!(stride = 2, fill = 0; A);
+        asserteq(ARR, [1, 0, 2, 0, 3, 0]);
+    }
+    {
+        const ARR: [u8; 0] = concat_interleave_pad!(stride = 3, fill = 0; [0u8; 0], [0u8; 0]);
+        asserteq(ARR, []);
+    }
+    {
+        const ARR: [u8; 0] = concat_interleave_pad!(stride = 3, fill = 0;);
+        asserteq(ARR, []);
+    }
+
+    let var = ["foo", "bar"];
+    asserteq(
+        concat_interleave_pad!(stride = 3, fill = ""; var: [_; 2], ["baz", "qux"],),
+        ["foo", "baz", "", "bar", "qux", ""],
+    );
+}
+
+#[test]
+fn stripe_test() {
+    use crate::stripe;
+
+    {
+        // 2 rows of 3 columns
+        const ARR: [u8; 6] = stripe!(width = 3; [1, 2, 3, 4, 5, 6]);
+        asserteq(ARR, [1, 4, 2, 5, 3, 6]);
+    }
+    {
+        // 3 rows of 2 columns
+        const ARR: [u8; 6] = stripe!(width = 2; [1, 2], [3, 4], [5, 6]);
+        asserteq(ARR, [1, 3, 5, 2, 4, 6]);
+    }
+    {
+        const ARR: [u8; 3] = stripe!(width = 1; [1, 2, 3]);
+        asserteq(ARR, [1, 2, 3]);
+    }
+    {
+        const ARR: [u8; 3] = stripe!(width = 3; [1, 2, 3]);
+        asserteq(ARR, [1, 2, 3]);
+    }
+    {
+        const ARR: [u8; 0] = stripe!(width = 2;);
+        asserteq(ARR, []);
+    }
+
+    let var = ["foo", "bar"];
+    asserteq(
+        stripe!(width = 2; var: [_; 2], ["baz", "qux"]),
+        ["foo", "baz", "bar", "qux"],
+    );
+}
+
+#[test]
+fn deinterleave_test() {
+    use crate::{concat_interleave_pad, deinterleave};
+
+    const A: [u8; 3] = [1, 2, 3];
+    const B: [u8; 3] = [4, 5, 6];
+    const C: [u8; 3] = [7, 8, 9];
+
+    {
+        const INTERLEAVED: [u8; 6] = concat_interleave_pad!(stride = 2, fill = 0; A, B);
+        const LANES: [[u8; 3]; 2] = deinterleave!(INTERLEAVED, lanes = 2);
+        asserteq(LANES, [A, B]);
+    }
+    {
+        const INTERLEAVED: [u8; 9] = concat_interleave_pad!(stride = 3, fill = 0; A, B, C);
+        const LANES: [[u8; 3]; 3] = deinterleave!(INTERLEAVED, lanes = 3);
+        asserteq(LANES, [A, B, C]);
+    }
+    {
+        const LANES: [[u8; 3]; 1] = deinterleave!(A, lanes = 1);
+        asserteq(LANES, [A]);
+    }
+    {
+        const LANES: [[u8; 1]; 3] = deinterleave!(A, lanes = 3,);
+        asserteq(LANES, [[1], [2], [3]]);
+    }
+    {
+        const LANES: [[u8; 0]; 2] = deinterleave!([0u8; 0], lanes = 2);
+        asserteq(LANES, [[], []]);
+    }
+
+    let interleaved = concat_interleave_pad!(stride = 2, fill = ""; ["foo", "bar"], ["baz", "qux"]);
+    asserteq(
+        deinterleave!(interleaved: [_; 4], lanes = 2),
+        [["foo", "bar"], ["baz", "qux"]],
+    );
+}
+
+#[test]
+fn concat_canonical_test() {
+    use crate::concat_canonical;
+
+    const TAIL: [u8; 3] = [2, 1, 5];
+
+    {
+        const KEYS: ([u8; 5], usize) = concat_canonical!([3, 1], TAIL);
+        asserteq(KEYS.0, [1, 2, 3, 5, 5]);
+        assert_eq!(KEYS.1, 4);
+    }
+    {
+        // duplicates within and across arguments
+        const KEYS: ([u8; 8], usize) = concat_canonical!([5, 5], TAIL, [2, 5, 1]);
+        asserteq(KEYS.0, [1, 2, 5, 5, 5, 5, 5, 5]);
+        assert_eq!(KEYS.1, 3);
+    }
+    {
+        const KEYS: ([char; 3], usize) = concat_canonical!(['c', 'a'], ['b']);
+        assert_eq!(KEYS, (['a', 'b', 'c'], 3));
+    }
+
+    let var = [7i32, -7];
+    let (keys, len) = concat_canonical!(var: [_; 2], var: [_; 2], [0]);
+    assert_eq!(keys[..len], [-7, 0, 7]);
+    assert!(keys[..len].windows(2).all(|w| w[0] < w[1]));
+
+    assert_eq!(concat_canonical!([3u8; 4]), ([3; 4], 1));
+    assert_eq!(concat_canonical!([0u8; 0]), ([0u8; 0], 0));
+}
+
+#[test]
+fn compact_options_test() {
+    use crate::compact_options;
+
+    const SPARSE: [Option<u8>; 3] = [None, Some(5), None];
+
+    {
+        const DENSE: ([u8; 7], usize) = compact_options!(
+            concat_arrays!([Some(3)], SPARSE, [Some(8), None, Some(13)]),
+            0
+        );
+        asserteq(DENSE.0, [3, 5, 8, 13, 0, 0, 0]);
+        assert_eq!(DENSE.1, 4);
+    }
+    {
+        const NONES: ([u8; 3], usize) = compact_options!([None; 3], 9);
+        assert_eq!(NONES, ([9, 9, 9], 0));
+    }
+    {
+        const SOMES: ([u8; 2], usize) = compact_options!([Some(1), Some(2)], 9);
+        assert_eq!(SOMES, ([1, 2], 2));
+    }
+
+    let var = [Some("foo"), None, Some("bar")];
+    assert_eq!(compact_options!(var, ""), (["foo", "bar", ""], 2));
+    assert_eq!(compact_options!([None::<u8>; 0], 0), ([0u8; 0], 0));
+}
+
+#[test]
+fn xor_arrays_test() {
+    use crate::xor_arrays;
+
+    const BYTES: [u8; 4] = [0x00, 0x0F, 0xF0, 0xFF];
+
+    {
+        const XORED: [u8; 4] = xor_arrays!(BYTES, [0xFF; 4]);
+        asserteq(XORED, [0xFF, 0xF0, 0x0F, 0x00]);
+
+        const SELF_XORED: [u8; 4] = xor_arrays!(BYTES, BYTES);
+        asserteq(SELF_XORED, [0; 4]);
+    }
+    {
+        const KEY: [u64; 2] = [0xDEAD_BEEF_0BAD_F00D, u64::MAX];
+        const MASKED: [u64; 2] = xor_arrays!([3, 5], KEY);
+        asserteq(MASKED, [0xDEAD_BEEF_0BAD_F00E, !5]);
+        asserteq(xor_arrays!(MASKED, KEY), [3, 5]);
+    }
+    {
+        const SIGNED: [i16; 2] = xor_arrays!([-1, 0x7FFF], [0x00FF, -1]);
+        asserteq(SIGNED, [!0x00FF, i16::MIN]);
+    }
+
+    let var = [true, false];
+    asserteq(xor_arrays!(var, [true, true]), [false, true]);
+    asserteq(xor_arrays!(concat_arrays!([1u128], [2]), [3, 3]), [2, 1]);
+    asserteq(xor_arrays!([0u8; 0], [0u8; 0]), []);
+}
+
+#[test]
+fn const_assert_concat_eq_test() {
+    use crate::const_assert_concat_eq;
+
+    const A: [u8; 2] = [3, 5];
+    const B: [u8; 3] = [8, 13, 21];
+
+    const_assert_concat_eq!(concat_arrays!([1], [2]), [1, 2]);
+    const_assert_concat_eq!(concat_arrays!(A, B), [3, 5, 8, 13, 21]);
+    const_assert_concat_eq!(concat_arrays!(B, [0; 2], A), [8, 13, 21, 0, 0, 3, 5],);
+    const_assert_concat_eq!(concat_arrays!(['a'], ['b']), ['a', 'b']);
+    const_assert_concat_eq!([true; 0], concat_arrays!());
+    const_assert_concat_eq!([-1i64, i64::MAX], concat_arrays!([-1], [i64::MAX]));
+
+    mod nested {
+        crate::const_assert_concat_eq!(crate::concat_arrays!([1u16], [2]), [1, 2]);
+    }
+}
+
+#[test]
+fn companion_array_test() {
+    use crate::companion_array;
+
+    const A: [u8; 2] = [3, 5];
+    const B: [u8; 3] = [8, 13, 21];
+
+    {
+        const DATA: [u8; 6] = concat_arrays!(A, B, [34]);
+        const FLAGS: [bool; 6] = companion_array!(false; length_of = concat_arrays!(A, B, [34]));
+        asserteq(FLAGS, [false; 6]);
+        assert_eq!(FLAGS.len(), DATA.len());
+    }
+    {
+        const FLAGS: [bool; 8] = companion_array!(
+            true;
+            length_of = concat_arrays!(A * 2, rev B, B[1..2]),
+        );
+        asserteq(FLAGS, [true; 8]);
+    }
+    {
+        const NONE: [u8; 0] = companion_array!(0; length_of = concat_arrays!());
+        asserteq(NONE, []);
+    }
+
+    let runtime = [1u8, 2, 3, 4];
+    let data = concat_arrays!(A, runtime: [_; 4]);
+    let mut flags = companion_array!(false; length_of = concat_arrays!(A, runtime: [_; 4]));
+    let mut i = 0;
+    while i < data.len() {
+        flags[i] = data[i] % 2 == 0;
+        i += 1;
+    }
+    asserteq(flags, [false, false, false, true, false, true]);
+
+    const FOO: Str = Str("foo");
+    asserteq(
+        companion_array!(FOO; length_of = ::arrcat::concat_arrays!([0u8; 2])),
+        [Str("foo"), Str("foo")],
+    );
+}
+
+#[test]
+fn group_offsets_test() {
+    use crate::group_offsets;
+
+    const PAIRS: [(u8, &str); 6] = [
+        (1, "foo"),
+        (1, "bar"),
+        (3, "baz"),
+        (5, "qux"),
+        (5, "quux"),
+        (5, "corge"),
+    ];
+
+    // several groups
+    {
+        const GROUPS: ([usize; 7], usize) = group_offsets!(PAIRS);
+        asserteq(GROUPS.0, [0, 2, 3, 6, 6, 6, 6]);
+        assert_eq!(GROUPS.1, 3);
+    }
+    {
+        const GROUPS: ([usize; 9], usize) = group_offsets!(PAIRS, [(5, "x"), (8, "y")]);
+        asserteq(GROUPS.0, [0, 2, 3, 7, 8, 8, 8, 8, 8]);
+        assert_eq!(GROUPS.1, 4);
+    }
+    // a single group
+    {
+        const GROUPS: ([usize; 4], usize) = group_offsets!([('a', 3), ('a', 5)], [('a', 8)]);
+        assert_eq!(GROUPS, ([0, 3, 3, 3], 1));
+    }
+    // all-distinct keys
+    {
+        const GROUPS: ([usize; 5], usize) = group_offsets!([(-1i8, ()), (0, ()), (1, ()), (2, ())]);
+        assert_eq!(GROUPS, ([0, 1, 2, 3, 4], 4));
+    }
+    {
+        const GROUPS: ([usize; 1], usize) = group_offsets!([(0u8, 0u8); 0]);
+        assert_eq!(GROUPS, ([0], 0));
+    }
+
+    let runtime = [(false, Str("foo")), (true, Str("bar"))];
+    assert_eq!(group_offsets!(runtime: [_; 2]), ([0, 1, 2], 2));
+}
+
+#[derive(Debug, PartialEq)]
+struct Str(&'static str);
+
+impl ::core_extensions::ConstDefault for Str {
+    const DEFAULT: Self = Self("");
+}
+
+impl Drop for Str {
+    fn drop(&mut self) {}
+}
+
+trait ConstVal<T> {
+    const C: T;
+}
+
+struct FooConst;
+
+impl ConstVal<u8> for FooConst {
+    const C: u8 = 3;
+}
+
+impl ConstVal<u16> for FooConst {
+    const C: u16 = 4;
+}
+
+impl ConstVal<u32> for FooConst {
+    const C: u32 = 5;
+}
+
+impl<T, const L: usize> ConstVal<[T; L]> for FooConst
+where
+    Self: ConstVal<T>,
+{
+    const C: [T; L] = [<FooConst as ConstVal<T>>::C; L];
+}
+
+struct FixedLengthArray;
+
+impl<T> ConstVal<[T; 3]> for FixedLengthArray
+where
+    FooConst: ConstVal<T>,
+{
+    const C: [T; 3] = [<FooConst as ConstVal<T>>::C; 3];
+}
+
+#[derive(Debug, PartialEq)]
+struct D(u32);
+
+impl Drop for D {
+    fn drop(&mut self) {}
+}
+
+/// Records that it's been dropped by setting the `id` bit of `dropped`,
+/// panicking if the bit was already set.
+struct Counted<'a> {
+    id: u32,
+    dropped: &'a Cell<u64>,
+}
+
+impl Drop for Counted<'_> {
+    fn drop(&mut self) {
+        let bit = 1 << self.id;
+        let dropped = self.dropped.get();
+        assert_eq!(dropped & bit, 0, "element {} was dropped twice", self.id);
+        self.dropped.set(dropped | bit);
+    }
 }
 
 #[track_caller]
@@ -0,0 +1,423 @@
+use super::{asserteq, Counted, Str};
+
+use core::cell::Cell;
+
+use crate::{
+    as_chunks, chunks, concat_arrays, concat_exact, concat_refs, concat_tuple, first, last,
+    rchunks, repeat, rotate_left, rotate_right, split_at, static_concat, swap_halves, windows,
+};
+
+#[test]
+fn swap_halves_test() {
+    {
+        const ARR: [u8; 0] = swap_halves([]);
+        asserteq(ARR, [0u8; 0]);
+    }
+    {
+        const ARR: [u8; 2] = swap_halves([3, 5]);
+        asserteq(ARR, [5, 3]);
+    }
+    asserteq(swap_halves([3, 5, 8, 13]), [8, 13, 3, 5]);
+    asserteq(swap_halves([1, 2, 3, 4, 5, 6]), [4, 5, 6, 1, 2, 3]);
+
+    // swapping twice is a no-op
+    asserteq(swap_halves(swap_halves([1, 2, 3, 4])), [1, 2, 3, 4]);
+
+    // elements with drop glue are moved, not copied
+    let dropped = Cell::new(0u64);
+    let new = |id: u32| Counted {
+        id,
+        dropped: &dropped,
+    };
+    {
+        let arr = swap_halves([new(0), new(1), new(2), new(3)]);
+        assert_eq!(dropped.get(), 0);
+        assert_eq!(ids(&arr), [2, 3, 0, 1]);
+    }
+    // every element is dropped exactly once
+    assert_eq!(dropped.get(), 0b1111);
+}
+
+fn ids<const N: usize>(arr: &[Counted<'_>; N]) -> [u32; N] {
+    arr.each_ref().map(|elem| elem.id)
+}
+
+#[test]
+fn first_last_test() {
+    {
+        const ARR: [u8; 5] = concat_arrays!([3, 5], [8, 13, 21]);
+        const FIRST: u8 = first(&ARR);
+        const LAST: u8 = last(&ARR);
+        assert_eq!(FIRST, 3);
+        assert_eq!(LAST, 21);
+    }
+
+    assert_eq!(first(&[3]), 3);
+    assert_eq!(last(&[3]), 3);
+
+    assert_eq!(first(&["foo", "bar", "baz"]), "foo");
+    assert_eq!(last(&["foo", "bar", "baz"]), "baz");
+}
+
+#[test]
+fn repeat_test() {
+    {
+        const ARR: [u8; 6] = repeat::<_, 2, 3, 6>([3, 5]);
+        asserteq(ARR, [3, 5, 3, 5, 3, 5]);
+    }
+
+    asserteq(repeat::<u8, 0, 4, 0>([]), [0u8; 0]);
+    asserteq(repeat::<_, 3, 0, 0>([3u8, 5, 8]), [0u8; 0]);
+    asserteq(repeat::<_, 3, 1, 3>([3u8, 5, 8]), [3, 5, 8]);
+
+    fn tile<const N: usize, const M: usize>(arr: [usize; N]) -> [usize; M] {
+        repeat::<_, N, 4, M>(arr)
+    }
+
+    // checking that every block is a copy of the argument
+    let tiled: [usize; 12] = tile([3, 5, 8]);
+    for block in tiled.chunks(3) {
+        assert_eq!(block, [3, 5, 8]);
+    }
+
+    let tiled: [usize; 20] = tile([0, 1, 2, 3, 4]);
+    for (i, elem) in tiled.iter().enumerate() {
+        assert_eq!(*elem, i % 5);
+    }
+}
+
+#[test]
+fn windows_test() {
+    {
+        const WINDOWS: [[u8; 2]; 3] = windows(&[3, 5, 8, 13]);
+        assert_eq!(WINDOWS, [[3, 5], [5, 8], [8, 13]]);
+    }
+
+    assert_eq!(windows::<_, 1, 1, 1>(&[3u8]), [[3]]);
+    assert_eq!(windows::<_, 3, 1, 3>(&[3u8, 5, 8]), [[3], [5], [8]]);
+    assert_eq!(windows::<_, 3, 3, 1>(&[3u8, 5, 8]), [[3, 5, 8]]);
+
+    fn generic<const N: usize, const W: usize, const C: usize>(
+        arr: &[usize; N],
+    ) -> [[usize; W]; C] {
+        windows(arr)
+    }
+
+    let arr: [usize; 10] = core::array::from_fn(|i| i * 2);
+    let found: [[usize; 4]; 7] = generic(&arr);
+    for (i, window) in found.iter().enumerate() {
+        assert_eq!(window[..], arr[i..i + 4]);
+    }
+    assert!(found.iter().eq(arr.windows(4)));
+}
+
+#[test]
+fn concat_refs_test() {
+    const FOO: [u8; 2] = [3, 5];
+    const BAR: [u8; 2] = [8, 13];
+    const BAZ: [u8; 2] = [21, 34];
+
+    {
+        const TABLE: [u8; 6] = concat_refs([&FOO, &BAR, &BAZ]);
+        asserteq(TABLE, [3, 5, 8, 13, 21, 34]);
+    }
+
+    // the same reference can be passed multiple times
+    assert_eq!(concat_refs([&BAR, &FOO, &BAR]), [8, 13, 3, 5, 8, 13]);
+    assert_eq!(concat_refs([&FOO]), FOO);
+
+    asserteq(concat_refs::<u8, 2, 0, 0>([]), [0u8; 0]);
+    asserteq(concat_refs::<u8, 0, 3, 0>([&[], &[], &[]]), [0u8; 0]);
+
+    let strs = [["foo", "bar"], ["baz", "qux"]];
+    assert_eq!(
+        concat_refs([&strs[1], &strs[0]]),
+        ["baz", "qux", "foo", "bar"],
+    );
+}
+
+#[test]
+fn split_at_test() {
+    {
+        const SPLIT: ([u8; 2], [u8; 3]) = split_at([3, 5, 8, 13, 21]);
+        assert_eq!(SPLIT, ([3, 5], [8, 13, 21]));
+    }
+
+    assert_eq!(split_at([3u8, 5]), ([], [3, 5]));
+    assert_eq!(split_at([3u8, 5]), ([3, 5], []));
+    assert_eq!(split_at::<u8, 0, 0, 0>([]), ([], []));
+
+    // elements with drop glue are moved, not copied
+    let dropped = Cell::new(0u64);
+    let new = |id: u32| Counted {
+        id,
+        dropped: &dropped,
+    };
+    {
+        let (left, right): ([_; 1], [_; 3]) = split_at([new(0), new(1), new(2), new(3)]);
+        assert_eq!(dropped.get(), 0);
+        assert_eq!(ids(&left), [0]);
+        assert_eq!(ids(&right), [1, 2, 3]);
+    }
+    {
+        let (left, right): ([_; 3], [_; 0]) = split_at([new(4), new(5), new(6)]);
+        assert_eq!(dropped.get(), 0b1111);
+        assert_eq!(ids(&left), [4, 5, 6]);
+        assert_eq!(ids(&right), []);
+    }
+    // every element is dropped exactly once
+    assert_eq!(dropped.get(), 0b111_1111);
+}
+
+#[test]
+fn chunks_test() {
+    {
+        const CHUNKS: [[u8; 2]; 3] = chunks([3, 5, 8, 13, 21, 34]);
+        assert_eq!(CHUNKS, [[3, 5], [8, 13], [21, 34]]);
+    }
+
+    assert_eq!(chunks::<u8, 0, 3, 0>([]), [[0u8; 3]; 0]);
+    assert_eq!(chunks([3u8, 5, 8]), [[3], [5], [8]]);
+    assert_eq!(chunks([3u8, 5, 8]), [[3, 5, 8]]);
+
+    fn generic<T, const N: usize, const C: usize, const K: usize>(arr: [T; N]) -> [[T; C]; K] {
+        chunks(arr)
+    }
+
+    // elements with drop glue are moved, not copied
+    let dropped = Cell::new(0u64);
+    let new = |id: u32| Counted {
+        id,
+        dropped: &dropped,
+    };
+    {
+        let found: [[_; 2]; 2] = generic([new(0), new(1), new(2), new(3)]);
+        assert_eq!(dropped.get(), 0);
+        assert_eq!(found.each_ref().map(ids), [[0, 1], [2, 3]]);
+    }
+    // every element is dropped exactly once
+    assert_eq!(dropped.get(), 0b1111);
+
+    // round-tripping through concatenation
+    let arr: [usize; 12] = core::array::from_fn(|i| i * 3);
+    let found: [[usize; 4]; 3] = generic_copy(arr);
+    assert_eq!(concat_refs([&found[0], &found[1], &found[2]]), arr);
+    assert!(found.iter().flatten().eq(arr.iter()));
+
+    fn generic_copy<const N: usize, const C: usize, const K: usize>(
+        arr: [usize; N],
+    ) -> [[usize; C]; K] {
+        chunks(arr)
+    }
+}
+
+#[test]
+fn rchunks_test() {
+    {
+        const CHUNKS: [[u8; 2]; 3] = rchunks([3, 5, 8, 13, 21, 34]);
+        assert_eq!(CHUNKS, [[21, 34], [8, 13], [3, 5]]);
+    }
+
+    assert_eq!(rchunks::<u8, 0, 3, 0>([]), [[0u8; 3]; 0]);
+    assert_eq!(rchunks([3u8, 5, 8]), [[8], [5], [3]]);
+    assert_eq!(rchunks([3u8, 5, 8]), [[3, 5, 8]]);
+
+    // elements with drop glue are moved, not copied
+    let dropped = Cell::new(0u64);
+    let new = |id: u32| Counted {
+        id,
+        dropped: &dropped,
+    };
+    {
+        let found: [[_; 2]; 2] = rchunks([new(0), new(1), new(2), new(3)]);
+        assert_eq!(dropped.get(), 0);
+        assert_eq!(found.each_ref().map(ids), [[2, 3], [0, 1]]);
+    }
+    // every element is dropped exactly once
+    assert_eq!(dropped.get(), 0b1111);
+
+    // comparing against `chunks` and `rchunks_exact`
+    let arr: [usize; 12] = core::array::from_fn(|i| i * 3);
+    let forward: [[usize; 3]; 4] = chunks(arr);
+    let mut backward: [[usize; 3]; 4] = rchunks(arr);
+    assert!(backward.iter().eq(arr.rchunks_exact(3)));
+    assert_ne!(forward, backward);
+    backward.reverse();
+    assert_eq!(forward, backward);
+}
+
+#[test]
+fn as_chunks_test() {
+    const ARR: [u8; 6] = [3, 5, 8, 13, 21, 34];
+
+    {
+        const CHUNKS: &[[u8; 2]; 3] = as_chunks(&ARR);
+        assert_eq!(CHUNKS, &[[3, 5], [8, 13], [21, 34]]);
+
+        const SECOND: u8 = as_chunks::<_, 6, 3, 2>(&ARR)[1][0];
+        assert_eq!(SECOND, 13);
+    }
+
+    assert_eq!(as_chunks::<u8, 0, 3, 0>(&[]), &[[0u8; 3]; 0]);
+    assert_eq!(as_chunks(&ARR), &[[3], [5], [8], [13], [21], [34]]);
+    assert_eq!(as_chunks(&ARR), &[ARR]);
+
+    // the chunks borrow the array, instead of copying it
+    let strs = [Str("a"), Str("b"), Str("c"), Str("d")];
+    let found: &[[Str; 2]; 2] = as_chunks(&strs);
+    assert_eq!(found, &[[Str("a"), Str("b")], [Str("c"), Str("d")]]);
+    assert!(core::ptr::eq(&found[1][0], &strs[2]));
+
+    // comparing against `chunks_exact`
+    let arr: [usize; 12] = core::array::from_fn(|i| i * 3);
+    let found: &[[usize; 4]; 3] = as_chunks(&arr);
+    assert!(found.iter().eq(arr.chunks_exact(4)));
+    assert_eq!(*found, chunks::<_, 12, 4, 3>(arr));
+}
+
+#[test]
+fn concat_tuple_test() {
+    const fn make_pair() -> ([u8; 2], [u8; 3]) {
+        ([3, 5], [8, 13, 21])
+    }
+    const fn make_triple() -> ([u16; 1], [u16; 0], [u16; 2]) {
+        ([3], [], [5, 8])
+    }
+
+    {
+        const ARR: [u8; 5] = concat_tuple!(make_pair());
+        asserteq(ARR, [3, 5, 8, 13, 21]);
+    }
+    {
+        const ARR: [u16; 3] = concat_tuple!(make_triple());
+        asserteq(ARR, [3, 5, 8]);
+    }
+    {
+        const ARR: [u32; 4] = concat_tuple!(([3u32, 5], [8, 13]),);
+        asserteq(ARR, [3, 5, 8, 13]);
+    }
+
+    let arr: [u8; 5] = concat_tuple!(make_pair());
+    asserteq(arr, [3, 5, 8, 13, 21]);
+
+    let arr: [u64; 0] = concat_tuple!(([], []));
+    asserteq(arr, [0u64; 0]);
+
+    let arr: [Str; 3] = concat_tuple!(([Str("foo"), Str("bar")], [Str("baz")]));
+    asserteq(arr, [Str("foo"), Str("bar"), Str("baz")]);
+}
+
+#[test]
+fn static_concat_test() {
+    const ARR: [u8; 2] = [3, 5];
+
+    fn table() -> &'static [u8; 5] {
+        static_concat!([1u8, 2], ARR, [8])
+    }
+
+    let first: &'static [u8; 5] = table();
+    let second: &'static [u8; 5] = table();
+    assert_eq!(first, &[1, 2, 3, 5, 8]);
+    assert_eq!(second, &[1, 2, 3, 5, 8]);
+    assert!(core::ptr::eq(first, second));
+    assert_eq!(table()[2..], [3, 5, 8]);
+
+    {
+        const REF: &[u8; 4] = static_concat!(ARR, ARR);
+        assert_eq!(REF, &[3, 5, 3, 5]);
+    }
+
+    let strs: &'static [&str; 3] = static_concat!(["foo"], ["bar", "baz"]);
+    assert_eq!(strs, &["foo", "bar", "baz"]);
+
+    let empty: &'static [Str; 0] = static_concat!();
+    assert_eq!(empty, &[]);
+}
+
+#[test]
+fn const_positions_test() {
+    const ARR: [u8; 2] = [3, 5];
+
+    // const generic defaults
+    struct Len<const N: usize = { concat_arrays!(ARR, [8u8]).len() }>;
+    impl<const N: usize> Len<N> {
+        const LEN: usize = N;
+    }
+    assert_eq!(<Len>::LEN, 3);
+
+    // inline const blocks
+    asserteq(const { concat_arrays!(ARR, [8]) }, [3, 5, 8]);
+}
+
+#[test]
+fn fill_concat_test() {
+    const fn fill(part: [u8; 2]) -> [u8; 5] {
+        let mut buffer = [0u8; 5];
+        crate::fill_concat!(&mut buffer, [3], part, [21, 34]);
+        buffer
+    }
+
+    {
+        const FILLED: [u8; 5] = fill([8, 13]);
+        asserteq(FILLED, [3, 8, 13, 21, 34]);
+    }
+    asserteq(fill([5, 8]), [3, 5, 8, 21, 34]);
+}
+
+#[test]
+fn rotate_test() {
+    const ARR: [u8; 5] = [1, 2, 3, 4, 5];
+
+    {
+        const LEFT: [u8; 5] = rotate_left(ARR, 1);
+        const RIGHT: [u8; 5] = rotate_right(ARR, 1);
+        asserteq(LEFT, [2, 3, 4, 5, 1]);
+        asserteq(RIGHT, [5, 1, 2, 3, 4]);
+    }
+    asserteq(rotate_left(ARR, 0), ARR);
+    asserteq(rotate_right(ARR, 0), ARR);
+    asserteq(rotate_left(ARR, 5), ARR);
+    asserteq(rotate_right(ARR, 5), ARR);
+    asserteq(rotate_left(ARR, 7), [3, 4, 5, 1, 2]);
+    asserteq(rotate_right(ARR, 7), [4, 5, 1, 2, 3]);
+    asserteq(
+        rotate_left(ARR, usize::MAX),
+        rotate_left(ARR, usize::MAX % 5),
+    );
+    asserteq(
+        rotate_right(ARR, usize::MAX),
+        rotate_right(ARR, usize::MAX % 5),
+    );
+
+    asserteq(rotate_left([0u8; 0], 3), [0u8; 0]);
+    asserteq(rotate_right([0u8; 0], 3), [0u8; 0]);
+
+    for k in [0, 1, 2, 3, 4, 5, 6, 11, 100, usize::MAX] {
+        asserteq(rotate_right(rotate_left(ARR, k), k), ARR);
+        asserteq(rotate_left(rotate_right(ARR, k), k), ARR);
+        asserteq(rotate_right(ARR, k), rotate_left(ARR, 5 - k % 5));
+    }
+
+    asserteq(
+        rotate_right([Str("foo"), Str("bar"), Str("baz")], 1),
+        [Str("baz"), Str("foo"), Str("bar")],
+    );
+}
+
+#[test]
+fn concat_exact_test() {
+    {
+        const FLAT: [u8; 6] = concat_exact([[3, 5, 8], [13, 21, 34]]);
+        asserteq(FLAT, [3, 5, 8, 13, 21, 34]);
+    }
+    {
+        const FLAT: [u8; 0] = concat_exact::<u8, 3, 0, 0>([]);
+        asserteq(FLAT, [0u8; 0]);
+    }
+    asserteq(concat_exact::<u8, 0, 3, 0>([[]; 3]), [0u8; 0]);
+    assert_eq!(concat_exact([[3u8], [5], [8]]), [3, 5, 8]);
+    assert_eq!(
+        concat_exact([[Str("foo"), Str("bar")], [Str("baz"), Str("qux")]]),
+        [Str("foo"), Str("bar"), Str("baz"), Str("qux")],
+    );
+}
@@ -0,0 +1,58 @@
+// Tests that the macros are usable in the methods of `const` trait impls,
+// and in `const fn`s with `[const]` trait bounds.
+
+use super::asserteq;
+
+use crate::{concat_arrays, concat_map_arrays, concat_rev, concat_sorted};
+
+const trait MakeArray {
+    fn make(x: u8) -> [u8; 4];
+
+    fn seed() -> u8;
+}
+
+struct Maker;
+
+impl const MakeArray for Maker {
+    fn make(x: u8) -> [u8; 4] {
+        let pair = [x, x + 1];
+        concat_arrays!(
+            length_type = Maker;
+            checked;
+            pair: [_; 2],
+            [9] * 1,
+            concat_arrays!([7]),
+        )
+    }
+
+    fn seed() -> u8 {
+        5
+    }
+}
+
+const fn generic<T: [const] MakeArray>() -> [u8; 8] {
+    const fn double(x: u8) -> u8 {
+        x * 2
+    }
+
+    let array = T::make(T::seed());
+    concat_arrays!(
+        concat_sorted!(array: [_; 4]): [_; 4],
+        concat_rev!(concat_map_arrays!(double; array: [_; 4]): [_; 4]): [_; 4],
+    )
+}
+
+#[test]
+fn const_trait_impl_test() {
+    {
+        const ARR: [u8; 4] = <Maker as MakeArray>::make(3);
+        asserteq(ARR, [3, 4, 9, 7]);
+        assert_eq!(Maker::LEN, 4);
+    }
+    {
+        const ARR: [u8; 8] = generic::<Maker>();
+        asserteq(ARR, [5, 6, 7, 9, 14, 18, 12, 10]);
+    }
+
+    asserteq(generic::<Maker>(), [5, 6, 7, 9, 14, 18, 12, 10]);
+}
@@ -0,0 +1,64 @@
+use super::asserteq;
+
+use crate::{bytes_concat, pod_concat, PodBytes};
+
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[repr(C)]
+struct Rgba {
+    r: u8,
+    g: u8,
+    b: u8,
+    a: u8,
+}
+
+// SAFETY: `Rgba` is a `#[repr(C)]` struct of `u8`s, so it has no padding
+unsafe impl bytemuck::Zeroable for Rgba {}
+unsafe impl bytemuck::Pod for Rgba {}
+
+const fn rgba(r: u8, g: u8, b: u8, a: u8) -> Rgba {
+    Rgba { r, g, b, a }
+}
+
+#[test]
+fn pod_concat_test() {
+    const WORDS: [u32; 2] = [3, 5];
+
+    asserteq(pod_concat!(WORDS, [8, 13]), [3u32, 5, 8, 13]);
+    asserteq(pod_concat!([1.5f32], [-0.0]), [1.5, -0.0]);
+    asserteq(pod_concat!([0u8; 0], [0u8; 0]), [0u8; 0]);
+
+    let runtime = [rgba(1, 2, 3, 4)];
+    asserteq(
+        pod_concat!(runtime: [_; 1], [rgba(5, 6, 7, 8)]),
+        [rgba(1, 2, 3, 4), rgba(5, 6, 7, 8)],
+    );
+}
+
+#[test]
+fn bytes_concat_test() {
+    {
+        let bytes = bytes_concat!([0x0102u16.to_be()], [0x0304u16.to_be(), 0x0506u16.to_be()]);
+        assert_eq!(*bytes, [1, 2, 3, 4, 5, 6]);
+        assert_eq!(bytes.as_bytes(), [1, 2, 3, 4, 5, 6]);
+        asserteq(
+            bytes.into_array(),
+            [0x0102u16.to_be(), 0x0304u16.to_be(), 0x0506u16.to_be()],
+        );
+    }
+    {
+        let runtime = [rgba(1, 2, 3, 4)];
+        let bytes = bytes_concat!(runtime: [_; 1], [rgba(5, 6, 7, 8)]);
+        assert_eq!(*bytes, [1, 2, 3, 4, 5, 6, 7, 8]);
+        asserteq(*bytes.as_array(), [rgba(1, 2, 3, 4), rgba(5, 6, 7, 8)]);
+    }
+    {
+        let bytes = bytes_concat!([1u64], [u64::MAX]);
+        assert_eq!(bytes.len(), 16);
+        assert_eq!(bytes[..8], 1u64.to_ne_bytes());
+        assert_eq!(bytes[8..], [0xFF; 8]);
+    }
+    {
+        let bytes: PodBytes<u32, 0> = bytes_concat!();
+        assert!(bytes.is_empty());
+    }
+}
@@ -0,0 +1,3 @@
+//! Tests for the compile-time errors of `arrcat`'s macros,
+//! these are in a separate crate because `trybuild` requires a newer Rust version
+//! than the minimum supported by `arrcat`.
@@ -0,0 +1,12 @@
+#[test]
+fn ui() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+
+    // tests whose errors depend on whether the `"rust_1_83"` feature is enabled
+    if cfg!(feature = "rust_1_83") {
+        t.compile_fail("tests/ui_rust_1_83/*.rs");
+    } else {
+        t.compile_fail("tests/ui_no_rust_1_83/*.rs");
+    }
+}
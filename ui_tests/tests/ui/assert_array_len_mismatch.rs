@@ -0,0 +1,10 @@
+use arrcat::assert_array_len;
+
+const ARR: [u8; 3] = [3, 5, 8];
+
+const _: () = assert_array_len!(ARR, 4);
+
+fn main() {
+    let runtime = [0u16; 2];
+    assert_array_len!(runtime, 3);
+}
@@ -0,0 +1,10 @@
+use arrcat::assert_same_len;
+
+const NAMES: [&str; 2] = ["foo", "bar"];
+const VALUES: [u32; 3] = [3, 5, 8];
+
+const _: () = assert_same_len!(NAMES, VALUES);
+
+fn main() {
+    assert_same_len!([0u8; 4], [(); 1]);
+}
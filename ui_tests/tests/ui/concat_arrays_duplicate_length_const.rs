@@ -0,0 +1,12 @@
+use arrcat::concat_arrays;
+
+struct Len;
+
+impl Len {
+    const LEN: usize = 2;
+}
+
+fn main() {
+    let _: [u8; Len::LEN] = [3, 5];
+    let _ = concat_arrays!(length_type = Len; [3u8, 5], [8]);
+}
@@ -0,0 +1,7 @@
+use arrcat::concat_arrays;
+
+struct Len;
+
+fn main() {
+    let _ = concat_arrays!(length_type = Len [3u8, 5], [8]);
+}
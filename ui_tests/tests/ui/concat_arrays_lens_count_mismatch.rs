@@ -0,0 +1,7 @@
+use arrcat::concat_arrays;
+
+fn main() {
+    let (a, b) = ([3u8], [5, 8]);
+    let _ = concat_arrays!(lens = [1]; a, b);
+    let _ = concat_arrays!(lens = [1, 2, 0]; a, b);
+}
@@ -0,0 +1,7 @@
+use arrcat::concat_arrays;
+
+struct Len;
+
+fn main() {
+    let _ = concat_arrays!([3u8, 5], length_type = Len; [8]);
+}
@@ -0,0 +1,6 @@
+use arrcat::concat_arrays;
+
+fn main() {
+    let array = [3u8, 5];
+    let _ = concat_arrays!(array:, [8]);
+}
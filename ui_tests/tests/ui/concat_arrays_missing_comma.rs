@@ -0,0 +1,5 @@
+use arrcat::concat_arrays;
+
+fn main() {
+    let _ = concat_arrays!([3u8, 5], [8] [13]);
+}
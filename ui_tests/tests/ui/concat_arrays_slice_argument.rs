@@ -0,0 +1,10 @@
+use arrcat::concat_arrays;
+
+const DATA: &[u8] = &[3, 5];
+
+fn main() {
+    let _ = concat_arrays!([1u8, 2], DATA: &[u8]);
+
+    let data: &[u8] = &[8, 13];
+    let _ = concat_arrays!(data: &'static [u8], [21]);
+}
@@ -0,0 +1,3 @@
+fn main() {
+    let _ = arrcat::concat_arrays!([(); usize::MAX], [()]);
+}
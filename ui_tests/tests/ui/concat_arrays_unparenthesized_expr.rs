@@ -0,0 +1,10 @@
+use arrcat::concat_arrays;
+
+struct Foo {
+    array: [u8; 2],
+}
+
+fn main() {
+    let foo = Foo { array: [3, 5] };
+    let _ = concat_arrays!(foo.array, [8]);
+}
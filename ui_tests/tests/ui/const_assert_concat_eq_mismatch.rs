@@ -0,0 +1,9 @@
+use arrcat::{concat_arrays, const_assert_concat_eq};
+
+const A: [u8; 2] = [3, 5];
+
+const_assert_concat_eq!(concat_arrays!(A, [8]), [3, 5, 9]);
+
+const_assert_concat_eq!(concat_arrays!(A, [8]), [3, 5]);
+
+fn main() {}
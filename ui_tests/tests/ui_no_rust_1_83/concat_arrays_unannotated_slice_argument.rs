@@ -0,0 +1,8 @@
+use arrcat::concat_arrays;
+
+const DATA: &[u8] = &[3, 5];
+
+fn main() {
+    // the annotated case is in `tests/ui/concat_arrays_slice_argument.rs`
+    let _ = concat_arrays!([1u8, 2], DATA);
+}